@@ -13,7 +13,7 @@ thread_local! {
     static CSS: Css = css_stylesheet!("../../styles/click_child.css");
 }
 
-#[derive(Hash)]
+#[derive(PartialEq, Clone)]
 pub(crate) struct ChildImmediateReloadProperties {
     pub(crate) click: Callback<()>,
     pub(crate) on_change_name: Callback<String>,
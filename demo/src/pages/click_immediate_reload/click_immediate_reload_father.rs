@@ -21,7 +21,7 @@ pub(crate) enum FatherImmediateReloadMessages {
     SecondChildChangeName(String),
 }
 
-#[derive(Hash)]
+#[derive(PartialEq, Clone)]
 pub(crate) struct FatherImmediateReloadProperties;
 
 pub(crate) struct FatherImmediateReloadComponent {
@@ -22,7 +22,7 @@ pub(crate) struct ToBeUpdatedMessage {
     pub(crate) name: String,
 }
 
-#[derive(Hash, Clone)]
+#[derive(PartialEq, Clone)]
 pub(crate) struct ClickNotImmediateReloadChildProperties {
     pub(crate) id: i32,
     pub(crate) count: i32,
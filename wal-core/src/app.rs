@@ -0,0 +1,159 @@
+use std::{cell::RefCell, rc::Rc};
+
+use web_sys::Element;
+
+use crate::{
+    component::{node::AnyComponentNode, Component},
+    events::delegation::{self, ContainerRegistration},
+};
+
+/// Application rendered into an element of the page by [mount].
+struct MountedApp {
+    element: Element,
+    root: Rc<RefCell<AnyComponentNode>>,
+    _registration: ContainerRegistration,
+}
+
+impl MountedApp {
+    fn erase(&self) {
+        if let Some(vdom) = self.root.borrow().vdom.as_ref() {
+            vdom.erase();
+        }
+    }
+}
+
+thread_local! {
+    static MOUNTED_APPS: RefCell<Vec<MountedApp>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Mounts the component ***C*** with provided properties as an independent application rendered into the `element`.
+/// Unlike [Router](crate::router::Router), it does not handle routing, so it can be used to embed widgets into existing pages.
+/// Any number of applications can be mounted into different elements of the same page, also next to the routed application.
+///
+/// Content of the `element` is replaced by the application. If another application was mounted into the `element`, it is [unmounted](unmount) first.
+///
+/// # Example
+/// ```ignore
+/// let element = gloo::utils::document().get_element_by_id("cart-widget").unwrap();
+/// wal_core::app::mount::<CartWidget>(&element, CartProperties { currency: "EUR".into() });
+/// ```
+pub fn mount<C: Component + 'static>(element: &Element, props: C::Properties) {
+    std::panic::set_hook(Box::new(console_error_panic_hook::hook));
+    unmount(element);
+
+    element.set_text_content(None);
+    let registration = delegation::register_container(element);
+    let root = AnyComponentNode::new_root(C::new(props), element.clone().into());
+    root.borrow_mut().view_and_patch();
+
+    MOUNTED_APPS.with(|apps| {
+        apps.borrow_mut().push(MountedApp {
+            element: element.clone(),
+            root,
+            _registration: registration,
+        })
+    });
+}
+
+/// Unmounts the application [mounted](mount) into the `element`. Its DOM nodes are removed and all of its components are dropped
+/// together with their subscriptions, timers, requests and event handlers. Returns `false` if there was no application mounted into the `element`.
+///
+/// It should not be called during the update of the components of the application itself, e.g. it can be called by a callback
+/// of the event handler instead.
+pub fn unmount(element: &Element) -> bool {
+    let app = MOUNTED_APPS.with(|apps| {
+        let mut apps = apps.borrow_mut();
+        apps.iter()
+            .position(|x| x.element == *element)
+            .map(|index| apps.remove(index))
+    });
+
+    match app {
+        Some(app) => {
+            app.erase();
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::{
+        component::{behavior::Behavior, Component},
+        virtual_dom::{dom, VNode, VText},
+    };
+
+    use super::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    struct Greeting(String);
+    impl Component for Greeting {
+        type Message = ();
+        type Properties = String;
+
+        fn new(props: Self::Properties) -> Self {
+            Greeting(props)
+        }
+        fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+            VText::new(format!("Hello, {}!", self.0)).into()
+        }
+        fn update(&mut self, _message: Self::Message) -> bool {
+            false
+        }
+    }
+
+    fn create_host() -> Element {
+        let host = dom::create_element("div");
+        dom::append_child(&gloo::utils::body(), &host);
+        host
+    }
+
+    #[wasm_bindgen_test]
+    fn mount_should_replace_content_of_element() {
+        // Arrange
+        let host = create_host();
+        host.set_text_content(Some("Loading..."));
+
+        // Act
+        mount::<Greeting>(&host, "world".to_string());
+
+        // Assert
+        assert_eq!(host.text_content().unwrap(), "Hello, world!");
+        unmount(&host);
+    }
+
+    #[wasm_bindgen_test]
+    fn mount_should_render_independent_roots() {
+        // Arrange
+        let first = create_host();
+        let second = create_host();
+
+        // Act
+        mount::<Greeting>(&first, "first".to_string());
+        mount::<Greeting>(&second, "second".to_string());
+        unmount(&first);
+
+        // Assert
+        assert_eq!(first.text_content().unwrap(), "");
+        assert_eq!(second.text_content().unwrap(), "Hello, second!");
+        unmount(&second);
+    }
+
+    #[wasm_bindgen_test]
+    fn mount_into_mounted_element_should_replace_application() {
+        // Arrange
+        let host = create_host();
+        mount::<Greeting>(&host, "old".to_string());
+
+        // Act
+        mount::<Greeting>(&host, "new".to_string());
+
+        // Assert
+        assert_eq!(host.text_content().unwrap(), "Hello, new!");
+        assert!(unmount(&host));
+        assert!(!unmount(&host));
+    }
+}
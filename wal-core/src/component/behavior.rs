@@ -1,21 +1,140 @@
 use std::{
+    borrow::Cow,
     cell::RefCell,
     rc::{Rc, Weak},
 };
 
-use super::{callback::Callback, node::AnyComponentNode, scheduler::Scheduler, Component};
+use wasm_bindgen::JsCast;
+
+#[cfg(feature = "serde")]
+use crate::storage::Persisted;
+use crate::{
+    fetch::{FetchError, Request, Response},
+    router::{
+        blocker::{BlockerCallback, NavigationBlocker},
+        BlockedNavigation,
+    },
+    websocket::{self, Decoder, WebSocketEvent, WebSocketHandle, WebSocketOptions},
+};
+
+#[cfg(feature = "serde")]
+use super::storage_observers::StorageObservers;
+
+use super::{
+    callback::Callback,
+    error_boundary::{ComponentError, ErrorHandler},
+    fetches::{Fetches, SentFetches},
+    node::AnyComponentNode,
+    scheduler::Scheduler,
+    sockets::Sockets,
+    subscription::{SubscriptionTarget, Subscriptions},
+    timer::{TimerHandle, TimerKind, Timers},
+    Component,
+};
 
 pub(crate) struct AnyComponentBehavior {
     any_component_node: Weak<RefCell<AnyComponentNode>>,
+    subscriptions: Subscriptions,
+    timers: Timers,
+    fetches: Fetches,
+    sockets: Sockets,
+    error_handler: Option<Rc<ErrorHandler>>,
+    is_error_handler_declared: bool,
+    navigation_blocker: Option<Rc<NavigationBlocker>>,
+    is_navigation_blocker_declared: bool,
+    #[cfg(feature = "serde")]
+    storage_observers: StorageObservers,
 }
 
 impl AnyComponentBehavior {
     pub(crate) fn new() -> Self {
         Self {
             any_component_node: Weak::new(),
+            subscriptions: Subscriptions::default(),
+            timers: Timers::default(),
+            fetches: Fetches::default(),
+            sockets: Sockets::default(),
+            error_handler: None,
+            is_error_handler_declared: false,
+            navigation_blocker: None,
+            is_navigation_blocker_declared: false,
+            #[cfg(feature = "serde")]
+            storage_observers: StorageObservers::default(),
         }
     }
 
+    pub(crate) fn start_view(&mut self) {
+        self.subscriptions.start_view();
+        self.timers.start_view();
+        self.fetches.start_view();
+        self.sockets.start_view();
+        self.is_error_handler_declared = false;
+        self.is_navigation_blocker_declared = false;
+        #[cfg(feature = "serde")]
+        self.storage_observers.start_view();
+    }
+
+    pub(crate) fn finish_view(&mut self) {
+        self.subscriptions.finish_view();
+        self.timers.finish_view();
+        self.fetches.finish_view();
+        self.sockets.finish_view();
+        if !self.is_error_handler_declared {
+            self.error_handler = None;
+        }
+        if !self.is_navigation_blocker_declared {
+            self.navigation_blocker = None;
+        }
+        #[cfg(feature = "serde")]
+        self.storage_observers.finish_view();
+    }
+
+    fn declare_timer<C, F>(&mut self, kind: TimerKind, millis: u32, message: F) -> TimerHandle
+    where
+        C: Component,
+        F: Fn() -> C::Message + 'static,
+    {
+        let any_component_node = self.any_component_node.clone();
+        let timer = self.timers.declare(
+            kind,
+            millis,
+            Rc::new(move || {
+                Scheduler::add_update_message(Box::new(message()), any_component_node.clone());
+            }),
+        );
+        TimerHandle::new(&timer)
+    }
+
+    fn subscribe<C, E, F>(
+        &mut self,
+        target: SubscriptionTarget,
+        event_type: &'static str,
+        wrapper: F,
+    ) where
+        C: Component,
+        E: JsCast + 'static,
+        F: Fn(E) -> C::Message + 'static,
+    {
+        let any_component_node = self.any_component_node.clone();
+        self.subscriptions.subscribe(
+            target,
+            Cow::from(event_type),
+            Box::new(move |event| {
+                let message = wrapper(event.clone().unchecked_into());
+                Scheduler::add_update_message(Box::new(message), any_component_node.clone());
+            }),
+        );
+    }
+
+    /// Returns the error handler declared by the component, if it is an error boundary.
+    pub(crate) fn get_error_handler(&self) -> Option<Weak<ErrorHandler>> {
+        self.error_handler.as_ref().map(Rc::downgrade)
+    }
+
+    pub(crate) fn get_any_component_node(&self) -> Weak<RefCell<AnyComponentNode>> {
+        self.any_component_node.clone()
+    }
+
     pub(crate) fn set_any_component_node(
         &mut self,
         any_component_node: Rc<RefCell<AnyComponentNode>>,
@@ -32,6 +151,211 @@ pub trait Behavior<C: Component> {
     fn create_callback<IN, F>(&mut self, wrapper: F) -> Callback<IN>
     where
         F: Fn(IN) -> C::Message + 'static;
+
+    /// Works the same as [create_callback](Behavior::create_callback), but the [Message](Component::Message) is skipped
+    /// if an equal message for this [Component](Component) is already waiting to be handled.
+    /// Useful for high frequency events like `oninput` or `onscroll`.
+    fn create_deduplicated_callback<IN, F>(&mut self, wrapper: F) -> Callback<IN>
+    where
+        F: Fn(IN) -> C::Message + 'static,
+        C::Message: PartialEq;
+
+    /// Subscribes the [Component](Component) to the events of `event_type` dispatched on the `window`.
+    /// Every event is mapped by the `wrapper` to the [Message](Component::Message) sent to the [Component](Component).
+    /// Useful for events which are not dispatched on the elements, like `resize`, `online`, `offline` or `popstate`.
+    ///
+    /// Subscriptions have to be declared during every [view](Component::view).
+    /// The subscription is removed when it is not declared during the view anymore or when the [Component](Component) is destroyed.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// fn view(&self, behavior: &mut impl Behavior<Self>) -> VNode {
+    ///     behavior.subscribe_to_window_event("resize", |_: web_sys::Event| Message::Resized);
+    ///     rsx! { <div /> }
+    /// }
+    /// ```
+    fn subscribe_to_window_event<E, F>(&mut self, event_type: &'static str, wrapper: F)
+    where
+        E: JsCast + 'static,
+        F: Fn(E) -> C::Message + 'static;
+
+    /// Works the same as [subscribe_to_window_event](Behavior::subscribe_to_window_event), but the events are dispatched on the `document`.
+    /// Useful for events like `keydown` used by keyboard shortcuts or `visibilitychange`.
+    fn subscribe_to_document_event<E, F>(&mut self, event_type: &'static str, wrapper: F)
+    where
+        E: JsCast + 'static,
+        F: Fn(E) -> C::Message + 'static;
+
+    /// Sends the [Message](Component::Message) created by `message` to the [Component](Component) once, after `millis` milliseconds.
+    ///
+    /// Timers have to be declared during every [view](Component::view) and are identified by the order of declarations.
+    /// Timer declared again with the same delay keeps running, so it is not restarted by rerenders.
+    /// Therefore the timeout which has already fired is not armed again by declaring it, it has to be [reset](TimerHandle::reset) instead.
+    /// The timer is cancelled when it is not declared during the view anymore or when the [Component](Component) is destroyed.
+    /// It can be also cancelled earlier using returned [TimerHandle].
+    fn set_timeout<F>(&mut self, millis: u32, message: F) -> TimerHandle
+    where
+        F: Fn() -> C::Message + 'static;
+
+    /// Sends the [Message](Component::Message) created by `message` to the [Component](Component) every `millis` milliseconds.
+    /// Declaring the interval works the same as for [set_timeout](Behavior::set_timeout).
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// fn view(&self, behavior: &mut impl Behavior<Self>) -> VNode {
+    ///     behavior.set_interval(1000, || Message::Tick);
+    ///     rsx! { <span>{ self.seconds }</span> }
+    /// }
+    /// ```
+    fn set_interval<F>(&mut self, millis: u32, message: F) -> TimerHandle
+    where
+        F: Fn() -> C::Message + 'static;
+
+    /// Works the same as [create_callback](Behavior::create_callback), but the [Message](Component::Message) is sent
+    /// only after `millis` milliseconds have passed without the callback being called again.
+    /// Useful for actions like searching while the user is typing.
+    /// Declaring the debounced callback works the same as for [set_timeout](Behavior::set_timeout).
+    fn debounce<IN, F>(&mut self, millis: u32, wrapper: F) -> Callback<IN>
+    where
+        F: Fn(IN) -> C::Message + 'static;
+
+    /// Sends the [Request] and maps its result by the `wrapper` to the [Message](Component::Message) sent to the [Component](Component).
+    ///
+    /// Requests have to be declared during every [view](Component::view) and are identified by the order of declarations.
+    /// Request declared again with an equal [Request] is not sent again, so it is not repeated by rerenders.
+    /// Otherwise the pending request is aborted and the new one is sent.
+    /// The pending request is aborted when it is not declared during the view anymore or when the [Component](Component) is destroyed.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// fn view(&self, behavior: &mut impl Behavior<Self>) -> VNode {
+    ///     behavior.fetch(Request::get(format!("/api/todos/{}", self.id)), Message::TodoLoaded);
+    ///     rsx! { <div /> }
+    /// }
+    /// ```
+    fn fetch<F>(&mut self, request: Request, wrapper: F)
+    where
+        F: Fn(Result<Response, FetchError>) -> C::Message + 'static;
+
+    /// Creates a [callback](Callback), which sends the [Request] created by `request` every time it is called.
+    /// Result of the request is mapped by the `wrapper` to the [Message](Component::Message) sent to the [Component](Component).
+    /// Pending requests are aborted when the [Component](Component) is destroyed.
+    fn create_fetch_callback<IN, R, F>(&mut self, request: R, wrapper: F) -> Callback<IN>
+    where
+        R: Fn(IN) -> Request + 'static,
+        F: Fn(Result<Response, FetchError>) -> C::Message + 'static;
+
+    /// Subscribes the [Component](Component) to the WebSocket connection described by `options`.
+    /// Received frames are decoded by the `decoder` and every [event](WebSocketEvent) of the connection
+    /// is mapped by the `wrapper` to the [Message](Component::Message) sent to the [Component](Component).
+    /// Returned [WebSocketHandle] allows to send frames through the connection.
+    ///
+    /// Connections have to be declared during every [view](Component::view) and are identified by the order of declarations.
+    /// Connection declared again with equal [options](WebSocketOptions) is kept, otherwise it is closed and the new one is established.
+    /// The connection is closed when it is not declared during the view anymore or when the [Component](Component) is destroyed.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// fn view(&self, behavior: &mut impl Behavior<Self>) -> VNode {
+    ///     let options = WebSocketOptions::new("wss://example.com/live").reconnect(ReconnectPolicy::default());
+    ///     let socket = behavior.subscribe_to_websocket(options, TextDecoder, Message::Live);
+    ///     let ping = Callback::new(move |_: MouseEvent| { let _ = socket.send_text("ping"); });
+    ///     rsx! { <button onclick={ping}>{ "Ping" }</button> }
+    /// }
+    /// ```
+    fn subscribe_to_websocket<D, F>(
+        &mut self,
+        options: WebSocketOptions,
+        decoder: D,
+        wrapper: F,
+    ) -> WebSocketHandle
+    where
+        D: Decoder,
+        F: Fn(WebSocketEvent<D::Output>) -> C::Message + 'static;
+
+    /// Makes the [Component](Component) an error boundary of its subtree.
+    /// Failures of its descendants are mapped by the `wrapper` to the [Message](Component::Message) sent to the [Component](Component),
+    /// which should render a fallback view instead of the failed subtree. Rendering the subtree again retries it.
+    /// Failures of the [Component](Component) itself are passed to the error boundary of its ancestors.
+    ///
+    /// The error boundary has to be declared during every [view](Component::view).
+    /// Ready-made [ErrorBoundary](super::ErrorBoundary) component could be used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// fn view(&self, behavior: &mut impl Behavior<Self>) -> VNode {
+    ///     behavior.catch_errors(Message::Failed);
+    ///     match &self.error {
+    ///         Some(error) => rsx! { <p>{ error }</p> },
+    ///         None => rsx! { <Dashboard /> },
+    ///     }
+    /// }
+    /// ```
+    fn catch_errors<F>(&mut self, wrapper: F)
+    where
+        F: Fn(ComponentError) -> C::Message + 'static;
+
+    /// Blocks the navigation of the [Router](crate::router::Router) away from the current page, e.g. if a form has unsaved changes.
+    /// Instead of navigating, the [Message](Component::Message) created from the [BlockedNavigation] is sent to the [Component](Component),
+    /// which can ask the user to confirm leaving the page with its own dialog or with [confirm](BlockedNavigation::confirm),
+    /// and then [proceed](BlockedNavigation::proceed) with the navigation.
+    /// Both clicking a `Link` and going back or forward in the history are blocked. Closing or reloading the tab
+    /// is confirmed by the browser with its own dialog.
+    ///
+    /// The blocker has to be declared during every [view](Component::view), in which the navigation should be blocked.
+    /// It is removed when it is not declared during the view anymore or when the [Component](Component) is destroyed.
+    /// If multiple components declare blockers, only the most recently declared one is notified.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// fn view(&self, behavior: &mut impl Behavior<Self>) -> VNode {
+    ///     if self.has_unsaved_changes {
+    ///         behavior.block_navigation(Message::NavigationBlocked);
+    ///     }
+    ///     rsx! { <form>...</form> }
+    /// }
+    ///
+    /// fn update(&mut self, message: Self::Message) -> bool {
+    ///     match message {
+    ///         Message::NavigationBlocked(navigation) => {
+    ///             navigation.confirm("Discard unsaved changes?");
+    ///             false
+    ///         }
+    ///         // ..
+    ///     }
+    /// }
+    /// ```
+    fn block_navigation<F>(&mut self, wrapper: F)
+    where
+        F: Fn(BlockedNavigation) -> C::Message + 'static;
+
+    /// Reads the [Persisted] value and subscribes the [Component](Component) to its changes.
+    /// The [Component](Component) is rerendered whenever the value is written or removed, also by the other tabs of the application.
+    /// Returns [None] if there is no value stored, the stored value could not be deserialized or the storage is unavailable.
+    ///
+    /// Subscriptions have to be declared during every [view](Component::view) and are identified by the order of declarations.
+    /// The subscription is removed when it is not declared during the view anymore or when the [Component](Component) is destroyed.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// const THEME: Persisted<Theme> = Persisted::local("theme");
+    ///
+    /// fn view(&self, behavior: &mut impl Behavior<Self>) -> VNode {
+    ///     let theme = behavior.subscribe_to_storage(&THEME).unwrap_or_default();
+    ///     rsx! { <div class={theme.class()} /> }
+    /// }
+    /// ```
+    #[cfg(feature = "serde")]
+    fn subscribe_to_storage<T>(&mut self, persisted: &Persisted<T>) -> Option<T>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned;
 }
 
 impl<C: Component> Behavior<C> for AnyComponentBehavior {
@@ -45,4 +369,176 @@ impl<C: Component> Behavior<C> for AnyComponentBehavior {
             Scheduler::add_update_message(Box::new(message), any_component_node.clone());
         })
     }
+
+    fn create_deduplicated_callback<IN, F>(&mut self, wrapper: F) -> Callback<IN>
+    where
+        F: Fn(IN) -> C::Message + 'static,
+        C::Message: PartialEq,
+    {
+        let any_component_node = self.any_component_node.clone();
+        Callback::new(move |data| {
+            let message = wrapper(data);
+            Scheduler::add_deduplicated_update_message(message, any_component_node.clone());
+        })
+    }
+
+    fn subscribe_to_window_event<E, F>(&mut self, event_type: &'static str, wrapper: F)
+    where
+        E: JsCast + 'static,
+        F: Fn(E) -> C::Message + 'static,
+    {
+        self.subscribe::<C, E, F>(SubscriptionTarget::Window, event_type, wrapper);
+    }
+
+    fn subscribe_to_document_event<E, F>(&mut self, event_type: &'static str, wrapper: F)
+    where
+        E: JsCast + 'static,
+        F: Fn(E) -> C::Message + 'static,
+    {
+        self.subscribe::<C, E, F>(SubscriptionTarget::Document, event_type, wrapper);
+    }
+
+    fn set_timeout<F>(&mut self, millis: u32, message: F) -> TimerHandle
+    where
+        F: Fn() -> C::Message + 'static,
+    {
+        self.declare_timer::<C, F>(TimerKind::Timeout, millis, message)
+    }
+
+    fn set_interval<F>(&mut self, millis: u32, message: F) -> TimerHandle
+    where
+        F: Fn() -> C::Message + 'static,
+    {
+        self.declare_timer::<C, F>(TimerKind::Interval, millis, message)
+    }
+
+    fn debounce<IN, F>(&mut self, millis: u32, wrapper: F) -> Callback<IN>
+    where
+        F: Fn(IN) -> C::Message + 'static,
+    {
+        let timer = Rc::downgrade(&self.timers.declare(
+            TimerKind::Debounce,
+            millis,
+            Rc::new(|| {}),
+        ));
+        let any_component_node = self.any_component_node.clone();
+        Callback::new(move |data| {
+            if let Some(timer) = timer.upgrade() {
+                let message = wrapper(data);
+                let any_component_node = any_component_node.clone();
+                timer.debounce(move || {
+                    Scheduler::add_update_message(Box::new(message), any_component_node);
+                });
+            }
+        })
+    }
+
+    fn fetch<F>(&mut self, request: Request, wrapper: F)
+    where
+        F: Fn(Result<Response, FetchError>) -> C::Message + 'static,
+    {
+        let any_component_node = self.any_component_node.clone();
+        self.fetches.declare(
+            request,
+            Box::new(move |result| {
+                Scheduler::add_update_message(
+                    Box::new(wrapper(result)),
+                    any_component_node.clone(),
+                );
+            }),
+        );
+    }
+
+    fn create_fetch_callback<IN, R, F>(&mut self, request: R, wrapper: F) -> Callback<IN>
+    where
+        R: Fn(IN) -> Request + 'static,
+        F: Fn(Result<Response, FetchError>) -> C::Message + 'static,
+    {
+        let sent_fetches = self.fetches.get_sent_fetches();
+        let any_component_node = self.any_component_node.clone();
+        let wrapper = Rc::new(wrapper);
+        Callback::new(move |data| {
+            if let Some(sent_fetches) = sent_fetches.upgrade() {
+                let any_component_node = any_component_node.clone();
+                let wrapper = wrapper.clone();
+                SentFetches::send(
+                    &sent_fetches,
+                    request(data),
+                    Box::new(move |result| {
+                        Scheduler::add_update_message(
+                            Box::new(wrapper(result)),
+                            any_component_node.clone(),
+                        );
+                    }),
+                );
+            }
+        })
+    }
+
+    fn subscribe_to_websocket<D, F>(
+        &mut self,
+        options: WebSocketOptions,
+        decoder: D,
+        wrapper: F,
+    ) -> WebSocketHandle
+    where
+        D: Decoder,
+        F: Fn(WebSocketEvent<D::Output>) -> C::Message + 'static,
+    {
+        let any_component_node = self.any_component_node.clone();
+        let socket = self.sockets.declare(
+            options,
+            Rc::new(move |event| {
+                let message = wrapper(websocket::decode(&decoder, event));
+                Scheduler::add_update_message(Box::new(message), any_component_node.clone());
+            }),
+        );
+        WebSocketHandle::new(&socket)
+    }
+
+    fn catch_errors<F>(&mut self, wrapper: F)
+    where
+        F: Fn(ComponentError) -> C::Message + 'static,
+    {
+        let any_component_node = self.any_component_node.clone();
+        let callback: Box<dyn Fn(ComponentError)> = Box::new(move |error| {
+            Scheduler::add_update_message(Box::new(wrapper(error)), any_component_node.clone());
+        });
+        match &self.error_handler {
+            Some(error_handler) => error_handler.set_callback(callback),
+            None => self.error_handler = Some(ErrorHandler::new(callback)),
+        }
+        self.is_error_handler_declared = true;
+    }
+
+    fn block_navigation<F>(&mut self, wrapper: F)
+    where
+        F: Fn(BlockedNavigation) -> C::Message + 'static,
+    {
+        let any_component_node = self.any_component_node.clone();
+        let callback: BlockerCallback = Box::new(move |navigation| {
+            Scheduler::add_update_message(
+                Box::new(wrapper(navigation)),
+                any_component_node.clone(),
+            );
+        });
+        match &self.navigation_blocker {
+            Some(navigation_blocker) => navigation_blocker.set_callback(callback),
+            None => self.navigation_blocker = Some(NavigationBlocker::new(callback)),
+        }
+        self.is_navigation_blocker_declared = true;
+    }
+
+    #[cfg(feature = "serde")]
+    fn subscribe_to_storage<T>(&mut self, persisted: &Persisted<T>) -> Option<T>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let any_component_node = self.any_component_node.clone();
+        self.storage_observers
+            .declare(persisted.get_area(), persisted.get_key(), move || {
+                Scheduler::add_invalidate_message(any_component_node.clone())
+            });
+        persisted.get().ok().flatten()
+    }
 }
@@ -30,6 +30,11 @@ impl<IN> Callback<IN> {
     pub fn emit(&self, input: IN) {
         (self.wrapper)(input);
     }
+
+    /// Identity of the wrapped function. Clones of the same [Callback] share the identity.
+    pub(crate) fn id(&self) -> usize {
+        Rc::as_ptr(&self.wrapper) as *const () as usize
+    }
 }
 
 impl<IN> Hash for Callback<IN> {
@@ -39,6 +44,12 @@ impl<IN> Hash for Callback<IN> {
     }
 }
 
+impl<IN> PartialEq for Callback<IN> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.wrapper, &other.wrapper)
+    }
+}
+
 impl<IN> Clone for Callback<IN> {
     fn clone(&self) -> Self {
         Self {
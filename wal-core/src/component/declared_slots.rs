@@ -0,0 +1,116 @@
+/// Value which can be held by [DeclaredSlots].
+pub(crate) trait Slot: Sized {
+    /// Arguments of a single declaration of the slot.
+    type Declaration;
+
+    /// Creates the slot from its declaration.
+    fn create(declaration: Self::Declaration) -> Self;
+
+    /// Reuses the slot for its next declaration. Declaration is given back if the slot cannot be reused,
+    /// in which case the slot is replaced with the newly created one.
+    fn redeclare(&self, declaration: Self::Declaration) -> Result<(), Self::Declaration>;
+}
+
+/// Slots declared during every view of a component and identified by the order of declarations.
+/// Slot declared again at the same position is [reused](Slot::redeclare) if possible, otherwise it is replaced.
+/// Slots which were not declared during the last view are dropped, all of the slots are dropped together with the component.
+pub(crate) struct DeclaredSlots<T> {
+    slots: Vec<T>,
+    declared: usize,
+}
+
+impl<T> Default for DeclaredSlots<T> {
+    fn default() -> Self {
+        Self {
+            slots: Vec::new(),
+            declared: 0,
+        }
+    }
+}
+
+impl<T: Slot> DeclaredSlots<T> {
+    pub(crate) fn declare(&mut self, declaration: T::Declaration) -> &T {
+        let index = self.declared;
+        self.declared += 1;
+
+        match self.slots.get(index) {
+            Some(slot) => {
+                if let Err(declaration) = slot.redeclare(declaration) {
+                    self.slots[index] = T::create(declaration);
+                }
+            }
+            None => self.slots.push(T::create(declaration)),
+        }
+        &self.slots[index]
+    }
+}
+
+impl<T> DeclaredSlots<T> {
+    pub(crate) fn start_view(&mut self) {
+        self.declared = 0;
+    }
+
+    pub(crate) fn finish_view(&mut self) {
+        self.slots.truncate(self.declared);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    struct Counter {
+        id: u32,
+        redeclared: Cell<u32>,
+    }
+
+    impl Slot for Counter {
+        type Declaration = u32;
+
+        fn create(id: u32) -> Self {
+            Self {
+                id,
+                redeclared: Cell::new(0),
+            }
+        }
+
+        fn redeclare(&self, id: u32) -> Result<(), u32> {
+            if self.id != id {
+                return Err(id);
+            }
+            self.redeclared.set(self.redeclared.get() + 1);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn declare_should_reuse_replace_and_drop_slots_by_order_of_declarations() {
+        // Arrange
+        let mut slots = DeclaredSlots::<Counter>::default();
+        slots.start_view();
+        slots.declare(1);
+        slots.declare(2);
+        slots.declare(3);
+        slots.finish_view();
+
+        // Act
+        slots.start_view();
+        let first = slots.declare(1).redeclared.get();
+        let second = slots.declare(4).redeclared.get();
+        slots.finish_view();
+
+        // Assert
+        assert_eq!(first, 1);
+        assert_eq!(second, 0);
+        assert_eq!(
+            slots.slots.iter().map(|slot| slot.id).collect::<Vec<_>>(),
+            vec![1, 4]
+        );
+    }
+}
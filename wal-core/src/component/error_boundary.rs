@@ -0,0 +1,512 @@
+//! This module provides [ErrorBoundary] component, which catches the failures of its subtree and renders a [Fallback] instead.
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    error::Error,
+    fmt,
+    marker::PhantomData,
+    panic::{self, AssertUnwindSafe},
+    rc::{Rc, Weak},
+};
+
+use crate::{
+    events::{onclick, EventHandler, MouseEvent},
+    virtual_dom::{VComponent, VElement, VList, VNode, VText},
+};
+
+use super::{behavior::Behavior, callback::Callback, Component};
+
+/// Failure of a component caught by the nearest [error boundary](Behavior::catch_errors) of its ancestors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentError {
+    component: &'static str,
+    message: String,
+}
+
+impl ComponentError {
+    /// Creates [ComponentError] of the `component` with provided `message`.
+    pub fn new(component: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            component,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn from_panic(component: &'static str, payload: &(dyn Any + Send)) -> Self {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "Component panicked".to_string()
+        };
+        Self::new(component, message)
+    }
+
+    /// Returns the type name of the component, which failed.
+    pub fn get_component(&self) -> &'static str {
+        self.component
+    }
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Component {} failed: {}", self.component, self.message)
+    }
+}
+
+impl Error for ComponentError {}
+
+pub(crate) type ErrorCallback = Box<dyn Fn(ComponentError)>;
+
+/// Handler of the errors declared by the component, which is an error boundary.
+pub(crate) struct ErrorHandler {
+    callback: RefCell<ErrorCallback>,
+}
+
+impl ErrorHandler {
+    pub(crate) fn new(callback: ErrorCallback) -> Rc<Self> {
+        Rc::new(Self {
+            callback: RefCell::new(callback),
+        })
+    }
+
+    pub(crate) fn set_callback(&self, callback: ErrorCallback) {
+        *self.callback.borrow_mut() = callback;
+    }
+}
+
+thread_local! {
+    /// Error handlers of the components, which subtrees are currently being patched. The last one is the nearest.
+    static ERROR_HANDLERS: RefCell<Vec<Weak<ErrorHandler>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Returns the error handler of the nearest error boundary of the components being patched.
+/// Components created while patching inherit it.
+pub(crate) fn get_current_error_handler() -> Weak<ErrorHandler> {
+    ERROR_HANDLERS.with(|x| x.borrow().last().cloned().unwrap_or_default())
+}
+
+/// Calls `patch` with the `error_handler` being the current one.
+pub(crate) fn with_error_handler<R>(
+    error_handler: Weak<ErrorHandler>,
+    patch: impl FnOnce() -> R,
+) -> R {
+    struct PopOnDrop;
+    impl Drop for PopOnDrop {
+        fn drop(&mut self) {
+            ERROR_HANDLERS.with(|x| x.borrow_mut().pop());
+        }
+    }
+
+    ERROR_HANDLERS.with(|x| x.borrow_mut().push(error_handler));
+    let _pop_on_drop = PopOnDrop;
+    patch()
+}
+
+pub(crate) type GlobalErrorCallback = Rc<dyn Fn(&ComponentError)>;
+
+thread_local! {
+    /// Error returned by the [FallibleComponent](super::FallibleComponent), which is currently being viewed or updated.
+    static REPORTED_ERROR: RefCell<Option<ComponentError>> = const { RefCell::new(None) };
+    /// Callback of the errors, which are not caught by any error boundary. Registered by the [Router](crate::router::Router).
+    static GLOBAL_ERROR_CALLBACK: RefCell<Option<GlobalErrorCallback>> = const { RefCell::new(None) };
+}
+
+pub(crate) fn set_global_error_callback(callback: Option<GlobalErrorCallback>) {
+    GLOBAL_ERROR_CALLBACK.with(|x| *x.borrow_mut() = callback);
+}
+
+/// Reports the error of the component, which is handled by its node once the view or update returns.
+pub(crate) fn report_error(error: ComponentError) {
+    REPORTED_ERROR.with(|x| *x.borrow_mut() = Some(error));
+}
+
+pub(crate) fn take_reported_error() -> Option<ComponentError> {
+    REPORTED_ERROR.with(|x| x.borrow_mut().take())
+}
+
+/// Passes the `error` to the `error_handler` or to the global error callback if there is no error boundary.
+/// Returns the `error` back if none of them exists.
+fn dispatch_error(
+    error_handler: &Weak<ErrorHandler>,
+    error: ComponentError,
+) -> Option<ComponentError> {
+    if let Some(error_handler) = error_handler.upgrade() {
+        (*error_handler.callback.borrow())(error);
+        return None;
+    }
+    match GLOBAL_ERROR_CALLBACK.with(|x| x.borrow().clone()) {
+        Some(callback) => {
+            callback(&error);
+            None
+        }
+        None => Some(error),
+    }
+}
+
+/// Passes the `error` to the nearest error boundary. If there is no error handler at all, the error is logged to the console.
+pub(crate) fn handle_error(error_handler: &Weak<ErrorHandler>, error: ComponentError) {
+    if let Some(error) = dispatch_error(error_handler, error) {
+        web_sys::console::error_1(&error.to_string().into());
+    }
+}
+
+/// Passes the panic of the `component` to the nearest error boundary.
+/// If there is no error handler at all, the panic is resumed, as there is nothing to render instead of the component.
+pub(crate) fn handle_panic(
+    error_handler: &Weak<ErrorHandler>,
+    component: &'static str,
+    payload: Box<dyn Any + Send>,
+) {
+    let error = ComponentError::from_panic(component, payload.as_ref());
+    if dispatch_error(error_handler, error).is_some() {
+        panic::resume_unwind(payload);
+    }
+}
+
+/// Calls the function of the component, passing its reported error or panic to the nearest error boundary.
+/// Returns the result of the `call`, or the result of the `on_panic` if the `call` panicked.
+pub(crate) fn catch_failure<R>(
+    error_handler: &Weak<ErrorHandler>,
+    component: &'static str,
+    call: impl FnOnce() -> R,
+    on_panic: impl FnOnce() -> R,
+) -> R {
+    let result = panic::catch_unwind(AssertUnwindSafe(call));
+    if let Some(error) = take_reported_error() {
+        handle_error(error_handler, error);
+    }
+    result.unwrap_or_else(|payload| {
+        handle_panic(error_handler, component, payload);
+        on_panic()
+    })
+}
+
+/// Component standing in for the component, which could not be created.
+pub(crate) struct FailedComponent;
+
+impl Component for FailedComponent {
+    type Message = ();
+    type Properties = ();
+
+    fn new(_props: Self::Properties) -> Self {
+        FailedComponent
+    }
+
+    fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+        VList::new_empty(None).into()
+    }
+
+    fn update(&mut self, _message: Self::Message) -> bool {
+        false
+    }
+}
+
+/// View rendered by the [ErrorBoundary] instead of its failed subtree.
+pub trait Fallback: 'static {
+    /// Returns the view of the `error`. Emitting the `retry` callback renders the subtree again.
+    fn view(error: &ComponentError, retry: Callback<()>) -> VNode;
+}
+
+/// [Fallback] rendering the message of the error and a button retrying the render.
+pub struct DefaultFallback;
+
+impl Fallback for DefaultFallback {
+    fn view(error: &ComponentError, retry: Callback<()>) -> VNode {
+        let retry = Callback::new(move |_: MouseEvent| retry.emit(()));
+        VElement::new(
+            "div".into(),
+            [("class".into(), "wal-error-boundary".into())].into(),
+            vec![],
+            None,
+            vec![
+                VElement::new(
+                    "p".into(),
+                    [].into(),
+                    vec![],
+                    None,
+                    vec![VText::new(error).into()],
+                )
+                .into(),
+                VElement::new(
+                    "button".into(),
+                    [].into(),
+                    vec![EventHandler::new(onclick(retry))],
+                    None,
+                    vec![VText::new("Retry").into()],
+                )
+                .into(),
+            ],
+        )
+        .into()
+    }
+}
+
+#[doc(hidden)]
+pub enum ErrorBoundaryMessage {
+    Failed(ComponentError),
+    Retry,
+}
+
+/// Component rendering the component ***C*** with its [Properties](Component::Properties).
+/// When the component or any of its descendants fails, the [Fallback] ***F*** is rendered instead, until the render is retried.
+///
+/// Failures are errors returned by [fallible components](super::FallibleComponent)
+/// and panics of the [new](Component::new), [view](Component::view) and [update](Component::update) functions.
+/// Panics are caught only if the target unwinds on panic, otherwise they still abort the application.
+/// The properties are kept to render the component again, so they have to be [Clone].
+///
+/// # Examples
+///
+/// ```ignore
+/// rsx! {
+///     <ErrorBoundary<Dashboard, DefaultFallback> props={DashboardProps { user_id: 1 }} />
+/// }
+/// ```
+pub struct ErrorBoundary<C: Component, F: Fallback = DefaultFallback> {
+    props: C::Properties,
+    error: Option<ComponentError>,
+    _fallback: PhantomData<F>,
+}
+
+impl<C: Component + 'static, F: Fallback> Component for ErrorBoundary<C, F>
+where
+    C::Properties: Clone,
+{
+    type Message = ErrorBoundaryMessage;
+    type Properties = C::Properties;
+
+    fn new(props: Self::Properties) -> Self {
+        Self {
+            props,
+            error: None,
+            _fallback: PhantomData,
+        }
+    }
+
+    fn view(&self, behavior: &mut impl Behavior<Self>) -> VNode {
+        behavior.catch_errors(ErrorBoundaryMessage::Failed);
+        match &self.error {
+            Some(error) => F::view(
+                error,
+                behavior.create_callback(|_| ErrorBoundaryMessage::Retry),
+            ),
+            None => VComponent::new::<C>(self.props.clone(), None).into(),
+        }
+    }
+
+    fn update(&mut self, message: Self::Message) -> bool {
+        match message {
+            // Only the first error is kept, the following ones are most likely its consequences
+            ErrorBoundaryMessage::Failed(error) if self.error.is_none() => {
+                self.error = Some(error);
+                true
+            }
+            ErrorBoundaryMessage::Failed(_) => false,
+            ErrorBoundaryMessage::Retry => self.error.take().is_some(),
+        }
+    }
+
+    fn changed(&mut self, props: Self::Properties) -> bool {
+        self.props = props;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::component::{node::AnyComponentNode, FallibleComponent};
+
+    use super::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn create_error_handler(errors: &Rc<RefCell<Vec<ComponentError>>>) -> Rc<ErrorHandler> {
+        let errors = errors.clone();
+        ErrorHandler::new(Box::new(move |error| errors.borrow_mut().push(error)))
+    }
+
+    #[wasm_bindgen_test]
+    fn component_error_should_be_created_from_panic_message() {
+        // Arrange
+        let str_payload: Box<dyn Any + Send> = Box::new("boom");
+        let string_payload: Box<dyn Any + Send> = Box::new(String::from("boom"));
+
+        // Act
+        let str_error = ComponentError::from_panic("Test", str_payload.as_ref());
+        let string_error = ComponentError::from_panic("Test", string_payload.as_ref());
+
+        // Assert
+        assert_eq!(str_error, ComponentError::new("Test", "boom"));
+        assert_eq!(string_error, ComponentError::new("Test", "boom"));
+    }
+
+    #[wasm_bindgen_test]
+    fn current_error_handler_should_be_the_innermost_one() {
+        // Arrange
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let outer = create_error_handler(&errors);
+        let inner = create_error_handler(&errors);
+
+        // Act
+        let current = with_error_handler(Rc::downgrade(&outer), || {
+            with_error_handler(Rc::downgrade(&inner), get_current_error_handler)
+        });
+
+        // Assert
+        assert!(Weak::ptr_eq(&current, &Rc::downgrade(&inner)));
+        assert!(get_current_error_handler().upgrade().is_none());
+    }
+
+    #[wasm_bindgen_test]
+    fn handle_panic_should_pass_error_to_error_handler() {
+        // Arrange
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let error_handler = create_error_handler(&errors);
+
+        // Act
+        handle_panic(&Rc::downgrade(&error_handler), "Test", Box::new("boom"));
+
+        // Assert
+        assert_eq!(*errors.borrow(), vec![ComponentError::new("Test", "boom")]);
+    }
+
+    struct Child;
+    impl Component for Child {
+        type Message = ();
+        type Properties = ();
+        fn new(_props: Self::Properties) -> Self {
+            Child
+        }
+        fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+            VText::new("child").into()
+        }
+        fn update(&mut self, _message: Self::Message) -> bool {
+            false
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn failed_node_should_pass_error_to_inherited_error_handler() {
+        // Arrange
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let error_handler = create_error_handler(&errors);
+        let ancestor = crate::virtual_dom::dom::get_root_element();
+
+        // Act
+        let node = with_error_handler(Rc::downgrade(&error_handler), || {
+            AnyComponentNode::new_failed::<Child>(Box::new("boom"), ancestor)
+        });
+
+        // Assert
+        assert!(node.borrow().is_failed());
+        assert_eq!(
+            *errors.borrow(),
+            vec![ComponentError::new(std::any::type_name::<Child>(), "boom")]
+        );
+    }
+
+    struct Parser;
+    impl FallibleComponent for Parser {
+        type Message = &'static str;
+        type Properties = ();
+        type Error = std::num::ParseIntError;
+        fn new(_props: Self::Properties) -> Self {
+            Parser
+        }
+        fn view(&self, _behavior: &mut impl Behavior<Self>) -> Result<VNode, Self::Error> {
+            Ok(VText::new("x".parse::<u32>()?).into())
+        }
+        fn update(&mut self, message: Self::Message) -> Result<bool, Self::Error> {
+            message.parse::<u32>().map(|x| x > 0)
+        }
+    }
+
+    fn create_parser_error() -> ComponentError {
+        ComponentError::new(
+            std::any::type_name::<Parser>(),
+            "x".parse::<u32>().unwrap_err().to_string(),
+        )
+    }
+
+    #[wasm_bindgen_test]
+    fn error_of_fallible_view_should_be_passed_to_inherited_error_handler() {
+        // Arrange
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let error_handler = create_error_handler(&errors);
+        let ancestor = crate::virtual_dom::dom::get_root_element();
+        let node = with_error_handler(Rc::downgrade(&error_handler), || {
+            AnyComponentNode::new_root(Parser, ancestor)
+        });
+
+        // Act
+        node.borrow_mut().view();
+
+        // Assert
+        assert_eq!(*errors.borrow(), vec![create_parser_error()]);
+        assert!(matches!(node.borrow().vdom, Some(VNode::List(_))));
+    }
+
+    #[wasm_bindgen_test]
+    fn error_of_fallible_update_should_be_passed_to_inherited_error_handler() {
+        // Arrange
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let error_handler = create_error_handler(&errors);
+        let ancestor = crate::virtual_dom::dom::get_root_element();
+        let node = with_error_handler(Rc::downgrade(&error_handler), || {
+            AnyComponentNode::new_root(Parser, ancestor)
+        });
+
+        // Act
+        let ok = node.borrow_mut().update(Box::new("1"));
+        let failed = node.borrow_mut().update(Box::new("x"));
+
+        // Assert
+        assert!(ok);
+        assert!(!failed);
+        assert_eq!(*errors.borrow(), vec![create_parser_error()]);
+    }
+
+    #[wasm_bindgen_test]
+    fn error_without_error_handler_should_be_passed_to_global_error_callback() {
+        // Arrange
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let errors_clone = errors.clone();
+        set_global_error_callback(Some(Rc::new(move |error: &ComponentError| {
+            errors_clone.borrow_mut().push(error.clone())
+        })));
+
+        // Act
+        handle_error(&Weak::new(), ComponentError::new("Test", "boom"));
+
+        // Assert
+        assert_eq!(*errors.borrow(), vec![ComponentError::new("Test", "boom")]);
+        set_global_error_callback(None);
+    }
+
+    #[wasm_bindgen_test]
+    fn error_boundary_should_keep_first_error_until_retry() {
+        // Arrange
+        let mut boundary = ErrorBoundary::<Child>::new(());
+
+        // Act
+        let first = boundary.update(ErrorBoundaryMessage::Failed(ComponentError::new("A", "a")));
+        let second = boundary.update(ErrorBoundaryMessage::Failed(ComponentError::new("B", "b")));
+        let error = boundary.error.clone();
+        let retry = boundary.update(ErrorBoundaryMessage::Retry);
+
+        // Assert
+        assert!(first);
+        assert!(!second);
+        assert_eq!(error, Some(ComponentError::new("A", "a")));
+        assert!(retry);
+        assert!(boundary.error.is_none());
+    }
+}
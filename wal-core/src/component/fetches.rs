@@ -0,0 +1,217 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    rc::{Rc, Weak},
+};
+
+use futures::future::{abortable, AbortHandle};
+
+use crate::fetch::{self, FetchError, Request, Response};
+
+use super::declared_slots::{DeclaredSlots, Slot};
+
+type FetchResult = Result<Response, FetchError>;
+type FetchResultHandler = Box<dyn Fn(FetchResult)>;
+
+fn spawn(request: Request, on_result: impl FnOnce(FetchResult) + 'static) -> AbortHandle {
+    let (future, abort_handle) = abortable(fetch::send(request));
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Ok(result) = future.await {
+            on_result(result);
+        }
+    });
+    abort_handle
+}
+
+struct DeclaredFetch {
+    request: Request,
+    handler: Rc<RefCell<FetchResultHandler>>,
+    abort_handle: AbortHandle,
+}
+
+impl DeclaredFetch {
+    fn new(request: Request, handler: FetchResultHandler) -> Self {
+        let handler = Rc::new(RefCell::new(handler));
+        let weak_handler = Rc::downgrade(&handler);
+        let abort_handle = spawn(request.clone(), move |result| {
+            if let Some(handler) = weak_handler.upgrade() {
+                (*handler.borrow())(result);
+            }
+        });
+        Self {
+            request,
+            handler,
+            abort_handle,
+        }
+    }
+}
+
+impl Drop for DeclaredFetch {
+    fn drop(&mut self) {
+        self.abort_handle.abort();
+    }
+}
+
+/// Requests sent by the callbacks created by a component, which are still pending.
+#[derive(Default)]
+pub(crate) struct SentFetches {
+    next_id: u64,
+    abort_handles: HashMap<u64, AbortHandle>,
+}
+
+impl SentFetches {
+    pub(crate) fn send(this: &Rc<RefCell<Self>>, request: Request, handler: FetchResultHandler) {
+        let mut sent_fetches = this.borrow_mut();
+        let id = sent_fetches.next_id;
+        sent_fetches.next_id += 1;
+
+        let weak_sent_fetches = Rc::downgrade(this);
+        let abort_handle = spawn(request, move |result| {
+            if let Some(sent_fetches) = weak_sent_fetches.upgrade() {
+                sent_fetches.borrow_mut().abort_handles.remove(&id);
+                handler(result);
+            }
+        });
+        sent_fetches.abort_handles.insert(id, abort_handle);
+    }
+}
+
+impl Drop for SentFetches {
+    fn drop(&mut self) {
+        self.abort_handles.values().for_each(AbortHandle::abort);
+    }
+}
+
+impl Slot for DeclaredFetch {
+    type Declaration = (Request, FetchResultHandler);
+
+    fn create((request, handler): Self::Declaration) -> Self {
+        DeclaredFetch::new(request, handler)
+    }
+
+    fn redeclare(&self, (request, handler): Self::Declaration) -> Result<(), Self::Declaration> {
+        if self.request != request {
+            return Err((request, handler));
+        }
+        *self.handler.borrow_mut() = handler;
+        Ok(())
+    }
+}
+
+/// Requests of a component held in [DeclaredSlots]. Request declared again with the same [Request] is not sent again,
+/// otherwise the pending one is aborted and the new one is sent. Dropped requests are aborted.
+#[derive(Default)]
+pub(crate) struct Fetches {
+    declared_fetches: DeclaredSlots<DeclaredFetch>,
+    sent_fetches: Rc<RefCell<SentFetches>>,
+}
+
+impl Fetches {
+    pub(crate) fn declare(&mut self, request: Request, handler: FetchResultHandler) {
+        self.declared_fetches.declare((request, handler));
+    }
+
+    pub(crate) fn get_sent_fetches(&self) -> Weak<RefCell<SentFetches>> {
+        Rc::downgrade(&self.sent_fetches)
+    }
+
+    pub(crate) fn start_view(&mut self) {
+        self.declared_fetches.start_view();
+    }
+
+    pub(crate) fn finish_view(&mut self) {
+        self.declared_fetches.finish_view();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::fetch::{set_transport, FetchTransport};
+
+    use super::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    async fn next_tick() {
+        wasm_bindgen_futures::JsFuture::from(js_sys::Promise::resolve(
+            &wasm_bindgen::JsValue::NULL,
+        ))
+        .await
+        .unwrap();
+    }
+
+    fn create_result_handler(results: &Rc<RefCell<Vec<FetchResult>>>) -> FetchResultHandler {
+        let results = results.clone();
+        Box::new(move |result| results.borrow_mut().push(result))
+    }
+
+    #[wasm_bindgen_test]
+    async fn declared_fetch_should_deliver_response() {
+        // Arrange
+        set_transport(|request: Request| Ok(Response::new(200, request.get_url())));
+        let results = Rc::new(RefCell::new(Vec::new()));
+        let mut fetches = Fetches::default();
+
+        // Act
+        fetches.start_view();
+        fetches.declare(Request::get("/todos"), create_result_handler(&results));
+        fetches.finish_view();
+        next_tick().await;
+
+        // Assert
+        assert_eq!(*results.borrow(), vec![Ok(Response::new(200, "/todos"))]);
+        set_transport(FetchTransport::default());
+    }
+
+    #[wasm_bindgen_test]
+    async fn fetch_declared_again_with_the_same_request_should_not_be_sent_again() {
+        // Arrange
+        let sent = Rc::new(RefCell::new(0));
+        let sent_clone = sent.clone();
+        set_transport(move |_: Request| {
+            *sent_clone.borrow_mut() += 1;
+            Ok(Response::new(200, ""))
+        });
+        let results = Rc::new(RefCell::new(Vec::new()));
+        let mut fetches = Fetches::default();
+
+        // Act
+        for _ in 0..2 {
+            fetches.start_view();
+            fetches.declare(Request::get("/todos"), create_result_handler(&results));
+            fetches.finish_view();
+        }
+        next_tick().await;
+
+        // Assert
+        assert_eq!(*sent.borrow(), 1);
+        set_transport(FetchTransport::default());
+    }
+
+    #[wasm_bindgen_test]
+    async fn dropped_fetches_should_not_deliver_responses() {
+        // Arrange
+        set_transport(|_: Request| Ok(Response::new(200, "")));
+        let results = Rc::new(RefCell::new(Vec::new()));
+        let mut fetches = Fetches::default();
+        fetches.start_view();
+        fetches.declare(Request::get("/todos"), create_result_handler(&results));
+        fetches.finish_view();
+        SentFetches::send(
+            &fetches.sent_fetches,
+            Request::get("/todos"),
+            create_result_handler(&results),
+        );
+
+        // Act
+        drop(fetches);
+        next_tick().await;
+
+        // Assert
+        assert!(results.borrow().is_empty());
+        set_transport(FetchTransport::default());
+    }
+}
@@ -1,8 +1,7 @@
 //! This module provides implementations of [Behavior](./behavior/trait.Behavior.html) trait and [Callbacks](./callback/struct.Callback.html) structs.
 
-use crate::virtual_dom::VNode;
-use std::any::Any;
-use std::hash::Hash;
+use crate::virtual_dom::{VList, VNode};
+use std::{any::Any, fmt};
 
 use self::behavior::AnyComponentBehavior;
 
@@ -10,12 +9,24 @@ use self::behavior::AnyComponentBehavior;
 pub mod behavior;
 #[doc(hidden)]
 pub mod callback;
+#[doc(hidden)]
+pub mod error_boundary;
 
+pub(crate) mod declared_slots;
+pub(crate) mod fetches;
 pub(crate) mod node;
 pub(crate) mod scheduler;
+pub(crate) mod sockets;
+#[cfg(feature = "serde")]
+pub(crate) mod storage_observers;
+pub(crate) mod subscription;
+#[doc(hidden)]
+pub mod timer;
 
 pub use self::behavior::Behavior;
 pub use self::callback::Callback;
+pub use self::error_boundary::{ComponentError, DefaultFallback, ErrorBoundary, Fallback};
+pub use self::timer::TimerHandle;
 
 /// Trait for defining custom component.
 ///
@@ -30,7 +41,11 @@ pub trait Component: Sized {
 
     /// Type to describe the properties that can be passed to the component.
     /// It is used to initialize the model of the component.
-    type Properties: Hash + 'static;
+    /// If the properties are [Clone], the ones from the last render are kept and compared with the new ones using [PartialEq],
+    /// so the component is notified through [changed](#method.changed) only if they differ.
+    /// Otherwise they are moved to the component, which is notified on every rerender of its parent.
+    /// Properties are never hashed, so they do not have to implement [Hash](std::hash::Hash).
+    type Properties: PartialEq + 'static;
 
     /// Function that creates a new instance of the component therefore initialize a model using [Properties](#associatedtype.Properties).
     fn new(props: Self::Properties) -> Self;
@@ -45,24 +60,124 @@ pub trait Component: Sized {
     /// It returns a boolean that indicates if the rerender of the component is necessary.
     /// Meaning whether the view of the component should be updated or not.
     fn update(&mut self, message: Self::Message) -> bool;
+
+    /// Function that is called when the parent rerenders the component with new [Properties](#associatedtype.Properties).
+    /// It returns a boolean that indicates if the rerender of the component is necessary.
+    ///
+    /// By default the model is reinitialized using [new](#tymethod.new) function, so the local state of the component, e.g. a draft of a text field, is lost.
+    /// Components that should keep their local state across properties changes have to override this function
+    /// and update only the part of the model that depends on the properties, as the component instance itself is kept.
+    /// Rerenders of the parent with [Clone] properties equal to the last ones do not call this function, so the local state is kept anyway.
+    fn changed(&mut self, props: Self::Properties) -> bool {
+        *self = Self::new(props);
+        true
+    }
+}
+
+/// Trait for defining custom component, which [view](#tymethod.view) and [update](#tymethod.update) could fail.
+///
+/// Every fallible component is a [Component] as well, so it is used in the same way.
+/// Returned errors are passed to the nearest [error boundary](Behavior::catch_errors) of the ancestors,
+/// or to the [error callback](crate::router::RouterBuilder::on_error) of the router if there is none.
+/// When the view fails, nothing is rendered in place of the component.
+///
+/// # Examples
+///
+/// ```ignore
+/// impl FallibleComponent for Profile {
+///     type Message = Message;
+///     type Properties = ();
+///     type Error = FetchError;
+///
+///     fn new(_props: Self::Properties) -> Self { Profile { response: None } }
+///
+///     fn view(&self, behavior: &mut impl Behavior<Self>) -> Result<VNode, Self::Error> {
+///         behavior.fetch(Request::get("/api/profile"), Message::Loaded);
+///         let name = match &self.response {
+///             Some(response) => response.clone().error_for_status()?.text().to_string(),
+///             None => String::new(),
+///         };
+///         Ok(rsx! { <h1>{ name }</h1> })
+///     }
+///
+///     fn update(&mut self, message: Self::Message) -> Result<bool, Self::Error> {
+///         let Message::Loaded(response) = message;
+///         self.response = Some(response?);
+///         Ok(true)
+///     }
+/// }
+/// ```
+pub trait FallibleComponent: Sized {
+    /// Type to describe the message that can be sent to the component. See [Message](Component::Message).
+    type Message: 'static;
+
+    /// Type to describe the properties that can be passed to the component. See [Properties](Component::Properties).
+    type Properties: PartialEq + 'static;
+
+    /// Type of the errors returned by the component. Errors are reported using their [Display](fmt::Display) implementation.
+    type Error: fmt::Display;
+
+    /// Function that creates a new instance of the component. See [new](Component::new).
+    fn new(props: Self::Properties) -> Self;
+
+    /// Function that returns a view of the component or the error. See [view](Component::view).
+    fn view(&self, behavior: &mut impl Behavior<Self>) -> Result<VNode, Self::Error>;
+
+    /// Function that updates the model of the component or returns the error. See [update](Component::update).
+    fn update(&mut self, message: Self::Message) -> Result<bool, Self::Error>;
+
+    /// Function that is called when the parent rerenders the component with new properties. See [changed](Component::changed).
+    fn changed(&mut self, props: Self::Properties) -> Result<bool, Self::Error> {
+        *self = Self::new(props);
+        Ok(true)
+    }
+}
+
+fn report_error<C: FallibleComponent>(error: C::Error) {
+    error_boundary::report_error(ComponentError::new(
+        std::any::type_name::<C>(),
+        error.to_string(),
+    ));
+}
+
+impl<C: FallibleComponent> Component for C {
+    type Message = <C as FallibleComponent>::Message;
+    type Properties = <C as FallibleComponent>::Properties;
+
+    fn new(props: Self::Properties) -> Self {
+        <C as FallibleComponent>::new(props)
+    }
+
+    fn view(&self, behavior: &mut impl Behavior<Self>) -> VNode {
+        FallibleComponent::view(self, behavior).unwrap_or_else(|error| {
+            report_error::<C>(error);
+            VList::new_empty(None).into()
+        })
+    }
+
+    fn update(&mut self, message: Self::Message) -> bool {
+        FallibleComponent::update(self, message).unwrap_or_else(|error| {
+            report_error::<C>(error);
+            false
+        })
+    }
+
+    fn changed(&mut self, props: Self::Properties) -> bool {
+        FallibleComponent::changed(self, props).unwrap_or_else(|error| {
+            report_error::<C>(error);
+            false
+        })
+    }
 }
 
 pub(crate) trait AnyComponent {
-    fn new(props: Box<dyn Any>) -> Self
-    where
-        Self: Sized;
     fn view(&self, behavior: &mut AnyComponentBehavior) -> VNode;
     fn update(&mut self, message: Box<dyn Any>) -> bool;
+    fn changed(&mut self, props: Box<dyn Any>) -> bool;
+    fn name(&self) -> &'static str;
 }
 
 impl<C: Component> AnyComponent for C {
-    fn new(props: Box<dyn Any>) -> Self {
-        let props = *props.downcast::<C::Properties>().expect(
-            "Failed to downcast properties in any component to properties of a real component",
-        );
-        C::new(props)
-    }
-
     fn view(&self, any_component_behavior: &mut AnyComponentBehavior) -> VNode {
         self.view(any_component_behavior)
     }
@@ -73,4 +188,15 @@ impl<C: Component> AnyComponent for C {
             .expect("Failed to downcast message in any component to message of a real component");
         self.update(msg)
     }
+
+    fn changed(&mut self, props: Box<dyn Any>) -> bool {
+        let props = *props.downcast::<C::Properties>().expect(
+            "Failed to downcast properties in any component to properties of a real component",
+        );
+        self.changed(props)
+    }
+
+    fn name(&self) -> &'static str {
+        std::any::type_name::<C>()
+    }
 }
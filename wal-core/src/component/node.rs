@@ -1,8 +1,20 @@
-use crate::virtual_dom::VNode;
-use std::{any::Any, cell::RefCell, fmt, rc::Rc};
+use crate::{
+    router::active_links,
+    virtual_dom::{VList, VNode},
+};
+use std::{
+    any::Any,
+    cell::RefCell,
+    fmt,
+    rc::{Rc, Weak},
+};
 use web_sys::Node;
 
-use super::{behavior::AnyComponentBehavior, AnyComponent, Component};
+use super::{
+    behavior::AnyComponentBehavior,
+    error_boundary::{self, ErrorHandler, FailedComponent},
+    AnyComponent, Component,
+};
 
 pub(crate) struct AnyComponentNode {
     component: Box<dyn AnyComponent>,
@@ -11,6 +23,9 @@ pub(crate) struct AnyComponentNode {
     behavior: AnyComponentBehavior,
     pub vdom: Option<VNode>,
     ancestor: Node,
+    /// Error handler of the nearest error boundary of the ancestors.
+    error_handler: Weak<ErrorHandler>,
+    is_failed: bool,
 }
 
 impl AnyComponentNode {
@@ -18,7 +33,21 @@ impl AnyComponentNode {
         Self::new_internal(component, ancestor, false, None)
     }
 
-    pub(crate) fn new_root_routing<C: Component + 'static>(
+    /// Creates node standing in for the component ***C***, which could not be created because of the panic.
+    pub(crate) fn new_failed<C: Component + 'static>(
+        payload: Box<dyn Any + Send>,
+        ancestor: Node,
+    ) -> Rc<RefCell<Self>> {
+        let node = Self::new(FailedComponent, ancestor);
+        {
+            let mut node = node.borrow_mut();
+            node.is_failed = true;
+            error_boundary::handle_panic(&node.error_handler, std::any::type_name::<C>(), payload);
+        }
+        node
+    }
+
+    pub(crate) fn new_root<C: Component + 'static>(
         component: C,
         ancestor: Node,
     ) -> Rc<RefCell<Self>> {
@@ -41,6 +70,8 @@ impl AnyComponentNode {
             behavior,
             vdom: None,
             ancestor,
+            error_handler: error_boundary::get_current_error_handler(),
+            is_failed: false,
         };
 
         let node_rc = Rc::new(RefCell::new(node));
@@ -63,19 +94,51 @@ impl AnyComponentNode {
 
     pub(crate) fn view_and_patch(&mut self) {
         let mut new_vdom = self.view_internal();
-        new_vdom.patch(self.vdom.take(), &self.ancestor);
+        error_boundary::with_error_handler(self.get_children_error_handler(), || {
+            new_vdom.patch(self.vdom.take(), &self.ancestor)
+        });
         self.vdom = Some(new_vdom);
         self.to_rerender = false;
     }
 
     fn view_internal(&mut self) -> VNode {
-        let mut new_vdom = self.component.view(&mut self.behavior);
+        self.behavior.start_view();
+        let any_component_node = self.behavior.get_any_component_node();
+        let mut new_vdom = active_links::with_viewed_component(any_component_node, || {
+            error_boundary::catch_failure(
+                &self.error_handler,
+                self.component.name(),
+                || self.component.view(&mut self.behavior),
+                || VList::new_empty(None).into(),
+            )
+        });
+        self.behavior.finish_view();
         new_vdom.set_depth(self.depth.unwrap() + 1);
         new_vdom
     }
 
+    /// Returns the error handler of the nearest error boundary of the children, which is this component if it declared one.
+    fn get_children_error_handler(&self) -> Weak<ErrorHandler> {
+        self.behavior
+            .get_error_handler()
+            .unwrap_or_else(|| self.error_handler.clone())
+    }
+
+    pub(crate) fn is_failed(&self) -> bool {
+        self.is_failed
+    }
+
+    pub(crate) fn is_to_rerender(&self) -> bool {
+        self.to_rerender
+    }
+
     pub(crate) fn update(&mut self, message: Box<dyn Any>) -> bool {
-        let to_rerender = self.component.update(message);
+        let to_rerender = error_boundary::catch_failure(
+            &self.error_handler,
+            self.component.name(),
+            || self.component.update(message),
+            || false,
+        );
         if !self.to_rerender && to_rerender {
             self.to_rerender = true;
             return true;
@@ -83,10 +146,38 @@ impl AnyComponentNode {
         false
     }
 
+    pub(crate) fn invalidate(&mut self) -> bool {
+        if !self.to_rerender {
+            self.to_rerender = true;
+            return true;
+        }
+        false
+    }
+
+    pub(crate) fn changed(&mut self, props: Box<dyn Any>) -> bool {
+        error_boundary::catch_failure(
+            &self.error_handler,
+            self.component.name(),
+            || self.component.changed(props),
+            || false,
+        )
+    }
+
     pub fn patch(
         &mut self,
         last_component_node: Option<Rc<RefCell<AnyComponentNode>>>,
         ancestor: &Node,
+    ) {
+        let error_handler = self.get_children_error_handler();
+        error_boundary::with_error_handler(error_handler, || {
+            self.patch_internal(last_component_node, ancestor)
+        });
+    }
+
+    fn patch_internal(
+        &mut self,
+        last_component_node: Option<Rc<RefCell<AnyComponentNode>>>,
+        ancestor: &Node,
     ) {
         if let Some(last_component_node) = last_component_node {
             let last_component_node = last_component_node.clone();
@@ -1,14 +1,23 @@
-use std::{any::Any, cell::RefCell, collections::BinaryHeap, rc::Weak};
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::{BinaryHeap, HashSet},
+    rc::Weak,
+};
 
 use wasm_bindgen_futures::spawn_local;
 
-use crate::utils::any_utils::raw_memory_compare;
+use crate::utils::any_utils::any_eq;
 
 use super::node::AnyComponentNode;
 
+/// Function comparing two type-erased messages. Update messages are deduplicated only if they have one.
+pub(crate) type MessageComparator = fn(&dyn Any, &dyn Any) -> bool;
+
 #[derive(Debug)]
 enum SchedulerMessage {
     Update(UpdateMessage),
+    Invalidate(InvalidateMessage),
     Rerender(RerenderMessage),
 }
 
@@ -16,15 +25,16 @@ impl SchedulerMessage {
     fn handle(self) {
         match self {
             SchedulerMessage::Update(msg) => msg.handle(),
+            SchedulerMessage::Invalidate(msg) => msg.handle(),
             SchedulerMessage::Rerender(msg) => msg.handle(),
         }
     }
 }
 
-#[derive(Debug)]
 struct UpdateMessage {
     message: Box<dyn Any>,
     any_component_node: Weak<RefCell<AnyComponentNode>>,
+    comparator: Option<MessageComparator>,
 }
 
 impl UpdateMessage {
@@ -41,6 +51,35 @@ impl UpdateMessage {
     }
 }
 
+impl std::fmt::Debug for UpdateMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UpdateMessage")
+            .field("any_component_node", &self.any_component_node)
+            .field("is_deduplicated", &self.comparator.is_some())
+            .finish()
+    }
+}
+
+/// Marks the component to be rerendered without updating it, e.g. when the external state it reads has changed.
+#[derive(Debug)]
+struct InvalidateMessage {
+    any_component_node: Weak<RefCell<AnyComponentNode>>,
+}
+
+impl InvalidateMessage {
+    fn handle(self) {
+        if let Some(any_component_node) = self.any_component_node.upgrade() {
+            let to_rerender = any_component_node.borrow_mut().invalidate();
+            if to_rerender {
+                Scheduler::add_rerender_message(
+                    self.any_component_node,
+                    any_component_node.borrow().depth.expect("Depth is not set"),
+                );
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 struct RerenderMessage {
     any_component_node: Weak<RefCell<AnyComponentNode>>,
@@ -50,7 +89,11 @@ struct RerenderMessage {
 impl RerenderMessage {
     fn handle(self) {
         if let Some(any_component_node) = self.any_component_node.upgrade() {
-            any_component_node.borrow_mut().view_and_patch();
+            let mut any_component_node = any_component_node.borrow_mut();
+            // Node could have been already patched in this tick, for example by its parent
+            if any_component_node.is_to_rerender() {
+                any_component_node.view_and_patch();
+            }
         }
     }
 }
@@ -60,7 +103,15 @@ impl PartialEq for SchedulerMessage {
         match (self, other) {
             (Self::Update(s_msg), Self::Update(o_msg)) => {
                 Weak::ptr_eq(&s_msg.any_component_node, &o_msg.any_component_node)
-                    && raw_memory_compare(&s_msg.message, &o_msg.message)
+                    && match (s_msg.comparator, o_msg.comparator) {
+                        (Some(comparator), Some(_)) => {
+                            comparator(s_msg.message.as_ref(), o_msg.message.as_ref())
+                        }
+                        _ => false,
+                    }
+            }
+            (Self::Invalidate(s_msg), Self::Invalidate(o_msg)) => {
+                Weak::ptr_eq(&s_msg.any_component_node, &o_msg.any_component_node)
             }
             (Self::Rerender(s_msg), Self::Rerender(o_msg)) => {
                 Weak::ptr_eq(&s_msg.any_component_node, &o_msg.any_component_node)
@@ -81,23 +132,60 @@ impl PartialOrd for SchedulerMessage {
 
 impl Ord for SchedulerMessage {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Invalidations are handled together with updates, as both of them only mark components to be rerendered
         match (self, other) {
-            (Self::Update(_), Self::Rerender(_)) => std::cmp::Ordering::Greater,
-            (Self::Rerender(_), Self::Update(_)) => std::cmp::Ordering::Less,
-            (Self::Update(_), Self::Update(_)) => std::cmp::Ordering::Equal,
             (Self::Rerender(s_msg), Self::Rerender(o_msg)) => {
                 s_msg.depth.cmp(&o_msg.depth).reverse()
             }
+            (Self::Rerender(_), _) => std::cmp::Ordering::Less,
+            (_, Self::Rerender(_)) => std::cmp::Ordering::Greater,
+            _ => std::cmp::Ordering::Equal,
         }
     }
 }
 
+/// Message waiting in the queue. Messages of the same priority are handled in the order they were added.
+#[derive(Debug)]
+struct QueuedMessage {
+    message: SchedulerMessage,
+    sequence: u64,
+}
+
+impl PartialEq for QueuedMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for QueuedMessage {}
+
+impl PartialOrd for QueuedMessage {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueuedMessage {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.message
+            .cmp(&other.message)
+            .then_with(|| self.sequence.cmp(&other.sequence).reverse())
+    }
+}
+
+type NodePtr = *const RefCell<AnyComponentNode>;
+
 thread_local! {
     pub(crate) static SCHEDULER_INSTANCE: RefCell<Scheduler> = RefCell::new(Scheduler::new());
 }
 
 pub(crate) struct Scheduler {
-    messages: BinaryHeap<SchedulerMessage>,
+    messages: BinaryHeap<QueuedMessage>,
+    next_sequence: u64,
+    /// Components with a queued invalidate message, so they are not invalidated twice in one tick.
+    invalidated: HashSet<NodePtr>,
+    /// Components with a queued rerender message and their depth, so they are not rerendered twice in one tick.
+    rerendered: HashSet<(NodePtr, u32)>,
     is_handle_messages_scheduled: bool,
 }
 
@@ -105,6 +193,9 @@ impl Scheduler {
     fn new() -> Self {
         Self {
             messages: BinaryHeap::new(),
+            next_sequence: 0,
+            invalidated: HashSet::new(),
+            rerendered: HashSet::new(),
             is_handle_messages_scheduled: false,
         }
     }
@@ -121,7 +212,12 @@ impl Scheduler {
     fn handle_messages() {
         let scheduler_messages: Vec<SchedulerMessage> = SCHEDULER_INSTANCE.with(|scheduler| {
             let mut scheduler = scheduler.borrow_mut();
-            let messages = scheduler.messages.drain().collect();
+            // Popping instead of draining keeps the priority order: updates first, then rerenders from the top of the tree
+            let messages = std::iter::from_fn(|| scheduler.messages.pop())
+                .map(|queued| queued.message)
+                .collect();
+            scheduler.invalidated.clear();
+            scheduler.rerendered.clear();
             scheduler.is_handle_messages_scheduled = false;
             messages
         });
@@ -138,10 +234,31 @@ impl Scheduler {
         let message = SchedulerMessage::Update(UpdateMessage {
             message,
             any_component_node,
+            comparator: None,
         });
         Self::add_message(message);
     }
 
+    /// Adds update message, which is skipped if an equal message for the same component is already waiting in the queue.
+    pub(crate) fn add_deduplicated_update_message<M: PartialEq + 'static>(
+        message: M,
+        any_component_node: Weak<RefCell<AnyComponentNode>>,
+    ) {
+        let message = SchedulerMessage::Update(UpdateMessage {
+            message: Box::new(message),
+            any_component_node,
+            comparator: Some(any_eq::<M>),
+        });
+        Self::add_message(message);
+    }
+
+    /// Adds message marking the component to be rerendered, even though it was not updated.
+    #[cfg_attr(not(feature = "serde"), allow(dead_code))]
+    pub(crate) fn add_invalidate_message(any_component_node: Weak<RefCell<AnyComponentNode>>) {
+        let message = SchedulerMessage::Invalidate(InvalidateMessage { any_component_node });
+        Self::add_message(message);
+    }
+
     pub(crate) fn add_rerender_message(
         any_component_node: Weak<RefCell<AnyComponentNode>>,
         depth: u32,
@@ -153,10 +270,35 @@ impl Scheduler {
         Self::add_message(message);
     }
 
+    /// Checks whether the equal message is already queued and remembers the message otherwise.
+    /// Only deduplicated update messages have to be compared with the queued ones.
+    fn is_queued(&mut self, message: &SchedulerMessage) -> bool {
+        match message {
+            SchedulerMessage::Update(UpdateMessage {
+                comparator: None, ..
+            }) => false,
+            SchedulerMessage::Update(_) => self
+                .messages
+                .iter()
+                .any(|queued| queued.message == *message),
+            SchedulerMessage::Invalidate(msg) => {
+                !self.invalidated.insert(msg.any_component_node.as_ptr())
+            }
+            SchedulerMessage::Rerender(msg) => !self
+                .rerendered
+                .insert((msg.any_component_node.as_ptr(), msg.depth)),
+        }
+    }
+
     fn add_message(message: SchedulerMessage) {
         SCHEDULER_INSTANCE.with(|scheduler| {
             let mut scheduler = scheduler.borrow_mut();
-            scheduler.messages.push(message);
+            if scheduler.is_queued(&message) {
+                return;
+            }
+            let sequence = scheduler.next_sequence;
+            scheduler.next_sequence += 1;
+            scheduler.messages.push(QueuedMessage { message, sequence });
             scheduler.schedule_handle_messages();
         });
     }
@@ -267,6 +409,8 @@ mod tests {
         SCHEDULER_INSTANCE.with(|scheduler| {
             let mut scheduler = scheduler.borrow_mut();
             scheduler.messages.clear();
+            scheduler.invalidated.clear();
+            scheduler.rerendered.clear();
             scheduler.is_handle_messages_scheduled = false;
         });
     }
@@ -276,7 +420,7 @@ mod tests {
     ) -> Rc<RefCell<AnyComponentNode>> {
         let ancestor = get_body();
         let component = T::new(props);
-        let anycomp = AnyComponentNode::new_root_routing(component, ancestor);
+        let anycomp = AnyComponentNode::new_root(component, ancestor);
         anycomp.borrow_mut().view_and_patch();
         anycomp
     }
@@ -284,11 +428,27 @@ mod tests {
     fn create_update_message<T: Component>(
         message: T::Message,
         any_component_node: &Rc<RefCell<AnyComponentNode>>,
+    ) -> SchedulerMessage
+    where
+        T::Message: PartialEq,
+    {
+        let weak_component_node = Rc::downgrade(any_component_node);
+        SchedulerMessage::Update(UpdateMessage {
+            message: Box::new(message),
+            any_component_node: weak_component_node,
+            comparator: Some(any_eq::<T::Message>),
+        })
+    }
+
+    fn create_not_deduplicated_update_message<T: Component>(
+        message: T::Message,
+        any_component_node: &Rc<RefCell<AnyComponentNode>>,
     ) -> SchedulerMessage {
         let weak_component_node = Rc::downgrade(any_component_node);
         SchedulerMessage::Update(UpdateMessage {
             message: Box::new(message),
             any_component_node: weak_component_node,
+            comparator: None,
         })
     }
 
@@ -328,6 +488,21 @@ mod tests {
         assert_ne!(update_message1, update_message2);
     }
 
+    #[wasm_bindgen_test]
+    fn not_deduplicated_update_messages_from_the_same_component_and_the_same_message_should_not_be_equal(
+    ) {
+        // Arrange
+        let component_node = create_any_component_node::<TestComponent>(());
+        let message = 0;
+        let update_message1 =
+            create_not_deduplicated_update_message::<TestComponent>(message, &component_node);
+        let update_message2 =
+            create_not_deduplicated_update_message::<TestComponent>(message, &component_node);
+
+        // Act & Assert
+        assert_ne!(update_message1, update_message2);
+    }
+
     #[wasm_bindgen_test]
     fn update_messages_from_different_component_type_should_not_be_equal() {
         // Arrange
@@ -524,7 +699,7 @@ mod tests {
             let scheduler = scheduler.borrow();
             assert_eq!(scheduler.messages.len(), 1);
             assert!(scheduler.is_handle_messages_scheduled);
-            let rerender_message = scheduler.messages.iter().next().unwrap();
+            let rerender_message = &scheduler.messages.iter().next().unwrap().message;
             assert_eq!(rerender_message, &expected_rerender_message);
         });
     }
@@ -570,6 +745,32 @@ mod tests {
         });
     }
 
+    // Tests invalidate message handle
+
+    #[wasm_bindgen_test]
+    fn handle_invalidate_message_should_add_rerender_message_to_schedulers_queue() {
+        // Arrange
+        clear_scheduler();
+        let component_node = create_any_component_node::<UpdateReturnsFalseComponent>(());
+        let expected_rerender_message = create_rerender_message(&component_node, 0);
+
+        // Act
+        Scheduler::add_invalidate_message(Rc::downgrade(&component_node));
+        Scheduler::add_invalidate_message(Rc::downgrade(&component_node));
+        let invalidate_message =
+            SCHEDULER_INSTANCE.with(|scheduler| scheduler.borrow_mut().messages.pop().unwrap());
+        let invalidate_message = invalidate_message.message;
+        invalidate_message.handle();
+
+        // Assert
+        SCHEDULER_INSTANCE.with(|scheduler| {
+            let scheduler = scheduler.borrow();
+            assert_eq!(scheduler.messages.len(), 1);
+            let rerender_message = &scheduler.messages.iter().next().unwrap().message;
+            assert_eq!(rerender_message, &expected_rerender_message);
+        });
+    }
+
     // Tests schedule_handle_messages
 
     #[wasm_bindgen_test]
@@ -593,25 +794,119 @@ mod tests {
         // Arrange
         clear_scheduler();
         let component_node = create_any_component_node::<TestComponent>(());
-        let message = 0;
+        let message_boxed = Box::new(0);
+        let weak_component_node = Rc::downgrade(&component_node);
+
+        // Act
+        Scheduler::add_update_message(message_boxed, weak_component_node);
+
+        // Assert
+        SCHEDULER_INSTANCE.with(|scheduler| {
+            let scheduler = scheduler.borrow();
+            assert_eq!(scheduler.messages.len(), 1);
+            assert!(scheduler.is_handle_messages_scheduled);
+        });
+    }
+
+    #[wasm_bindgen_test]
+    fn add_update_message_twice_with_the_same_message_should_add_both_messages_to_schedulers_queue()
+    {
+        // Arrange
+        clear_scheduler();
+        let component_node = create_any_component_node::<TestComponent>(());
+
+        // Act
+        Scheduler::add_update_message(Box::new(0), Rc::downgrade(&component_node));
+        Scheduler::add_update_message(Box::new(0), Rc::downgrade(&component_node));
+
+        // Assert
+        SCHEDULER_INSTANCE.with(|scheduler| {
+            assert_eq!(scheduler.borrow().messages.len(), 2);
+        });
+    }
+
+    #[wasm_bindgen_test]
+    fn add_deduplicated_update_message_should_add_update_message_to_schedulers_queue() {
+        // Arrange
+        clear_scheduler();
+        let component_node = create_any_component_node::<TestComponent>(());
+        let message: i8 = 0;
         let expected_update_message =
             create_update_message::<TestComponent>(message, &component_node);
-        let message_boxed = Box::new(message);
         let weak_component_node = Rc::downgrade(&component_node);
 
         // Act
-        Scheduler::add_update_message(message_boxed, weak_component_node);
+        Scheduler::add_deduplicated_update_message(message, weak_component_node);
 
         // Assert
         SCHEDULER_INSTANCE.with(|scheduler| {
             let scheduler = scheduler.borrow();
             assert_eq!(scheduler.messages.len(), 1);
             assert!(scheduler.is_handle_messages_scheduled);
-            let update_message = scheduler.messages.iter().next().unwrap();
+            let update_message = &scheduler.messages.iter().next().unwrap().message;
             assert_eq!(update_message, &expected_update_message);
         });
     }
 
+    #[wasm_bindgen_test]
+    fn add_deduplicated_update_message_twice_with_the_same_message_should_add_only_one_message_to_schedulers_queue(
+    ) {
+        // Arrange
+        clear_scheduler();
+        let component_node = create_any_component_node::<TestComponent>(());
+
+        // Act
+        Scheduler::add_deduplicated_update_message(0_i8, Rc::downgrade(&component_node));
+        Scheduler::add_deduplicated_update_message(0_i8, Rc::downgrade(&component_node));
+        Scheduler::add_deduplicated_update_message(1_i8, Rc::downgrade(&component_node));
+
+        // Assert
+        SCHEDULER_INSTANCE.with(|scheduler| {
+            assert_eq!(scheduler.borrow().messages.len(), 2);
+        });
+    }
+
+    #[wasm_bindgen_test]
+    fn handle_messages_should_pop_update_messages_in_the_order_they_were_added() {
+        // Arrange
+        clear_scheduler();
+        let component_node = create_any_component_node::<TestComponent>(());
+
+        // Act
+        for message in 0..6_i8 {
+            Scheduler::add_update_message(Box::new(message), Rc::downgrade(&component_node));
+        }
+        let messages: Vec<i8> = SCHEDULER_INSTANCE.with(|scheduler| {
+            let mut scheduler = scheduler.borrow_mut();
+            std::iter::from_fn(|| scheduler.messages.pop())
+                .map(|queued| match queued.message {
+                    SchedulerMessage::Update(msg) => *msg.message.downcast::<i8>().unwrap(),
+                    _ => panic!("Expected update message"),
+                })
+                .collect()
+        });
+
+        // Assert
+        assert_eq!(messages, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[wasm_bindgen_test]
+    fn add_rerender_message_twice_for_the_same_component_should_add_only_one_message_to_schedulers_queue(
+    ) {
+        // Arrange
+        clear_scheduler();
+        let component_node = create_any_component_node::<TestComponent>(());
+
+        // Act
+        Scheduler::add_rerender_message(Rc::downgrade(&component_node), 0);
+        Scheduler::add_rerender_message(Rc::downgrade(&component_node), 0);
+
+        // Assert
+        SCHEDULER_INSTANCE.with(|scheduler| {
+            assert_eq!(scheduler.borrow().messages.len(), 1);
+        });
+    }
+
     #[wasm_bindgen_test]
     fn add_rerender_message_should_add_rerender_message_to_schedulers_queue() {
         // Arrange
@@ -629,7 +924,7 @@ mod tests {
             let scheduler = scheduler.borrow();
             assert_eq!(scheduler.messages.len(), 1);
             assert!(scheduler.is_handle_messages_scheduled);
-            let rerender_message = scheduler.messages.iter().next().unwrap();
+            let rerender_message = &scheduler.messages.iter().next().unwrap().message;
             assert_eq!(rerender_message, &expected_rerender_message);
         });
     }
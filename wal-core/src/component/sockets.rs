@@ -0,0 +1,46 @@
+use std::rc::Rc;
+
+use crate::websocket::{Socket, SocketEventHandler, WebSocketOptions};
+
+use super::declared_slots::{DeclaredSlots, Slot};
+
+impl Slot for Rc<Socket> {
+    type Declaration = (WebSocketOptions, SocketEventHandler);
+
+    fn create((options, handler): Self::Declaration) -> Self {
+        Socket::new(options, handler)
+    }
+
+    fn redeclare(&self, (options, handler): Self::Declaration) -> Result<(), Self::Declaration> {
+        if *self.get_options() != options {
+            return Err((options, handler));
+        }
+        self.set_handler(handler);
+        Ok(())
+    }
+}
+
+/// WebSocket connections of a component held in [DeclaredSlots].
+/// Connection declared again with the same [options](WebSocketOptions) is kept, otherwise it is closed and the new one is established.
+#[derive(Default)]
+pub(crate) struct Sockets {
+    sockets: DeclaredSlots<Rc<Socket>>,
+}
+
+impl Sockets {
+    pub(crate) fn declare(
+        &mut self,
+        options: WebSocketOptions,
+        handler: SocketEventHandler,
+    ) -> Rc<Socket> {
+        self.sockets.declare((options, handler)).clone()
+    }
+
+    pub(crate) fn start_view(&mut self) {
+        self.sockets.start_view();
+    }
+
+    pub(crate) fn finish_view(&mut self) {
+        self.sockets.finish_view();
+    }
+}
@@ -0,0 +1,46 @@
+use std::rc::Rc;
+
+use crate::storage::{StorageArea, StorageObserver};
+
+use super::declared_slots::{DeclaredSlots, Slot};
+
+impl Slot for Rc<StorageObserver> {
+    type Declaration = (StorageArea, &'static str, Box<dyn Fn()>);
+
+    fn create((area, key, notify): Self::Declaration) -> Self {
+        StorageObserver::new(area, key, notify)
+    }
+
+    fn redeclare(&self, (area, key, notify): Self::Declaration) -> Result<(), Self::Declaration> {
+        if !self.is_observing(area, key) {
+            return Err((area, key, notify));
+        }
+        Ok(())
+    }
+}
+
+/// Observers of the persisted values read by a component held in [DeclaredSlots].
+/// Observer declared again for the same value is kept.
+#[derive(Default)]
+pub(crate) struct StorageObservers {
+    observers: DeclaredSlots<Rc<StorageObserver>>,
+}
+
+impl StorageObservers {
+    pub(crate) fn declare(
+        &mut self,
+        area: StorageArea,
+        key: &'static str,
+        notify: impl Fn() + 'static,
+    ) {
+        self.observers.declare((area, key, Box::new(notify)));
+    }
+
+    pub(crate) fn start_view(&mut self) {
+        self.observers.start_view();
+    }
+
+    pub(crate) fn finish_view(&mut self) {
+        self.observers.finish_view();
+    }
+}
@@ -0,0 +1,207 @@
+use std::{borrow::Cow, cell::RefCell, collections::HashMap, rc::Rc};
+
+use gloo::{
+    events::EventListener,
+    utils::{document, window},
+};
+use web_sys::EventTarget;
+
+use super::declared_slots::{DeclaredSlots, Slot};
+
+type SubscriptionCallback = Box<dyn Fn(&web_sys::Event)>;
+
+/// Global target, to which events a component can subscribe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum SubscriptionTarget {
+    Window,
+    Document,
+}
+
+impl SubscriptionTarget {
+    fn get(&self) -> EventTarget {
+        match self {
+            SubscriptionTarget::Window => window().into(),
+            SubscriptionTarget::Document => document().into(),
+        }
+    }
+}
+
+type SubscriptionKey = (SubscriptionTarget, Cow<'static, str>);
+
+struct Subscription {
+    callback: Rc<RefCell<SubscriptionCallback>>,
+    _listener: EventListener,
+}
+
+impl Subscription {
+    fn new(key: &SubscriptionKey, callback: SubscriptionCallback) -> Self {
+        let callback = Rc::new(RefCell::new(callback));
+        let callback_clone = callback.clone();
+        let listener = EventListener::new(&key.0.get(), key.1.clone(), move |event| {
+            (*callback_clone.borrow())(event);
+        });
+        Self {
+            callback,
+            _listener: listener,
+        }
+    }
+}
+
+impl Slot for Subscription {
+    type Declaration = (SubscriptionKey, SubscriptionCallback);
+
+    fn create((key, callback): Self::Declaration) -> Self {
+        Subscription::new(&key, callback)
+    }
+
+    fn redeclare(&self, (_, callback): Self::Declaration) -> Result<(), Self::Declaration> {
+        *self.callback.borrow_mut() = callback;
+        Ok(())
+    }
+}
+
+/// Subscriptions of a component to the events of [global targets](SubscriptionTarget).
+/// Subscriptions to every target and event type are held in separate [DeclaredSlots], listeners of subscriptions declared again are reused.
+#[derive(Default)]
+pub(crate) struct Subscriptions {
+    subscriptions: HashMap<SubscriptionKey, DeclaredSlots<Subscription>>,
+}
+
+impl Subscriptions {
+    pub(crate) fn subscribe(
+        &mut self,
+        target: SubscriptionTarget,
+        event_type: Cow<'static, str>,
+        callback: SubscriptionCallback,
+    ) {
+        let key = (target, event_type);
+        self.subscriptions
+            .entry(key.clone())
+            .or_default()
+            .declare((key, callback));
+    }
+
+    pub(crate) fn start_view(&mut self) {
+        self.subscriptions
+            .values_mut()
+            .for_each(DeclaredSlots::start_view);
+    }
+
+    pub(crate) fn finish_view(&mut self) {
+        self.subscriptions.retain(|_, subscriptions| {
+            subscriptions.finish_view();
+            !subscriptions.is_empty()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use gloo::utils::window;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn create_counting_callback(counter: &Rc<RefCell<u32>>) -> SubscriptionCallback {
+        let counter = counter.clone();
+        Box::new(move |_| *counter.borrow_mut() += 1)
+    }
+
+    fn dispatch_on_window(event_type: &str) {
+        window()
+            .dispatch_event(&web_sys::Event::new(event_type).unwrap())
+            .unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn subscription_should_be_called_when_event_is_dispatched_on_target() {
+        // Arrange
+        let counter = Rc::new(RefCell::new(0));
+        let mut subscriptions = Subscriptions::default();
+        subscriptions.start_view();
+        subscriptions.subscribe(
+            SubscriptionTarget::Window,
+            Cow::from("test-subscription-called"),
+            create_counting_callback(&counter),
+        );
+        subscriptions.finish_view();
+
+        // Act
+        dispatch_on_window("test-subscription-called");
+
+        // Assert
+        assert_eq!(*counter.borrow(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn subscription_declared_again_should_call_only_the_latest_callback() {
+        // Arrange
+        let first_counter = Rc::new(RefCell::new(0));
+        let second_counter = Rc::new(RefCell::new(0));
+        let mut subscriptions = Subscriptions::default();
+        for counter in [&first_counter, &second_counter] {
+            subscriptions.start_view();
+            subscriptions.subscribe(
+                SubscriptionTarget::Window,
+                Cow::from("test-subscription-redeclared"),
+                create_counting_callback(counter),
+            );
+            subscriptions.finish_view();
+        }
+
+        // Act
+        dispatch_on_window("test-subscription-redeclared");
+
+        // Assert
+        assert_eq!(*first_counter.borrow(), 0);
+        assert_eq!(*second_counter.borrow(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn subscription_not_declared_again_should_be_removed() {
+        // Arrange
+        let counter = Rc::new(RefCell::new(0));
+        let mut subscriptions = Subscriptions::default();
+        subscriptions.start_view();
+        subscriptions.subscribe(
+            SubscriptionTarget::Window,
+            Cow::from("test-subscription-removed"),
+            create_counting_callback(&counter),
+        );
+        subscriptions.finish_view();
+
+        // Act
+        subscriptions.start_view();
+        subscriptions.finish_view();
+        dispatch_on_window("test-subscription-removed");
+
+        // Assert
+        assert_eq!(*counter.borrow(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn dropped_subscriptions_should_not_be_called() {
+        // Arrange
+        let counter = Rc::new(RefCell::new(0));
+        let mut subscriptions = Subscriptions::default();
+        subscriptions.start_view();
+        subscriptions.subscribe(
+            SubscriptionTarget::Document,
+            Cow::from("test-subscription-dropped"),
+            create_counting_callback(&counter),
+        );
+        subscriptions.finish_view();
+
+        // Act
+        drop(subscriptions);
+        document()
+            .dispatch_event(&web_sys::Event::new("test-subscription-dropped").unwrap())
+            .unwrap();
+
+        // Assert
+        assert_eq!(*counter.borrow(), 0);
+    }
+}
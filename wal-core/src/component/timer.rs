@@ -0,0 +1,273 @@
+//! This module provides [TimerHandle] of timers created using [Behavior](super::Behavior).
+
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+use gloo::timers::callback::{Interval, Timeout};
+
+use super::declared_slots::{DeclaredSlots, Slot};
+
+type TimerTick = Rc<dyn Fn()>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimerKind {
+    Timeout,
+    Interval,
+    Debounce,
+}
+
+// Running timers are kept only to be cancelled when dropped
+enum RunningTimer {
+    Timeout { _timer: Timeout },
+    Interval { _timer: Interval },
+}
+
+pub(crate) struct Timer {
+    kind: TimerKind,
+    millis: u32,
+    tick: RefCell<TimerTick>,
+    running: RefCell<Option<RunningTimer>>,
+}
+
+impl Timer {
+    fn new(kind: TimerKind, millis: u32, tick: TimerTick) -> Rc<Self> {
+        let timer = Rc::new(Self {
+            kind,
+            millis,
+            tick: RefCell::new(tick),
+            running: RefCell::new(None),
+        });
+        timer.start();
+        timer
+    }
+
+    fn start(self: &Rc<Self>) {
+        let timer = Rc::downgrade(self);
+        let running = match self.kind {
+            TimerKind::Timeout => RunningTimer::Timeout {
+                _timer: Timeout::new(self.millis, move || {
+                    if let Some(timer) = timer.upgrade() {
+                        let _finished = timer.running.borrow_mut().take();
+                        let tick = timer.tick.borrow().clone();
+                        tick();
+                    }
+                }),
+            },
+            TimerKind::Interval => RunningTimer::Interval {
+                _timer: Interval::new(self.millis, move || {
+                    if let Some(timer) = timer.upgrade() {
+                        let tick = timer.tick.borrow().clone();
+                        tick();
+                    }
+                }),
+            },
+            TimerKind::Debounce => return,
+        };
+        *self.running.borrow_mut() = Some(running);
+    }
+
+    /// Cancels the pending call of the debounced timer and schedules the `call` after the delay.
+    pub(crate) fn debounce(self: &Rc<Self>, call: impl FnOnce() + 'static) {
+        let timer = Rc::downgrade(self);
+        let timeout = Timeout::new(self.millis, move || {
+            if let Some(timer) = timer.upgrade() {
+                let _finished = timer.running.borrow_mut().take();
+                call();
+            }
+        });
+        *self.running.borrow_mut() = Some(RunningTimer::Timeout { _timer: timeout });
+    }
+
+    fn cancel(&self) {
+        self.running.borrow_mut().take();
+    }
+
+    fn is_active(&self) -> bool {
+        self.running.borrow().is_some()
+    }
+}
+
+/// Handle of the timer created using [set_timeout](super::Behavior::set_timeout) or [set_interval](super::Behavior::set_interval).
+/// Dropping the handle does not cancel the timer, it is cancelled when the component is destroyed
+/// or when the timer is not declared during the view anymore.
+#[derive(Clone)]
+pub struct TimerHandle(Weak<Timer>);
+
+impl TimerHandle {
+    pub(crate) fn new(timer: &Rc<Timer>) -> Self {
+        Self(Rc::downgrade(timer))
+    }
+
+    /// Cancels the timer, so it will not emit any more messages.
+    pub fn cancel(&self) {
+        if let Some(timer) = self.0.upgrade() {
+            timer.cancel();
+        }
+    }
+
+    /// Restarts the timer, so it emits the next message after the whole delay from now.
+    /// The timeout is armed again even if it has already fired or has been cancelled.
+    pub fn reset(&self) {
+        if let Some(timer) = self.0.upgrade() {
+            timer.start();
+        }
+    }
+
+    /// Checks whether the timer is still going to emit messages.
+    pub fn is_active(&self) -> bool {
+        self.0.upgrade().is_some_and(|timer| timer.is_active())
+    }
+}
+
+impl Slot for Rc<Timer> {
+    type Declaration = (TimerKind, u32, TimerTick);
+
+    fn create((kind, millis, tick): Self::Declaration) -> Self {
+        Timer::new(kind, millis, tick)
+    }
+
+    fn redeclare(&self, (kind, millis, tick): Self::Declaration) -> Result<(), Self::Declaration> {
+        if self.kind != kind || self.millis != millis {
+            return Err((kind, millis, tick));
+        }
+        *self.tick.borrow_mut() = tick;
+        Ok(())
+    }
+}
+
+/// Timers of a component held in [DeclaredSlots]. Timer declared again with the same kind and delay keeps running, otherwise it is restarted.
+#[derive(Default)]
+pub(crate) struct Timers {
+    timers: DeclaredSlots<Rc<Timer>>,
+}
+
+impl Timers {
+    pub(crate) fn declare(&mut self, kind: TimerKind, millis: u32, tick: TimerTick) -> Rc<Timer> {
+        self.timers.declare((kind, millis, tick)).clone()
+    }
+
+    pub(crate) fn start_view(&mut self) {
+        self.timers.start_view();
+    }
+
+    pub(crate) fn finish_view(&mut self) {
+        self.timers.finish_view();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn create_tick() -> TimerTick {
+        Rc::new(|| {})
+    }
+
+    #[wasm_bindgen_test]
+    fn timer_declared_again_with_the_same_delay_should_be_reused() {
+        // Arrange
+        let mut timers = Timers::default();
+        timers.start_view();
+        let first = timers.declare(TimerKind::Interval, 1000, create_tick());
+        timers.finish_view();
+
+        // Act
+        timers.start_view();
+        let second = timers.declare(TimerKind::Interval, 1000, create_tick());
+        timers.finish_view();
+
+        // Assert
+        assert!(Rc::ptr_eq(&first, &second));
+    }
+
+    #[wasm_bindgen_test]
+    fn timer_declared_again_with_different_delay_should_be_restarted() {
+        // Arrange
+        let mut timers = Timers::default();
+        timers.start_view();
+        let first = timers.declare(TimerKind::Interval, 1000, create_tick());
+        timers.finish_view();
+
+        // Act
+        timers.start_view();
+        let second = timers.declare(TimerKind::Interval, 2000, create_tick());
+        timers.finish_view();
+
+        // Assert
+        assert!(!Rc::ptr_eq(&first, &second));
+    }
+
+    #[wasm_bindgen_test]
+    fn timer_not_declared_again_should_be_cancelled() {
+        // Arrange
+        let mut timers = Timers::default();
+        timers.start_view();
+        let handle = TimerHandle::new(&timers.declare(TimerKind::Timeout, 1000, create_tick()));
+        timers.finish_view();
+
+        // Act
+        timers.start_view();
+        timers.finish_view();
+
+        // Assert
+        assert!(!handle.is_active());
+    }
+
+    #[wasm_bindgen_test]
+    fn cancel_should_stop_the_timer() {
+        // Arrange
+        let mut timers = Timers::default();
+        timers.start_view();
+        let handle = TimerHandle::new(&timers.declare(TimerKind::Interval, 1000, create_tick()));
+        timers.finish_view();
+
+        // Act
+        handle.cancel();
+
+        // Assert
+        assert!(!handle.is_active());
+    }
+
+    #[wasm_bindgen_test]
+    fn reset_should_arm_the_cancelled_timeout_again() {
+        // Arrange
+        let mut timers = Timers::default();
+        timers.start_view();
+        let handle = TimerHandle::new(&timers.declare(TimerKind::Timeout, 1000, create_tick()));
+        timers.finish_view();
+        handle.cancel();
+
+        // Act
+        handle.reset();
+
+        // Assert
+        assert!(handle.is_active());
+    }
+
+    #[wasm_bindgen_test]
+    fn debounce_should_replace_pending_call() {
+        // Arrange
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut timers = Timers::default();
+        timers.start_view();
+        let timer = timers.declare(TimerKind::Debounce, 1000, create_tick());
+        timers.finish_view();
+        let calls_clone = calls.clone();
+        timer.debounce(move || calls_clone.borrow_mut().push(1));
+
+        // Act
+        let calls_clone = calls.clone();
+        timer.debounce(move || calls_clone.borrow_mut().push(2));
+
+        // Assert
+        assert!(timer.is_active());
+        assert!(calls.borrow().is_empty());
+    }
+}
@@ -0,0 +1,15 @@
+//! Bindings of DOM events, which are missing in [web_sys].
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Event fired on a form, when its entry list is constructed. See [MDN](https://developer.mozilla.org/en-US/docs/Web/API/FormDataEvent).
+    #[wasm_bindgen(extends = web_sys::Event, extends = js_sys::Object, js_name = FormDataEvent, typescript_type = "FormDataEvent")]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub type FormDataEvent;
+
+    /// Returns the [FormData](web_sys::FormData) object representing the data contained in the form, when the event was fired.
+    #[wasm_bindgen(method, getter, js_name = formData)]
+    pub fn form_data(this: &FormDataEvent) -> web_sys::FormData;
+}
@@ -0,0 +1,718 @@
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet},
+    rc::{Rc, Weak},
+};
+
+use gloo::events::EventListener;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Element, Node};
+
+use crate::virtual_dom::dom;
+
+use super::EventOptions;
+
+/// Name of the property set on DOM elements, which identifies them in the [Registry].
+const ELEMENT_ID_PROPERTY: &str = "__walrust_id";
+
+/// Name of the property set on the top DOM nodes rendered by a portal, which points to the parent of the portal in the virtual DOM.
+const PORTAL_PARENT_PROPERTY: &str = "__walrust_portal_parent";
+
+/// Events which do not bubble. They are listened to in the capture phase and dispatched only to the target element.
+const NON_BUBBLING_EVENTS: &[&str] = &[
+    "abort",
+    "blur",
+    "cancel",
+    "canplay",
+    "canplaythrough",
+    "close",
+    "durationchange",
+    "emptied",
+    "ended",
+    "error",
+    "focus",
+    "invalid",
+    "load",
+    "loadeddata",
+    "loadedmetadata",
+    "loadend",
+    "loadstart",
+    "mouseenter",
+    "mouseleave",
+    "pause",
+    "play",
+    "playing",
+    "pointerenter",
+    "pointerleave",
+    "progress",
+    "ratechange",
+    "resize",
+    "scroll",
+    "seeked",
+    "seeking",
+    "stalled",
+    "suspend",
+    "timeupdate",
+    "toggle",
+];
+
+type DelegatedCallback = Box<dyn FnMut(&web_sys::Event)>;
+
+struct DelegatedHandler {
+    listener: ListenerKey,
+    options: EventOptions,
+    was_called: Cell<bool>,
+    callback: RefCell<DelegatedCallback>,
+}
+
+impl DelegatedHandler {
+    fn call(&self, event: &web_sys::Event) {
+        if self.options.once && self.was_called.get() {
+            return;
+        }
+        self.was_called.set(true);
+
+        if self.options.prevent_default {
+            event.prevent_default();
+        }
+        if self.options.stop_propagation {
+            event.stop_propagation();
+        }
+
+        // Handler which is already running (event dispatched synchronously from its own callback) is skipped
+        if let Ok(mut callback) = self.callback.try_borrow_mut() {
+            (*callback)(event);
+        }
+    }
+
+    fn is_dispatched_by(&self, listener: &ListenerKey) -> bool {
+        self.listener == *listener
+    }
+}
+
+/// Identifies DOM event listener attached to every container.
+/// Custom events are listened to in the capture phase, as it is not known whether they bubble.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct ListenerKey {
+    event_type: Cow<'static, str>,
+    capture: bool,
+    passive: bool,
+}
+
+impl ListenerKey {
+    fn new(event_type: Cow<'static, str>, options: &EventOptions, custom: bool) -> Self {
+        Self {
+            capture: options.capture || custom || is_non_bubbling(&event_type),
+            passive: options.passive,
+            event_type,
+        }
+    }
+}
+
+/// Handle of a handler registered in the [Registry]. Handler is unregistered when the handle is dropped.
+pub(crate) struct Registration {
+    element_id: u32,
+    handler: Rc<DelegatedHandler>,
+}
+
+impl Registration {
+    /// Replaces the callback of the registered handler, while the handler stays registered.
+    pub(crate) fn set_callback(&self, callback: DelegatedCallback) {
+        *self.handler.callback.borrow_mut() = callback;
+    }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        let _ = REGISTRY.try_with(|registry| {
+            if let Ok(mut registry) = registry.try_borrow_mut() {
+                registry.remove(self.element_id, &self.handler);
+            }
+        });
+    }
+}
+
+/// Handle of a container registered in the [Registry]. Container is unregistered when all of its handles are dropped.
+pub(crate) struct ContainerRegistration {
+    container: Node,
+}
+
+impl Drop for ContainerRegistration {
+    fn drop(&mut self) {
+        let _ = REGISTRY.try_with(|registry| {
+            if let Ok(mut registry) = registry.try_borrow_mut() {
+                registry.remove_container(&self.container);
+            }
+        });
+    }
+}
+
+/// DOM node, to which the delegated event listeners are attached. The root element is the container of the routed application,
+/// while [mounted](crate::app::mount) applications and portals register their elements as additional containers.
+struct Container {
+    node: Node,
+    registrations: usize,
+    is_root: bool,
+    listeners: HashMap<ListenerKey, EventListener>,
+}
+
+impl Container {
+    fn new(node: Node, listener_keys: &HashSet<ListenerKey>) -> Self {
+        let mut container = Self {
+            node,
+            registrations: 0,
+            is_root: false,
+            listeners: HashMap::new(),
+        };
+        for listener_key in listener_keys {
+            container.listen(listener_key.clone());
+        }
+        container
+    }
+
+    fn listen(&mut self, listener_key: ListenerKey) {
+        let listener = create_container_listener(&self.node, listener_key.clone());
+        self.listeners.insert(listener_key, listener);
+    }
+}
+
+thread_local! {
+    static REGISTRY: RefCell<Registry> = RefCell::new(Registry::default());
+}
+
+/// Registry of handlers of all elements rendered by the virtual DOM.
+/// There is only one DOM event listener per event type attached to every container, which dispatches events to the registered handlers.
+#[derive(Default)]
+struct Registry {
+    next_element_id: u32,
+    handlers: HashMap<u32, Vec<Weak<DelegatedHandler>>>,
+    listener_keys: HashSet<ListenerKey>,
+    containers: Vec<Container>,
+}
+
+impl Registry {
+    fn element_id(&mut self, element: &Element) -> u32 {
+        if let Some(element_id) = get_element_id(element) {
+            return element_id;
+        }
+
+        let element_id = self.next_element_id;
+        self.next_element_id += 1;
+        js_sys::Reflect::set(
+            element,
+            &JsValue::from_str(ELEMENT_ID_PROPERTY),
+            &JsValue::from(element_id),
+        )
+        .expect("Couldnt set element id");
+        element_id
+    }
+
+    fn add(&mut self, element_id: u32, handler: &Rc<DelegatedHandler>) {
+        self.handlers
+            .entry(element_id)
+            .or_default()
+            .push(Rc::downgrade(handler));
+
+        self.add_root_container();
+        let listener_key = handler.listener.clone();
+        if self.listener_keys.insert(listener_key.clone()) {
+            for container in self.containers.iter_mut() {
+                container.listen(listener_key.clone());
+            }
+        }
+    }
+
+    /// Adds the root element as a container, once it is rendered. It is not created here,
+    /// so the pages with only [mounted](crate::app::mount) applications do not get an extra element.
+    fn add_root_container(&mut self) {
+        if self.containers.iter().any(|x| x.is_root) {
+            return;
+        }
+        if let Some(root) = dom::find_root_element() {
+            let mut root = Container::new(root, &self.listener_keys);
+            root.is_root = true;
+            self.containers.push(root);
+        }
+    }
+
+    fn add_container(&mut self, node: &Node) {
+        let index = match self.containers.iter().position(|x| x.node == *node) {
+            Some(index) => index,
+            None => {
+                let container = Container::new(node.clone(), &self.listener_keys);
+                self.containers.push(container);
+                self.containers.len() - 1
+            }
+        };
+        self.containers[index].registrations += 1;
+    }
+
+    fn remove_container(&mut self, node: &Node) {
+        if let Some(index) = self
+            .containers
+            .iter()
+            .position(|x| x.node == *node && x.registrations > 0)
+        {
+            self.containers[index].registrations -= 1;
+            // Root container is never removed
+            if self.containers[index].registrations == 0 && !self.containers[index].is_root {
+                self.containers.remove(index);
+            }
+        }
+    }
+
+    fn is_container(&self, node: &Node) -> bool {
+        self.containers.iter().any(|x| x.node == *node)
+    }
+
+    fn remove(&mut self, element_id: u32, handler: &Rc<DelegatedHandler>) {
+        if let Some(handlers) = self.handlers.get_mut(&element_id) {
+            handlers.retain(|x| x.strong_count() > 0 && !x.ptr_eq(&Rc::downgrade(handler)));
+            if handlers.is_empty() {
+                self.handlers.remove(&element_id);
+            }
+        }
+    }
+
+    fn get<P>(&self, node: &Node, predicate: P) -> Vec<Rc<DelegatedHandler>>
+    where
+        P: Fn(&DelegatedHandler) -> bool,
+    {
+        get_element_id(node)
+            .and_then(|element_id| self.handlers.get(&element_id))
+            .map_or_else(Vec::new, |handlers| {
+                handlers
+                    .iter()
+                    .filter_map(Weak::upgrade)
+                    .filter(|x| predicate(x))
+                    .collect()
+            })
+    }
+}
+
+/// Registers `callback` to be called when the event of `event_type` is dispatched on the `element` or bubbles through it.
+/// Event is `custom` if it is not a standard DOM event.
+pub(crate) fn register(
+    element: &Element,
+    event_type: Cow<'static, str>,
+    options: EventOptions,
+    custom: bool,
+    callback: DelegatedCallback,
+) -> Registration {
+    let handler = Rc::new(DelegatedHandler {
+        listener: ListenerKey::new(event_type, &options, custom),
+        options,
+        was_called: Cell::new(false),
+        callback: RefCell::new(callback),
+    });
+
+    REGISTRY.with(|registry| {
+        let mut registry = registry.borrow_mut();
+        let element_id = registry.element_id(element);
+        registry.add(element_id, &handler);
+        Registration {
+            element_id,
+            handler,
+        }
+    })
+}
+
+/// Registers the `container`, into which an application or a portal renders, so the events dispatched inside it are delegated.
+/// Containers nested in each other are allowed, as the events are dispatched only by the nearest container of their target.
+pub(crate) fn register_container(container: &Node) -> ContainerRegistration {
+    REGISTRY.with(|registry| registry.borrow_mut().add_container(container));
+    ContainerRegistration {
+        container: container.clone(),
+    }
+}
+
+/// Sets the `parent` of the portal as the parent of the `node` rendered by the portal, so the events bubble from the portal to the `parent`.
+pub(crate) fn set_portal_parent(node: &Node, parent: &Node) {
+    js_sys::Reflect::set(node, &JsValue::from_str(PORTAL_PARENT_PROPERTY), parent)
+        .expect("Couldnt set portal parent");
+}
+
+fn get_portal_parent(node: &Node) -> Option<Node> {
+    js_sys::Reflect::get(node, &JsValue::from_str(PORTAL_PARENT_PROPERTY))
+        .ok()
+        .and_then(|x| x.dyn_into::<Node>().ok())
+}
+
+fn get_element_id(node: &JsValue) -> Option<u32> {
+    js_sys::Reflect::get(node, &JsValue::from_str(ELEMENT_ID_PROPERTY))
+        .ok()
+        .and_then(|x| x.as_f64())
+        .map(|x| x as u32)
+}
+
+fn is_non_bubbling(event_type: &str) -> bool {
+    NON_BUBBLING_EVENTS.contains(&event_type)
+}
+
+fn create_container_listener(container: &Node, listener: ListenerKey) -> EventListener {
+    let container_clone = container.clone();
+    dom::create_delegated_event_listener(
+        container,
+        listener.event_type.clone(),
+        listener.capture,
+        listener.passive,
+        move |event| dispatch(event, &container_clone, &listener),
+    )
+}
+
+/// Dispatches the `event` to the handlers registered on the path from the target of the `event` up to the document.
+/// The `event` is dispatched only by the nearest container of its target, so containers nested in each other do not dispatch it twice.
+/// Capture listener calls capture handlers from the top of the path, which mimics the native capturing,
+/// and then the rest of handlers from the target up, as long as the event bubbles.
+/// Bubble listener calls handlers from the target up, which mimics the native bubbling.
+fn dispatch(event: &web_sys::Event, container: &Node, listener: &ListenerKey) {
+    if get_nearest_container(event).as_ref() != Some(container) {
+        return;
+    }
+    let path = get_event_path(event);
+
+    if listener.capture {
+        for node in path.iter().rev() {
+            call_handlers(node, event, |x| {
+                x.is_dispatched_by(listener) && x.options.capture
+            });
+            if event.cancel_bubble() {
+                return;
+            }
+        }
+        bubble(&path, event, |x| {
+            x.is_dispatched_by(listener) && !x.options.capture
+        });
+    } else {
+        bubble(&path, event, |x| x.is_dispatched_by(listener));
+    }
+}
+
+fn bubble<P>(path: &[Node], event: &web_sys::Event, predicate: P)
+where
+    P: Fn(&DelegatedHandler) -> bool,
+{
+    for node in path {
+        call_handlers(node, event, &predicate);
+        if !event.bubbles() || event.cancel_bubble() {
+            break;
+        }
+    }
+}
+
+fn get_event_target(event: &web_sys::Event) -> Option<Node> {
+    event.target().and_then(|x| x.dyn_into::<Node>().ok())
+}
+
+fn get_nearest_container(event: &web_sys::Event) -> Option<Node> {
+    REGISTRY.with(|registry| {
+        let registry = registry.borrow();
+        let mut current = get_event_target(event);
+        while let Some(node) = current {
+            if registry.is_container(&node) {
+                return Some(node);
+            }
+            current = node.parent_node();
+        }
+        None
+    })
+}
+
+/// Returns the path of the `event` in the virtual DOM, which goes from the nodes rendered by a portal to the parent of the portal.
+fn get_event_path(event: &web_sys::Event) -> Vec<Node> {
+    let mut path = Vec::new();
+    let mut current = get_event_target(event);
+    while let Some(node) = current {
+        current = get_portal_parent(&node).or_else(|| node.parent_node());
+        path.push(node);
+    }
+    path
+}
+
+fn call_handlers<P>(node: &Node, event: &web_sys::Event, predicate: P)
+where
+    P: Fn(&DelegatedHandler) -> bool,
+{
+    let handlers = REGISTRY.with(|registry| registry.borrow().get(node, predicate));
+    for handler in handlers {
+        handler.call(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use wasm_bindgen_test::wasm_bindgen_test;
+    use web_sys::{Event, EventInit};
+
+    use super::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn create_attached_element() -> Element {
+        let element = dom::create_element("div");
+        dom::append_child(&dom::get_root_element(), &element);
+        element
+    }
+
+    fn create_event(event_type: &str, bubbles: bool) -> Event {
+        let event_init = EventInit::new();
+        event_init.set_bubbles(bubbles);
+        Event::new_with_event_init_dict(event_type, &event_init).unwrap()
+    }
+
+    fn register_counter(
+        element: &Element,
+        event_type: &'static str,
+    ) -> (Registration, Rc<RefCell<u32>>) {
+        let counter = Rc::new(RefCell::new(0));
+        let counter_clone = counter.clone();
+        let registration = register(
+            element,
+            Cow::from(event_type),
+            EventOptions::default(),
+            false,
+            Box::new(move |_| *counter_clone.borrow_mut() += 1),
+        );
+        (registration, counter)
+    }
+
+    #[wasm_bindgen_test]
+    fn registered_handler_should_be_called_when_event_is_dispatched_on_element() {
+        // Arrange
+        let element = create_attached_element();
+        let (_registration, counter) = register_counter(&element, "click");
+
+        // Act
+        element
+            .dispatch_event(&create_event("click", true))
+            .unwrap();
+
+        // Assert
+        assert_eq!(*counter.borrow(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn registered_handler_should_be_called_when_event_bubbles_from_child() {
+        // Arrange
+        let parent = create_attached_element();
+        let child = dom::create_element("span");
+        dom::append_child(&parent, &child);
+        let (_registration, counter) = register_counter(&parent, "click");
+
+        // Act
+        child.dispatch_event(&create_event("click", true)).unwrap();
+
+        // Assert
+        assert_eq!(*counter.borrow(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn stop_propagation_should_stop_dispatching_to_ancestors() {
+        // Arrange
+        let parent = create_attached_element();
+        let child = dom::create_element("span");
+        dom::append_child(&parent, &child);
+        let (_parent_registration, parent_counter) = register_counter(&parent, "click");
+        let _child_registration = register(
+            &child,
+            Cow::from("click"),
+            EventOptions::default(),
+            false,
+            Box::new(|event| event.stop_propagation()),
+        );
+
+        // Act
+        child.dispatch_event(&create_event("click", true)).unwrap();
+
+        // Assert
+        assert_eq!(*parent_counter.borrow(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn non_bubbling_event_should_be_dispatched_only_to_target() {
+        // Arrange
+        let parent = create_attached_element();
+        let child = dom::create_element("input");
+        dom::append_child(&parent, &child);
+        let (_parent_registration, parent_counter) = register_counter(&parent, "focus");
+        let (_child_registration, child_counter) = register_counter(&child, "focus");
+
+        // Act
+        child.dispatch_event(&create_event("focus", false)).unwrap();
+
+        // Assert
+        assert_eq!(*child_counter.borrow(), 1);
+        assert_eq!(*parent_counter.borrow(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn handler_with_prevent_default_option_should_prevent_default() {
+        // Arrange
+        let element = create_attached_element();
+        let options = EventOptions {
+            prevent_default: true,
+            ..Default::default()
+        };
+        let _registration = register(
+            &element,
+            Cow::from("click"),
+            options,
+            false,
+            Box::new(|_| {}),
+        );
+        let event_init = EventInit::new();
+        event_init.set_bubbles(true);
+        event_init.set_cancelable(true);
+        let event = Event::new_with_event_init_dict("click", &event_init).unwrap();
+
+        // Act
+        element.dispatch_event(&event).unwrap();
+
+        // Assert
+        assert!(event.default_prevented());
+    }
+
+    #[wasm_bindgen_test]
+    fn handler_with_once_option_should_be_called_only_once() {
+        // Arrange
+        let element = create_attached_element();
+        let counter = Rc::new(RefCell::new(0));
+        let counter_clone = counter.clone();
+        let options = EventOptions {
+            once: true,
+            ..Default::default()
+        };
+        let _registration = register(
+            &element,
+            Cow::from("click"),
+            options,
+            false,
+            Box::new(move |_| *counter_clone.borrow_mut() += 1),
+        );
+
+        // Act
+        element
+            .dispatch_event(&create_event("click", true))
+            .unwrap();
+        element
+            .dispatch_event(&create_event("click", true))
+            .unwrap();
+
+        // Assert
+        assert_eq!(*counter.borrow(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn capture_handler_of_parent_should_be_called_before_handler_of_child() {
+        // Arrange
+        let parent = create_attached_element();
+        let child = dom::create_element("span");
+        dom::append_child(&parent, &child);
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let parent_calls = calls.clone();
+        let child_calls = calls.clone();
+        let options = EventOptions {
+            capture: true,
+            ..Default::default()
+        };
+        let _parent_registration = register(
+            &parent,
+            Cow::from("click"),
+            options,
+            false,
+            Box::new(move |_| parent_calls.borrow_mut().push("parent")),
+        );
+        let _child_registration = register(
+            &child,
+            Cow::from("click"),
+            EventOptions::default(),
+            false,
+            Box::new(move |_| child_calls.borrow_mut().push("child")),
+        );
+
+        // Act
+        child.dispatch_event(&create_event("click", true)).unwrap();
+
+        // Assert
+        assert_eq!(*calls.borrow(), vec!["parent", "child"]);
+    }
+
+    #[wasm_bindgen_test]
+    fn dropped_registration_should_not_be_called() {
+        // Arrange
+        let element = create_attached_element();
+        let (registration, counter) = register_counter(&element, "click");
+
+        // Act
+        drop(registration);
+        element
+            .dispatch_event(&create_event("click", true))
+            .unwrap();
+
+        // Assert
+        assert_eq!(*counter.borrow(), 0);
+    }
+
+    fn create_portal_container() -> Element {
+        let container = dom::create_element("div");
+        dom::append_child(&gloo::utils::body(), &container);
+        container
+    }
+
+    #[wasm_bindgen_test]
+    fn event_inside_registered_container_outside_of_root_should_be_dispatched() {
+        // Arrange
+        let container = create_portal_container();
+        let _container_registration = register_container(&container);
+        let element = dom::create_element("button");
+        dom::append_child(&container, &element);
+        let (_registration, counter) = register_counter(&element, "click");
+
+        // Act
+        element
+            .dispatch_event(&create_event("click", true))
+            .unwrap();
+
+        // Assert
+        assert_eq!(*counter.borrow(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn event_inside_portal_should_bubble_to_portal_parent_once() {
+        // Arrange
+        let parent = create_attached_element();
+        let container = create_portal_container();
+        let _container_registration = register_container(&container);
+        let child = dom::create_element("span");
+        dom::append_child(&container, &child);
+        set_portal_parent(&child, &parent);
+        let (_registration, counter) = register_counter(&parent, "click");
+
+        // Act
+        child.dispatch_event(&create_event("click", true)).unwrap();
+
+        // Assert
+        assert_eq!(*counter.borrow(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn event_inside_unregistered_container_should_not_be_dispatched() {
+        // Arrange
+        let parent = create_attached_element();
+        let container = create_portal_container();
+        let container_registration = register_container(&container);
+        let child = dom::create_element("span");
+        dom::append_child(&container, &child);
+        set_portal_parent(&child, &parent);
+        let (_registration, counter) = register_counter(&parent, "click");
+
+        // Act
+        drop(container_registration);
+        child.dispatch_event(&create_event("click", true)).unwrap();
+
+        // Assert
+        assert_eq!(*counter.borrow(), 0);
+    }
+}
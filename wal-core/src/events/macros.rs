@@ -1,11 +1,14 @@
 macro_rules! define_events {
     ($($event:ident),*) => {
+        define_events!($($event(web_sys::$event)),*);
+    };
+    ($($event:ident ($sys_event:ty)),*) => {
         $(
             #[doc(hidden)]
-            pub struct $event(web_sys::$event);
+            pub struct $event($sys_event);
 
             impl Deref for $event {
-                type Target = web_sys::$event;
+                type Target = $sys_event;
 
                 fn deref(&self) -> &Self::Target {
                     &self.0
@@ -14,7 +17,7 @@ macro_rules! define_events {
 
             impl $event {
                 #[allow(dead_code)]
-                fn new(event: web_sys::$event) -> Self {
+                fn new(event: $sys_event) -> Self {
                     Self(event)
                 }
             }
@@ -36,6 +39,10 @@ macro_rules! event_creators {
                     self.event_type.clone()
                 }
 
+                fn get_callback_id(&self) -> usize {
+                    self.callback.id()
+                }
+
                 fn create_callback(&self) -> Box<dyn FnMut(&web_sys::Event)> {
                     let callback = self.callback.clone();
                     Box::new(move |event: &web_sys::Event| {
@@ -1,17 +1,23 @@
 use std::{borrow::Cow, fmt::Debug, hash::Hash, ops::Deref};
 
-use gloo::events::EventListener;
-use wasm_bindgen::JsCast;
-use web_sys::Element;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Element, EventTarget};
 
-use crate::{component::callback::Callback, virtual_dom::dom};
+use crate::component::callback::Callback;
 
+use self::delegation::Registration;
+
+pub mod bindings;
+pub(crate) mod delegation;
 #[macro_use]
 mod macros;
 
 define_events!(
     Event,
     AnimationEvent,
+    ClipboardEvent,
+    CompositionEvent,
+    CustomEvent,
     DragEvent,
     FocusEvent,
     InputEvent,
@@ -19,16 +25,23 @@ define_events!(
     MouseEvent,
     PointerEvent,
     ProgressEvent,
+    SecurityPolicyViolationEvent,
     SubmitEvent,
     TouchEvent,
     TransitionEvent,
     WheelEvent
 );
 
+define_events!(FormDataEvent(bindings::FormDataEvent));
+
 #[doc(hidden)]
 pub trait EventCreator {
     fn get_event_type(&self) -> Cow<'static, str>;
+    fn get_callback_id(&self) -> usize;
     fn create_callback(&self) -> Box<dyn FnMut(&web_sys::Event)>;
+    fn is_custom(&self) -> bool {
+        false
+    }
 }
 
 impl Debug for dyn EventCreator {
@@ -40,6 +53,7 @@ impl Debug for dyn EventCreator {
 impl PartialEq for dyn EventCreator {
     fn eq(&self, other: &Self) -> bool {
         self.get_event_type() == other.get_event_type()
+            && self.get_callback_id() == other.get_callback_id()
     }
 }
 
@@ -58,6 +72,10 @@ impl EventCreator for UnspecializedEventCreator {
         self.event_type.clone()
     }
 
+    fn get_callback_id(&self) -> usize {
+        self.callback.id()
+    }
+
     fn create_callback(&self) -> Box<dyn FnMut(&web_sys::Event)> {
         let callback = self.callback.clone();
         Box::new(move |event: &web_sys::Event| {
@@ -67,8 +85,38 @@ impl EventCreator for UnspecializedEventCreator {
     }
 }
 
+#[doc(hidden)]
+pub struct CustomEventCreator {
+    pub event_type: Cow<'static, str>,
+    pub callback: Callback<CustomEvent>,
+}
+
+impl EventCreator for CustomEventCreator {
+    fn get_event_type(&self) -> Cow<'static, str> {
+        self.event_type.clone()
+    }
+
+    fn get_callback_id(&self) -> usize {
+        self.callback.id()
+    }
+
+    fn create_callback(&self) -> Box<dyn FnMut(&web_sys::Event)> {
+        let callback = self.callback.clone();
+        Box::new(move |event: &web_sys::Event| {
+            let event = CustomEvent(event.clone().unchecked_into());
+            callback.emit(event);
+        })
+    }
+
+    fn is_custom(&self) -> bool {
+        true
+    }
+}
+
 event_creators! {
     AnimationEventCreator(AnimationEvent),
+    ClipboardEventCreator(ClipboardEvent),
+    CompositionEventCreator(CompositionEvent),
     DragEventCreator(DragEvent),
     FocusEventCreator(FocusEvent),
     InputEventCreator(InputEvent),
@@ -76,6 +124,8 @@ event_creators! {
     MouseEventCreator(MouseEvent),
     PointerEventCreator(PointerEvent),
     ProgressEventCreator(ProgressEvent),
+    SecurityPolicyViolationEventCreator(SecurityPolicyViolationEvent),
+    FormDataEventCreator(FormDataEvent),
     SubmitEventCreator(SubmitEvent),
     TouchEventCreator(TouchEvent),
     TransitionEventCreator(TransitionEvent),
@@ -89,9 +139,7 @@ unspecialized_event_creators_constructor! {
     oncanplaythrough,
     onchange,
     onclose,
-    oncopy,
     oncuechange,
-    oncut,
     ondurationchange,
     onemptied,
     onended,
@@ -100,7 +148,6 @@ unspecialized_event_creators_constructor! {
     onload,
     onloadeddata,
     onloadedmetadata,
-    onpaste,
     onpause,
     onplay,
     onplaying,
@@ -110,7 +157,6 @@ unspecialized_event_creators_constructor! {
     onreset,
     onresize,
     onscroll,
-    onsecuritypolicyviolation,
     onseeked,
     onseeking,
     onselect,
@@ -123,10 +169,7 @@ unspecialized_event_creators_constructor! {
     ontimeupdate,
     ontoggle,
     onvolumechange,
-    onwaiting,
-
-    // FormData Events
-    onformdata  // web_sys is missing `FormDataEvent`` so it is handled as Unspecialized Event
+    onwaiting
 }
 
 event_creators_constructor! {
@@ -136,6 +179,16 @@ event_creators_constructor! {
     onanimationiteration(AnimationEvent),
     onanimationstart(AnimationEvent),
 
+    // Clipboard Events
+    oncopy(ClipboardEvent),
+    oncut(ClipboardEvent),
+    onpaste(ClipboardEvent),
+
+    // Composition Events
+    oncompositionend(CompositionEvent),
+    oncompositionstart(CompositionEvent),
+    oncompositionupdate(CompositionEvent),
+
     // Drag Events
     ondrag(DragEvent),
     ondragend(DragEvent),
@@ -152,7 +205,11 @@ event_creators_constructor! {
     onfocusin(FocusEvent),
     onfocusout(FocusEvent),
 
+    // FormData Events
+    onformdata(FormDataEvent),
+
     // Input Events
+    onbeforeinput(InputEvent),
     oninput(InputEvent),
 
     // Keyboard Events
@@ -190,6 +247,9 @@ event_creators_constructor! {
     onloadstart(ProgressEvent),
     onprogress(ProgressEvent),
 
+    // SecurityPolicyViolation Events
+    onsecuritypolicyviolation(SecurityPolicyViolationEvent),
+
     // Submit Events
     onsubmit(SubmitEvent),
 
@@ -210,49 +270,162 @@ event_creators_constructor! {
 }
 
 #[doc(hidden)]
-#[derive(Debug)]
+pub fn on_custom_event(
+    event_type: &'static str,
+    callback: Callback<CustomEvent>,
+) -> Box<dyn EventCreator> {
+    Box::new(CustomEventCreator {
+        event_type: Cow::from(event_type),
+        callback,
+    })
+}
+
+impl CustomEvent {
+    /// Deserializes the detail of the [CustomEvent], which was serialized by [dispatch_serialized_custom_event].
+    #[cfg(feature = "serde")]
+    pub fn deserialize_detail<T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> Result<T, serde_wasm_bindgen::Error> {
+        serde_wasm_bindgen::from_value(self.detail())
+    }
+}
+
+/// Dispatches [CustomEvent] of `event_type` with provided `detail` on the `target`.
+/// Dispatched event bubbles and is composed, so it can be handled by any ancestor of the `target`,
+/// also outside of the shadow DOM of a web component.
+/// Returns `false` if the event was cancelled by any of its handlers, `true` otherwise.
+///
+/// In [rsx](../../wal_rsx/macro.rsx.html) macro handlers of custom events are defined using `on:` prefix,
+/// e.g. `on:item-selected={callback}`.
+pub fn dispatch_custom_event(target: &EventTarget, event_type: &str, detail: &JsValue) -> bool {
+    let event_init = web_sys::CustomEventInit::new();
+    event_init.set_bubbles(true);
+    event_init.set_cancelable(true);
+    event_init.set_composed(true);
+    event_init.set_detail(detail);
+    let event = web_sys::CustomEvent::new_with_event_init_dict(event_type, &event_init)
+        .expect("Couldnt create custom event");
+    target
+        .dispatch_event(&event)
+        .expect("Couldnt dispatch custom event")
+}
+
+/// Serializes the `detail` and dispatches [CustomEvent] of `event_type` with it on the `target`.
+/// See [dispatch_custom_event] and [CustomEvent::deserialize_detail].
+#[cfg(feature = "serde")]
+pub fn dispatch_serialized_custom_event<T: serde::Serialize>(
+    target: &EventTarget,
+    event_type: &str,
+    detail: &T,
+) -> Result<bool, serde_wasm_bindgen::Error> {
+    let detail = serde_wasm_bindgen::to_value(detail)?;
+    Ok(dispatch_custom_event(target, event_type, &detail))
+}
+
+/// Options of the [EventHandler]. In [rsx](../../wal_rsx/macro.rsx.html) macro they are set using event modifiers,
+/// e.g. `onsubmit:prevent={callback}` or `onscroll:passive={callback}`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventOptions {
+    /// Calls `prevent_default` on the event before the callback is called. Set by `prevent` modifier.
+    pub prevent_default: bool,
+    /// Calls `stop_propagation` on the event before the callback is called. Set by `stop` modifier.
+    pub stop_propagation: bool,
+    /// Callback is called only for the first event. Set by `once` modifier.
+    pub once: bool,
+    /// Event is listened to by a passive listener, so `prevent_default` has no effect. Set by `passive` modifier.
+    pub passive: bool,
+    /// Callback is called in the capture phase, before handlers of descendants. Set by `capture` modifier.
+    pub capture: bool,
+}
+
+#[doc(hidden)]
 pub struct EventHandler {
     event_creator: Box<dyn EventCreator>,
-    event_listener: Option<EventListener>,
+    options: EventOptions,
+    registration: Option<Registration>,
 }
 
 impl EventHandler {
     pub fn new(event_creator: Box<dyn EventCreator>) -> Self {
+        Self::new_with_options(event_creator, EventOptions::default())
+    }
+
+    pub fn new_with_options(event_creator: Box<dyn EventCreator>, options: EventOptions) -> Self {
         Self {
             event_creator,
-            event_listener: None,
+            options,
+            registration: None,
         }
     }
 
+    /// Registers the handler for the `element` in the delegated event system.
+    /// No DOM event listener is attached to the `element` itself, events are dispatched from a single listener on the root element.
     pub(crate) fn attach(&mut self, element: &Element) {
         let event_type = self.get_event_type();
         let callback = self.event_creator.create_callback();
-        self.event_listener = Some(dom::create_event_listener(element, event_type, callback));
+        self.registration = Some(delegation::register(
+            element,
+            event_type,
+            self.options,
+            self.event_creator.is_custom(),
+            callback,
+        ));
     }
 
     pub(crate) fn get_event_type(&self) -> Cow<'static, str> {
         self.event_creator.get_event_type()
     }
+
+    pub(crate) fn is_attached(&self) -> bool {
+        self.registration.is_some()
+    }
+
+    /// Checks whether handlers are interchangeable, meaning they are of the [same kind](EventHandler::is_same_kind) and wrap the same [Callback].
+    pub(crate) fn is_same(&self, other: &EventHandler) -> bool {
+        *self.event_creator == *other.event_creator && self.options == other.options
+    }
+
+    /// Checks whether handlers are of the same event type and have the same [options](EventOptions).
+    pub(crate) fn is_same_kind(&self, other: &EventHandler) -> bool {
+        self.get_event_type() == other.get_event_type() && self.options == other.options
+    }
+
+    /// Takes over the registration of the `last` handler attached to the same element, therefore no registration is created nor removed.
+    /// If handlers are not the [same](EventHandler::is_same), the callback of the registration is replaced.
+    pub(crate) fn reuse(&mut self, last: &mut EventHandler) {
+        let registration = last
+            .registration
+            .take()
+            .expect("Reused event handler should be attached");
+        if !self.is_same(last) {
+            registration.set_callback(self.event_creator.create_callback());
+        }
+        self.registration = Some(registration);
+    }
 }
 
 impl PartialEq for EventHandler {
     fn eq(&self, other: &Self) -> bool {
-        let event_listener_eq = match (&self.event_listener, &other.event_listener) {
-            (Some(self_event_listener), Some(other_event_listener)) => {
-                self_event_listener.event_type() == other_event_listener.event_type()
-            }
-            (None, None) => true,
-            _ => false,
-        };
-        event_listener_eq && *self.event_creator == *other.event_creator
+        self.is_same(other)
     }
 }
 
 impl Eq for EventHandler {}
 
+impl Debug for EventHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventHandler")
+            .field("event_creator", &self.event_creator)
+            .field("options", &self.options)
+            .field("is_attached", &self.registration.is_some())
+            .finish()
+    }
+}
+
 impl Hash for EventHandler {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.get_event_type().hash(state);
+        self.event_creator.get_callback_id().hash(state);
     }
 }
 
@@ -358,6 +531,10 @@ mod tests {
             self.event_type.clone()
         }
 
+        fn get_callback_id(&self) -> usize {
+            Rc::as_ptr(&self.flag) as usize
+        }
+
         fn create_callback(&self) -> Box<dyn FnMut(&web_sys::Event)> {
             let flag = self.flag.clone();
             Box::new(move |_| {
@@ -369,9 +546,11 @@ mod tests {
     #[wasm_bindgen_test]
     fn event_handler_attach_should_attach_event_to_an_element() {
         // Arrange
-        let window = web_sys::window().unwrap();
-        let document = window.document().unwrap();
-        let body = document.body().unwrap();
+        let element = crate::virtual_dom::dom::create_element("div");
+        crate::virtual_dom::dom::append_child(
+            &crate::virtual_dom::dom::get_root_element(),
+            &element,
+        );
 
         let flag = Rc::new(RefCell::new(false));
         let event_creator = Box::new(TestEventCreator {
@@ -379,11 +558,79 @@ mod tests {
             flag: flag.clone(),
         });
         let mut handler = EventHandler::new(event_creator);
-        handler.attach(&body);
-        let event = web_sys::Event::new("click").unwrap();
+        handler.attach(&element);
+        let event_init = web_sys::EventInit::new();
+        event_init.set_bubbles(true);
+        let event = web_sys::Event::new_with_event_init_dict("click", &event_init).unwrap();
+
+        // Act
+        element.dispatch_event(&event).unwrap();
+
+        // Assert
+        assert!(*flag.borrow());
+    }
+
+    #[wasm_bindgen_test]
+    fn oncopy_specialized_event_handler_constructor_should_create_event_handler_with_clipboard_event(
+    ) {
+        // Arrange
+        let was_callback_executed = Rc::new(RefCell::new(false));
+        let was_callback_executed_clone = was_callback_executed.clone();
+        let callback = Callback::new(move |event: ClipboardEvent| {
+            assert_eq!(event.type_(), "copy");
+            *was_callback_executed_clone.borrow_mut() = true;
+        });
+
+        // Act
+        let event_creator = oncopy(callback);
+
+        // Assert
+        let mut event_callback = event_creator.create_callback();
+        (*event_callback)(web_sys::ClipboardEvent::new("copy").unwrap().as_ref());
+        assert!(*was_callback_executed.borrow());
+    }
+
+    #[wasm_bindgen_test]
+    fn custom_event_handler_should_receive_dispatched_custom_event() {
+        // Arrange
+        let element = crate::virtual_dom::dom::create_element("div");
+        crate::virtual_dom::dom::append_child(
+            &crate::virtual_dom::dom::get_root_element(),
+            &element,
+        );
+        let detail = Rc::new(RefCell::new(None));
+        let detail_clone = detail.clone();
+        let callback = Callback::new(move |event: CustomEvent| {
+            *detail_clone.borrow_mut() = event.detail().as_f64();
+        });
+        let mut handler = EventHandler::new(on_custom_event("item-selected", callback));
+        handler.attach(&element);
+
+        // Act
+        dispatch_custom_event(&element, "item-selected", &JsValue::from(5));
+
+        // Assert
+        assert_eq!(*detail.borrow(), Some(5.0));
+    }
+
+    #[wasm_bindgen_test]
+    fn custom_event_handler_should_receive_not_bubbling_custom_event_dispatched_on_its_element() {
+        // Arrange
+        let element = crate::virtual_dom::dom::create_element("div");
+        crate::virtual_dom::dom::append_child(
+            &crate::virtual_dom::dom::get_root_element(),
+            &element,
+        );
+        let flag = Rc::new(RefCell::new(false));
+        let flag_clone = flag.clone();
+        let callback = Callback::new(move |_: CustomEvent| *flag_clone.borrow_mut() = true);
+        let mut handler = EventHandler::new(on_custom_event("opened", callback));
+        handler.attach(&element);
 
         // Act
-        body.dispatch_event(&event).unwrap();
+        element
+            .dispatch_event(&web_sys::CustomEvent::new("opened").unwrap())
+            .unwrap();
 
         // Assert
         assert!(*flag.borrow());
@@ -402,4 +649,22 @@ mod tests {
         // Assert
         assert_eq!(event_type, "click");
     }
+
+    #[wasm_bindgen_test]
+    fn event_handlers_should_be_equal_only_if_they_wrap_the_same_callback() {
+        // Arrange
+        let callback = Callback::new(|_| {});
+        let handler = EventHandler::new(onclick(callback.clone()));
+        let same_callback_handler = EventHandler::new(onclick(callback));
+        let other_callback_handler = EventHandler::new(onclick(Callback::new(|_| {})));
+
+        // Act
+        let is_equal_to_same_callback_handler = handler == same_callback_handler;
+        let is_equal_to_other_callback_handler = handler == other_callback_handler;
+
+        // Assert
+        assert!(is_equal_to_same_callback_handler);
+        assert!(!is_equal_to_other_callback_handler);
+        assert!(handler.is_same_kind(&other_callback_handler));
+    }
 }
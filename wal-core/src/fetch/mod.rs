@@ -0,0 +1,330 @@
+use std::{error::Error, fmt};
+
+pub use self::transport::{set_transport, FetchTransport, Transport, TransportFuture};
+
+mod transport;
+
+/// HTTP method of the [Request].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+    Head,
+}
+
+impl Method {
+    /// Returns the name of the method, as it is sent in the request.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Patch => "PATCH",
+            Method::Delete => "DELETE",
+            Method::Head => "HEAD",
+        }
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// HTTP request sent by the [Transport]. Requests are sent using [send] function or
+/// using [Behavior](crate::component::Behavior), which delivers responses to the component as messages.
+///
+/// # Examples
+///
+/// ```ignore
+/// let request = Request::post("/api/todos")
+///     .header("Authorization", "Bearer token")
+///     .body("{\"title\": \"Buy milk\"}");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    method: Method,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<String>,
+}
+
+impl Request {
+    /// Creates a [Request] with provided [Method] to the `url`.
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// Creates a `GET` [Request] to the `url`.
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new(Method::Get, url)
+    }
+
+    /// Creates a `POST` [Request] to the `url`.
+    pub fn post(url: impl Into<String>) -> Self {
+        Self::new(Method::Post, url)
+    }
+
+    /// Creates a `PUT` [Request] to the `url`.
+    pub fn put(url: impl Into<String>) -> Self {
+        Self::new(Method::Put, url)
+    }
+
+    /// Creates a `PATCH` [Request] to the `url`.
+    pub fn patch(url: impl Into<String>) -> Self {
+        Self::new(Method::Patch, url)
+    }
+
+    /// Creates a `DELETE` [Request] to the `url`.
+    pub fn delete(url: impl Into<String>) -> Self {
+        Self::new(Method::Delete, url)
+    }
+
+    /// Creates a `HEAD` [Request] to the `url`.
+    pub fn head(url: impl Into<String>) -> Self {
+        Self::new(Method::Head, url)
+    }
+
+    /// Adds a header to the [Request].
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the body of the [Request].
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Serializes the `value` to JSON, sets it as the body of the [Request] and sets `Content-Type` header to `application/json`.
+    #[cfg(feature = "serde")]
+    pub fn json<T: serde::Serialize + ?Sized>(self, value: &T) -> Result<Self, FetchError> {
+        let body = serde_json::to_string(value)
+            .map_err(|error| FetchError::Serialization(error.to_string()))?;
+        Ok(self.header("Content-Type", "application/json").body(body))
+    }
+
+    /// Returns the [Method] of the [Request].
+    pub fn get_method(&self) -> Method {
+        self.method
+    }
+
+    /// Returns the url the [Request] is sent to.
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the headers of the [Request] as name-value pairs, in the order they were added.
+    pub fn get_headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Returns the body of the [Request], if it was set.
+    pub fn get_body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+}
+
+/// HTTP response received by the [Transport]. Response is returned for any status, see [error_for_status](Response::error_for_status).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl Response {
+    /// Creates a [Response] with provided `status` and `body`. Useful for mock [transports](Transport).
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Adds a header to the [Response].
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Returns the HTTP status code of the [Response].
+    pub fn get_status(&self) -> u16 {
+        self.status
+    }
+
+    /// Checks whether the status of the [Response] is in range 200-299.
+    pub fn ok(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Returns the value of the first header with provided `name`. Names are compared case insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the headers of the [Response] as name-value pairs.
+    pub fn get_headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Returns the body of the [Response] as text.
+    pub fn text(&self) -> &str {
+        &self.body
+    }
+
+    /// Deserializes the body of the [Response] from JSON.
+    #[cfg(feature = "serde")]
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T, FetchError> {
+        serde_json::from_str(&self.body)
+            .map_err(|error| FetchError::Deserialization(error.to_string()))
+    }
+
+    /// Turns the [Response] into [FetchError::Status] if its status is not [ok](Response::ok).
+    pub fn error_for_status(self) -> Result<Self, FetchError> {
+        if self.ok() {
+            Ok(self)
+        } else {
+            Err(FetchError::Status {
+                status: self.status,
+                body: self.body,
+            })
+        }
+    }
+}
+
+/// Error of sending the [Request] or handling its [Response].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FetchError {
+    /// Request could not be sent or response could not be received, e.g. because of the network failure or CORS policy.
+    Network(String),
+    /// Response was received, but its status is not [ok](Response::ok). Returned by [error_for_status](Response::error_for_status).
+    Status { status: u16, body: String },
+    /// Body of the request could not be serialized.
+    Serialization(String),
+    /// Body of the response could not be deserialized.
+    Deserialization(String),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Network(message) => write!(f, "Network error: {message}"),
+            FetchError::Status { status, .. } => write!(f, "Response has status {status}"),
+            FetchError::Serialization(message) => write!(f, "Serialization error: {message}"),
+            FetchError::Deserialization(message) => {
+                write!(f, "Deserialization error: {message}")
+            }
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+/// Sends the [Request] using the current [Transport], see [set_transport].
+/// To deliver the [Response] to a component as a message, use [Behavior](crate::component::Behavior) instead.
+pub async fn send(request: Request) -> Result<Response, FetchError> {
+    transport::get_transport().send(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn request_builder_should_set_method_url_headers_and_body() {
+        // Act
+        let request = Request::post("/api/todos")
+            .header("Accept", "text/plain")
+            .body("todo");
+
+        // Assert
+        assert_eq!(request.get_method(), Method::Post);
+        assert_eq!(request.get_url(), "/api/todos");
+        assert_eq!(
+            request.get_headers(),
+            &[("Accept".to_string(), "text/plain".to_string())]
+        );
+        assert_eq!(request.get_body(), Some("todo"));
+    }
+
+    #[wasm_bindgen_test]
+    fn response_header_should_be_found_case_insensitively() {
+        // Arrange
+        let response = Response::new(200, "").with_header("Content-Type", "text/plain");
+
+        // Act
+        let header = response.header("content-type");
+
+        // Assert
+        assert_eq!(header, Some("text/plain"));
+    }
+
+    #[wasm_bindgen_test]
+    fn error_for_status_should_return_error_for_not_ok_status() {
+        // Arrange
+        let response = Response::new(404, "Not found");
+
+        // Act
+        let result = response.error_for_status();
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(FetchError::Status {
+                status: 404,
+                body: "Not found".to_string()
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn send_should_use_injected_transport() {
+        // Arrange
+        set_transport(|request: Request| {
+            Ok(Response::new(
+                200,
+                format!("{} {}", request.get_method(), request.get_url()),
+            ))
+        });
+
+        // Act
+        let response = send(Request::get("/api/todos")).await;
+
+        // Assert
+        assert_eq!(response.unwrap().text(), "GET /api/todos");
+        set_transport(FetchTransport::default());
+    }
+
+    #[cfg(feature = "serde")]
+    #[wasm_bindgen_test]
+    fn json_should_serialize_body_and_deserialize_response() {
+        // Arrange
+        let request = Request::post("/api/numbers").json(&vec![1, 2]).unwrap();
+        let response = Response::new(200, request.get_body().unwrap());
+
+        // Act
+        let numbers = response.json::<Vec<u32>>();
+
+        // Assert
+        assert_eq!(request.get_body(), Some("[1,2]"));
+        assert_eq!(numbers, Ok(vec![1, 2]));
+    }
+}
@@ -0,0 +1,124 @@
+use std::{cell::RefCell, future::Future, pin::Pin, rc::Rc};
+
+use gloo::net::http;
+use web_sys::AbortController;
+
+use super::{FetchError, Method, Request, Response};
+
+pub type TransportFuture = Pin<Box<dyn Future<Output = Result<Response, FetchError>>>>;
+
+/// Transport sends [requests](Request) and receives [responses](Response).
+/// By default [FetchTransport] is used. It can be replaced with [set_transport], e.g. by a mock transport in tests.
+/// Every closure taking a [Request] and returning a result of the [Response] is a transport as well.
+pub trait Transport {
+    /// Sends the [Request]. Dropping the returned future should abort the request if it is still pending.
+    fn send(&self, request: Request) -> TransportFuture;
+}
+
+impl<F> Transport for F
+where
+    F: Fn(Request) -> Result<Response, FetchError>,
+{
+    fn send(&self, request: Request) -> TransportFuture {
+        let result = self(request);
+        Box::pin(async move { result })
+    }
+}
+
+/// [Transport] using the `fetch` function of the browser.
+#[derive(Debug, Clone, Default)]
+pub struct FetchTransport {
+    base_url: Option<String>,
+}
+
+impl FetchTransport {
+    /// Creates [FetchTransport], which prepends the `base_url` to the urls of requests starting with `/`.
+    /// Useful for sending requests to a local stand-in server in tests.
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: Some(base_url.into()),
+        }
+    }
+
+    fn get_url(&self, url: &str) -> String {
+        match &self.base_url {
+            Some(base_url) if url.starts_with('/') => {
+                format!("{}{}", base_url.trim_end_matches('/'), url)
+            }
+            _ => url.to_string(),
+        }
+    }
+}
+
+impl Transport for FetchTransport {
+    fn send(&self, request: Request) -> TransportFuture {
+        let url = self.get_url(request.get_url());
+        Box::pin(async move {
+            let abort_on_drop = AbortOnDrop::new();
+            let mut builder = http::RequestBuilder::new(&url)
+                .method(get_http_method(request.get_method()))
+                .abort_signal(Some(&abort_on_drop.0.signal()));
+            for (name, value) in request.get_headers() {
+                builder = builder.header(name, value);
+            }
+
+            let response = match request.get_body() {
+                Some(body) => builder.body(body).map_err(network_error)?.send().await,
+                None => builder.send().await,
+            }
+            .map_err(network_error)?;
+
+            let status = response.status();
+            let headers = response.headers().entries().collect();
+            let body = response.text().await.map_err(network_error)?;
+            Ok(Response {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+/// Aborts the pending request when the future sending it is dropped. Aborting the finished request has no effect.
+struct AbortOnDrop(AbortController);
+
+impl AbortOnDrop {
+    fn new() -> Self {
+        Self(AbortController::new().expect("Couldnt create abort controller"))
+    }
+}
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+fn get_http_method(method: Method) -> http::Method {
+    match method {
+        Method::Get => http::Method::GET,
+        Method::Post => http::Method::POST,
+        Method::Put => http::Method::PUT,
+        Method::Patch => http::Method::PATCH,
+        Method::Delete => http::Method::DELETE,
+        Method::Head => http::Method::HEAD,
+    }
+}
+
+fn network_error(error: gloo::net::Error) -> FetchError {
+    FetchError::Network(error.to_string())
+}
+
+thread_local! {
+    static TRANSPORT: RefCell<Rc<dyn Transport>> = RefCell::new(Rc::new(FetchTransport::default()));
+}
+
+/// Replaces the [Transport] used to send all of the following requests.
+pub fn set_transport(transport: impl Transport + 'static) {
+    TRANSPORT.with(|x| *x.borrow_mut() = Rc::new(transport));
+}
+
+pub(crate) fn get_transport() -> Rc<dyn Transport> {
+    TRANSPORT.with(|x| x.borrow().clone())
+}
@@ -0,0 +1,507 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use gloo::utils::{document, head};
+use itertools::Itertools;
+use web_sys::Element;
+
+use crate::virtual_dom::{dom, VElement, VNode};
+
+/// Attribute identifying the single `meta` element declaring the encoding of the document.
+const META_CHARSET_ATTR: &str = "charset";
+/// Attributes identifying the `meta` element, e.g. `name="description"` or `property="og:title"`.
+const META_KEY_ATTRS: [&str; 3] = ["name", "property", "http-equiv"];
+/// Attribute identifying the `link` element together with its `href`, e.g. `rel="stylesheet"`.
+const LINK_REL_ATTR: &str = "rel";
+const LINK_HREF_ATTR: &str = "href";
+/// Relations of the `link` element, which can be declared only once in the document, so they are identified by the `rel` alone.
+const SINGLETON_LINK_RELS: [&str; 2] = ["canonical", "manifest"];
+
+/// Element of the `<head>` managed by the [HeadManager]. Declarations of the same key conflict with each other.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum HeadKey {
+    Title,
+    /// Element identified by the attributes. Attribute without a value identifies the element by its presence.
+    Element {
+        tag_name: &'static str,
+        attributes: Vec<(&'static str, Option<String>)>,
+    },
+}
+
+impl HeadKey {
+    fn selector(&self) -> Option<String> {
+        match self {
+            HeadKey::Title => None,
+            HeadKey::Element {
+                tag_name,
+                attributes,
+            } => {
+                let attributes: String = attributes
+                    .iter()
+                    .map(|(attribute, value)| match value {
+                        Some(value) => {
+                            let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+                            format!("[{attribute}=\"{value}\"]")
+                        }
+                        None => format!("[{attribute}]"),
+                    })
+                    .collect();
+                Some(format!("{tag_name}{attributes}"))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum HeadValue {
+    Title(String),
+    Attributes(HashMap<String, String>),
+}
+
+pub(crate) type HeadEntries = Vec<(HeadKey, HeadValue)>;
+
+/// Converts the children of the `Head` into the entries of the `<head>`.
+/// Only `title`, `meta` and `link` elements, also wrapped in lists, are supported. Other nodes are reported in the console and skipped.
+pub(crate) fn get_entries(node: &VNode) -> HeadEntries {
+    let mut entries = Vec::new();
+    collect_entries(node, &mut entries);
+    entries
+}
+
+fn collect_entries(node: &VNode, entries: &mut HeadEntries) {
+    match node {
+        VNode::Element(velement) => entries.extend(get_entry(velement)),
+        VNode::List(vlist) => {
+            for node in vlist.nodes.iter() {
+                collect_entries(node, entries);
+            }
+        }
+        _ => warn("Head supports only title, meta and link elements"),
+    }
+}
+
+fn get_entry(velement: &VElement) -> Option<(HeadKey, HeadValue)> {
+    let attribute = |name: &'static str| {
+        velement
+            .attr
+            .get(name)
+            .map(|value| (name, Some(value.clone())))
+    };
+    let element_key = |tag_name, attributes: Option<Vec<_>>, required: &str| {
+        let key = attributes.map(|attributes| HeadKey::Element {
+            tag_name,
+            attributes,
+        });
+        if key.is_none() {
+            warn(&format!(
+                "Head element `{tag_name}` requires {required} attribute"
+            ));
+        }
+        key
+    };
+
+    let key = match velement.tag_name.as_str() {
+        "title" => {
+            return Some((
+                HeadKey::Title,
+                HeadValue::Title(get_text(&velement.children)),
+            ))
+        }
+        "meta" => {
+            let attributes = if velement.attr.contains_key(META_CHARSET_ATTR) {
+                Some(vec![(META_CHARSET_ATTR, None)])
+            } else {
+                META_KEY_ATTRS
+                    .into_iter()
+                    .find_map(attribute)
+                    .map(|attribute| vec![attribute])
+            };
+            element_key(
+                "meta",
+                attributes,
+                "`charset`, `name`, `property` or `http-equiv`",
+            )
+        }
+        "link" => {
+            let attributes = attribute(LINK_REL_ATTR).and_then(|rel| {
+                let is_singleton = rel
+                    .1
+                    .as_deref()
+                    .is_some_and(|rel| SINGLETON_LINK_RELS.contains(&rel));
+                if is_singleton {
+                    Some(vec![rel])
+                } else {
+                    attribute(LINK_HREF_ATTR).map(|href| vec![rel, href])
+                }
+            });
+            element_key("link", attributes, "`rel` and `href`")
+        }
+        tag_name => {
+            warn(&format!(
+                "Head supports only title, meta and link elements, got `{tag_name}`"
+            ));
+            None
+        }
+    }?;
+    Some((key, HeadValue::Attributes(velement.attr.clone())))
+}
+
+fn get_text(nodes: &[VNode]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            VNode::Text(vtext) => vtext.text.clone(),
+            VNode::List(vlist) => get_text(&vlist.nodes),
+            _ => {
+                warn("Head title supports only text");
+                String::new()
+            }
+        })
+        .collect()
+}
+
+fn warn(message: &str) {
+    web_sys::console::warn_1(&message.into());
+}
+
+struct Declaration {
+    id: u64,
+    depth: u32,
+    entries: HeadEntries,
+}
+
+/// Keeps the elements of the `<head>` declared by the components. If multiple components declare the same element,
+/// the deepest one wins, and among the components at the same depth the one declared last, e.g. the page, which is being navigated to.
+/// Values of the elements before the first declaration are restored when all declarations of the element are removed.
+#[derive(Default)]
+struct HeadManager {
+    declarations: Vec<Declaration>,
+    originals: HashMap<HeadKey, Option<HeadValue>>,
+    next_id: u64,
+}
+
+thread_local! {
+    static HEAD_MANAGER: RefCell<HeadManager> = RefCell::new(HeadManager::default());
+}
+
+impl HeadManager {
+    fn declare(&mut self, depth: u32, entries: HeadEntries) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        let keys: Vec<HeadKey> = entries.iter().map(|(key, _)| key.clone()).collect();
+        self.declarations.push(Declaration { id, depth, entries });
+        self.apply(keys);
+        id
+    }
+
+    fn update(&mut self, id: u64, depth: u32, entries: HeadEntries) {
+        let Some(declaration) = self.declarations.iter_mut().find(|x| x.id == id) else {
+            return;
+        };
+        if declaration.depth == depth && declaration.entries == entries {
+            return;
+        }
+        let keys = declaration
+            .entries
+            .iter()
+            .chain(entries.iter())
+            .map(|(key, _)| key.clone())
+            .collect();
+        declaration.depth = depth;
+        declaration.entries = entries;
+        self.apply(keys);
+    }
+
+    fn remove(&mut self, id: u64) {
+        let Some(index) = self.declarations.iter().position(|x| x.id == id) else {
+            return;
+        };
+        let declaration = self.declarations.remove(index);
+        self.apply(
+            declaration
+                .entries
+                .into_iter()
+                .map(|(key, _)| key)
+                .collect(),
+        );
+    }
+
+    fn apply(&mut self, keys: Vec<HeadKey>) {
+        for key in keys.into_iter().unique() {
+            let winner = self
+                .declarations
+                .iter()
+                .filter_map(|declaration| {
+                    declaration
+                        .entries
+                        .iter()
+                        .rev()
+                        .find(|(x, _)| *x == key)
+                        .map(|(_, value)| (declaration.depth, declaration.id, value))
+                })
+                .max_by_key(|(depth, id, _)| (*depth, *id))
+                .map(|(_, _, value)| value.clone());
+
+            match winner {
+                Some(value) => {
+                    self.originals
+                        .entry(key.clone())
+                        .or_insert_with(|| read(&key));
+                    write(&key, Some(&value));
+                }
+                None => {
+                    if let Some(original) = self.originals.remove(&key) {
+                        write(&key, original.as_ref());
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn find_element(key: &HeadKey) -> Option<Element> {
+    key.selector()
+        .and_then(|selector| head().query_selector(&selector).ok().flatten())
+}
+
+fn read(key: &HeadKey) -> Option<HeadValue> {
+    match key {
+        HeadKey::Title => Some(HeadValue::Title(document().title())),
+        HeadKey::Element { .. } => find_element(key).map(|element| {
+            let names = element.get_attribute_names();
+            HeadValue::Attributes(
+                names
+                    .iter()
+                    .filter_map(|name| name.as_string())
+                    .filter_map(|name| element.get_attribute(&name).map(|value| (name, value)))
+                    .collect(),
+            )
+        }),
+    }
+}
+
+fn write(key: &HeadKey, value: Option<&HeadValue>) {
+    match (key, value) {
+        (HeadKey::Title, Some(HeadValue::Title(title))) => document().set_title(title),
+        (HeadKey::Title, _) => document().set_title(""),
+        (HeadKey::Element { tag_name, .. }, Some(HeadValue::Attributes(attributes))) => {
+            let element = find_element(key).unwrap_or_else(|| {
+                let element = dom::create_element(tag_name);
+                dom::append_child(&head(), &element);
+                element
+            });
+            for name in element.get_attribute_names().iter() {
+                if let Some(name) = name.as_string() {
+                    if !attributes.contains_key(&name) {
+                        dom::remove_attribute(&element, &name);
+                    }
+                }
+            }
+            for (name, value) in attributes {
+                dom::set_attribute(&element, name, value);
+            }
+        }
+        (HeadKey::Element { .. }, _) => {
+            if let Some(element) = find_element(key) {
+                element.remove();
+            }
+        }
+    }
+}
+
+/// Declaration of the elements of the `<head>`, which is removed when dropped.
+#[derive(Debug)]
+pub(crate) struct HeadDeclaration {
+    id: u64,
+}
+
+impl HeadDeclaration {
+    pub(crate) fn new(depth: u32, entries: HeadEntries) -> Self {
+        let id = HEAD_MANAGER.with(|manager| manager.borrow_mut().declare(depth, entries));
+        Self { id }
+    }
+
+    pub(crate) fn update(&self, depth: u32, entries: HeadEntries) {
+        HEAD_MANAGER.with(|manager| manager.borrow_mut().update(self.id, depth, entries));
+    }
+
+    pub(crate) fn remove(&self) {
+        HEAD_MANAGER.with(|manager| manager.borrow_mut().remove(self.id));
+    }
+}
+
+impl Drop for HeadDeclaration {
+    fn drop(&mut self) {
+        self.remove();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn title(title: &str) -> (HeadKey, HeadValue) {
+        (HeadKey::Title, HeadValue::Title(title.to_string()))
+    }
+
+    fn description(content: &str) -> (HeadKey, HeadValue) {
+        (
+            HeadKey::Element {
+                tag_name: "meta",
+                attributes: vec![("name", Some("description".to_string()))],
+            },
+            HeadValue::Attributes(
+                [
+                    ("name".to_string(), "description".to_string()),
+                    ("content".to_string(), content.to_string()),
+                ]
+                .into(),
+            ),
+        )
+    }
+
+    fn get_description() -> Option<String> {
+        head()
+            .query_selector("meta[name=\"description\"]")
+            .unwrap()
+            .and_then(|x| x.get_attribute("content"))
+    }
+
+    #[wasm_bindgen_test]
+    fn deeper_declaration_should_win() {
+        // Arrange
+        let _page = HeadDeclaration::new(1, vec![title("Page")]);
+
+        // Act
+        let _nested = HeadDeclaration::new(3, vec![title("Nested")]);
+        let _sibling = HeadDeclaration::new(1, vec![title("Sibling")]);
+
+        // Assert
+        assert_eq!(document().title(), "Nested");
+    }
+
+    #[wasm_bindgen_test]
+    fn later_declaration_at_the_same_depth_should_win() {
+        // Arrange
+        let _old_page = HeadDeclaration::new(1, vec![title("Old page")]);
+
+        // Act
+        let _new_page = HeadDeclaration::new(1, vec![title("New page")]);
+
+        // Assert
+        assert_eq!(document().title(), "New page");
+    }
+
+    #[wasm_bindgen_test]
+    fn removed_declaration_should_restore_previous_value() {
+        // Arrange
+        document().set_title("Original");
+        let page = HeadDeclaration::new(1, vec![title("Page")]);
+        let nested = HeadDeclaration::new(2, vec![title("Nested")]);
+
+        // Act
+        drop(nested);
+        let page_title = document().title();
+        drop(page);
+
+        // Assert
+        assert_eq!(page_title, "Page");
+        assert_eq!(document().title(), "Original");
+    }
+
+    #[wasm_bindgen_test]
+    fn declared_meta_should_be_created_updated_and_removed() {
+        // Arrange
+        let declaration = HeadDeclaration::new(1, vec![description("First")]);
+        let created = get_description();
+
+        // Act
+        declaration.update(1, vec![description("Second")]);
+        let updated = get_description();
+        drop(declaration);
+
+        // Assert
+        assert_eq!(created, Some("First".to_string()));
+        assert_eq!(updated, Some("Second".to_string()));
+        assert_eq!(get_description(), None);
+    }
+
+    fn element(tag_name: &str, attributes: &[(&str, &str)]) -> VNode {
+        VNode::Element(VElement::new(
+            tag_name.to_string(),
+            attributes
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            vec![],
+            None,
+            vec![],
+        ))
+    }
+
+    fn has_element(selector: &str) -> bool {
+        head().query_selector(selector).unwrap().is_some()
+    }
+
+    #[wasm_bindgen_test]
+    fn links_with_the_same_rel_and_different_href_should_not_conflict() {
+        // Arrange
+        let links = VNode::List(crate::virtual_dom::VList::new(
+            vec![
+                element("link", &[("rel", "stylesheet"), ("href", "/first.css")]),
+                element("link", &[("rel", "stylesheet"), ("href", "/second.css")]),
+            ],
+            None,
+        ));
+
+        // Act
+        let declaration = HeadDeclaration::new(1, get_entries(&links));
+        let declared = (
+            has_element("link[href=\"/first.css\"]"),
+            has_element("link[href=\"/second.css\"]"),
+        );
+        drop(declaration);
+
+        // Assert
+        assert_eq!(declared, (true, true));
+        assert!(!has_element("link[href=\"/first.css\"]"));
+    }
+
+    #[wasm_bindgen_test]
+    fn meta_charset_should_be_identified_by_its_presence() {
+        // Arrange
+        let utf8 = element("meta", &[("charset", "utf-8")]);
+        let latin2 = element("meta", &[("charset", "iso-8859-2")]);
+
+        // Act
+        let _page = HeadDeclaration::new(1, get_entries(&utf8));
+        let _nested = HeadDeclaration::new(2, get_entries(&latin2));
+
+        // Assert
+        assert_eq!(
+            head()
+                .query_selector("meta[charset]")
+                .unwrap()
+                .and_then(|x| x.get_attribute("charset")),
+            Some("iso-8859-2".to_string())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn unsupported_children_should_be_skipped() {
+        // Act
+        let entries = get_entries(&VNode::List(crate::virtual_dom::VList::new(
+            vec![
+                VNode::Text(crate::virtual_dom::VText::new("text")),
+                element("div", &[]),
+                element("link", &[("rel", "icon")]),
+            ],
+            None,
+        )));
+
+        // Assert
+        assert!(entries.is_empty());
+    }
+}
@@ -1,12 +1,22 @@
 //! This crate provides the core functionalities of wal library, which are essential to build a web application.
 //! This includes component, events, routing and virtual dom.
 
+/// Module `app` provides the `mount` and `unmount` functions, which run components as independent applications inside of existing elements of the page.
+pub mod app;
 /// Module `component` provides the `Component` trait, which is the core of wal library. It enables for creating components, which are the building blocks of a web application.
 pub mod component;
 /// Module `events` provides the event functions and types, which is used to handle events in a web application.
 pub mod events;
+/// Module `fetch` provides the `Request` and `Response` types and the `Transport` trait, which are used to communicate with HTTP servers.
+pub mod fetch;
+pub(crate) mod head;
 /// Module `router` provides the `PageRenderer` and `Router` structs, which are used to run application and handle routing.
 pub mod router;
+/// Module `storage` provides the `Persisted` values, which are kept in the web storage and rerender the components reading them when they change.
+#[cfg(feature = "serde")]
+pub mod storage;
 pub(crate) mod utils;
 /// Module `virtual_dom` provides functions and types related to VDOM. VDOM is later translated into real DOM, that is rendered in the browser.
 pub mod virtual_dom;
+/// Module `websocket` provides the WebSocket connections, which deliver received frames to components as messages.
+pub mod websocket;
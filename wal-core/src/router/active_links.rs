@@ -0,0 +1,48 @@
+use std::{cell::RefCell, rc::Weak};
+
+use crate::component::{node::AnyComponentNode, scheduler::Scheduler};
+
+type NodeRef = Weak<RefCell<AnyComponentNode>>;
+
+thread_local! {
+    /// Components, which are currently being viewed. The last one is the innermost.
+    static VIEWED_COMPONENTS: RefCell<Vec<NodeRef>> = const { RefCell::new(Vec::new()) };
+    /// Components, which rendered a link with an active class since the last navigation.
+    static LINK_OWNERS: RefCell<Vec<NodeRef>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Calls `view` with the `node` being the currently viewed component, so links it renders are assigned to it.
+pub(crate) fn with_viewed_component<R>(node: NodeRef, view: impl FnOnce() -> R) -> R {
+    struct PopOnDrop;
+    impl Drop for PopOnDrop {
+        fn drop(&mut self) {
+            VIEWED_COMPONENTS.with(|x| x.borrow_mut().pop());
+        }
+    }
+
+    VIEWED_COMPONENTS.with(|x| x.borrow_mut().push(node));
+    let _pop_on_drop = PopOnDrop;
+    view()
+}
+
+/// Remembers the currently viewed component as the owner of a link with an active class.
+pub(crate) fn register_link_owner() {
+    let Some(node) = VIEWED_COMPONENTS.with(|x| x.borrow().last().cloned()) else {
+        return;
+    };
+    LINK_OWNERS.with(|x| {
+        let mut owners = x.borrow_mut();
+        if !owners.iter().any(|owner| Weak::ptr_eq(owner, &node)) {
+            owners.push(node);
+        }
+    });
+}
+
+/// Rerenders components, which rendered a link with an active class, so the class reflects the current path.
+/// Components kept after the navigation, e.g. the navigation bar with unchanged properties, are not viewed again otherwise.
+pub(crate) fn invalidate_link_owners() {
+    let owners = LINK_OWNERS.with(|x| std::mem::take(&mut *x.borrow_mut()));
+    for owner in owners.into_iter().filter(|x| x.upgrade().is_some()) {
+        Scheduler::add_invalidate_message(owner);
+    }
+}
@@ -0,0 +1,181 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+use gloo::utils::window;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{BeforeUnloadEvent, Event};
+
+use super::Router;
+
+/// Navigation, which was blocked by the component [declaring](crate::component::behavior::Behavior::block_navigation) the blocker.
+/// It is not performed unless it is [resumed](BlockedNavigation::proceed), e.g. after the user confirms leaving the page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockedNavigation(NavigationKind);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NavigationKind {
+    Push(String),
+    Replace(String),
+    /// Navigation back or forward in the history by the number of entries.
+    Traverse(i32),
+}
+
+impl BlockedNavigation {
+    pub(crate) fn push(url: impl Into<String>) -> Self {
+        Self(NavigationKind::Push(url.into()))
+    }
+
+    pub(crate) fn replace(url: impl Into<String>) -> Self {
+        Self(NavigationKind::Replace(url.into()))
+    }
+
+    pub(crate) fn traverse(delta: i32) -> Self {
+        Self(NavigationKind::Traverse(delta))
+    }
+
+    /// Returns the url of the clicked link or [None] if the user went back or forward in the history.
+    pub fn get_url(&self) -> Option<&str> {
+        match &self.0 {
+            NavigationKind::Push(url) | NavigationKind::Replace(url) => Some(url),
+            NavigationKind::Traverse(_) => None,
+        }
+    }
+
+    /// Performs the navigation without consulting the blockers again. The navigation is deferred until the current task finishes,
+    /// so it can be called while the blocking component is being updated, as the page it is on may be replaced.
+    pub fn proceed(&self) {
+        let navigation = self.clone();
+        spawn_local(async move { navigation.perform() });
+    }
+
+    /// Performs the navigation immediately without consulting the blockers again.
+    pub(crate) fn perform(&self) {
+        match &self.0 {
+            NavigationKind::Push(url) => Router::navigate_to(url),
+            NavigationKind::Replace(url) => Router::replace_with(url),
+            NavigationKind::Traverse(delta) => Router::traverse(*delta),
+        }
+    }
+
+    /// Shows the `window.confirm` dialog with provided message and [proceeds](BlockedNavigation::proceed) if the user accepts it.
+    /// Returns `true` if the user accepted it.
+    pub fn confirm(&self, message: &str) -> bool {
+        let is_confirmed = window().confirm_with_message(message).unwrap_or(false);
+        if is_confirmed {
+            self.proceed();
+        }
+        is_confirmed
+    }
+}
+
+pub(crate) type BlockerCallback = Box<dyn Fn(BlockedNavigation)>;
+
+/// Navigation blocker declared by the component, which is notified instead of performing the navigation.
+pub(crate) struct NavigationBlocker {
+    callback: RefCell<BlockerCallback>,
+}
+
+impl NavigationBlocker {
+    pub(crate) fn new(callback: BlockerCallback) -> Rc<Self> {
+        let blocker = Rc::new(Self {
+            callback: RefCell::new(callback),
+        });
+        BLOCKERS.with(|blockers| blockers.borrow_mut().push(Rc::downgrade(&blocker)));
+        blocker
+    }
+
+    pub(crate) fn set_callback(&self, callback: BlockerCallback) {
+        *self.callback.borrow_mut() = callback;
+    }
+}
+
+thread_local! {
+    /// Blockers in the order of declaration. They are removed when dropped by the components.
+    static BLOCKERS: RefCell<Vec<Weak<NavigationBlocker>>> = const { RefCell::new(Vec::new()) };
+}
+
+fn get_active_blocker() -> Option<Rc<NavigationBlocker>> {
+    BLOCKERS.with(|blockers| {
+        let mut blockers = blockers.borrow_mut();
+        blockers.retain(|x| x.strong_count() > 0);
+        blockers.last().and_then(Weak::upgrade)
+    })
+}
+
+/// Notifies the most recently declared blocker about the navigation. Returns `false` if there is no blocker,
+/// so the navigation should be performed.
+pub(crate) fn block(navigation: BlockedNavigation) -> bool {
+    match get_active_blocker() {
+        Some(blocker) => {
+            (blocker.callback.borrow())(navigation);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Asks the browser to confirm leaving the page, e.g. by closing the tab or reloading it, if any blocker is declared.
+/// Browsers show their own dialog, which can not be customized.
+pub(crate) fn before_unload(event: Event) {
+    if get_active_blocker().is_some() {
+        event.prevent_default();
+        if let Some(event) = event.dyn_ref::<BeforeUnloadEvent>() {
+            event.set_return_value("");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn block_without_blockers_should_not_block() {
+        assert!(!block(BlockedNavigation::push("/")));
+    }
+
+    #[wasm_bindgen_test]
+    fn block_should_notify_the_last_declared_blocker() {
+        // Arrange
+        let notified = Rc::new(RefCell::new(Vec::new()));
+        let first_notified = notified.clone();
+        let _first = NavigationBlocker::new(Box::new(move |_| first_notified.borrow_mut().push(1)));
+        let second_notified = notified.clone();
+        let _second =
+            NavigationBlocker::new(Box::new(move |_| second_notified.borrow_mut().push(2)));
+
+        // Act
+        let is_blocked = block(BlockedNavigation::push("/"));
+
+        // Assert
+        assert!(is_blocked);
+        assert_eq!(*notified.borrow(), vec![2]);
+    }
+
+    #[wasm_bindgen_test]
+    fn dropped_blocker_should_not_block() {
+        // Arrange
+        let blocker = NavigationBlocker::new(Box::new(|_| {}));
+
+        // Act
+        drop(blocker);
+
+        // Assert
+        assert!(!block(BlockedNavigation::traverse(-1)));
+    }
+
+    #[wasm_bindgen_test]
+    fn blocked_navigation_should_return_url_of_link() {
+        assert_eq!(BlockedNavigation::push("/a").get_url(), Some("/a"));
+        assert_eq!(BlockedNavigation::replace("/b").get_url(), Some("/b"));
+        assert_eq!(BlockedNavigation::traverse(-1).get_url(), None);
+    }
+}
@@ -1,14 +1,27 @@
-use super::{not_found_component::NotFoundComponent, PageRenderer, Router};
+use super::{
+    not_found_component::NotFoundComponent, routable::Routable, PageRenderer, Router,
+    RoutesRenderer,
+};
 use crate::{
-    component::{node::AnyComponentNode, Component},
+    component::{
+        error_boundary::GlobalErrorCallback, node::AnyComponentNode, Component, ComponentError,
+    },
     virtual_dom::dom,
 };
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    rc::Rc,
+};
 
 /// Builds application with routing.
 pub struct RouterBuilder {
     pages: HashMap<&'static str, PageRenderer>,
+    routes: Vec<RoutesRenderer>,
     not_found_page: Option<PageRenderer>,
+    loading_page: Option<PageRenderer>,
+    scroll_disabled_paths: HashSet<&'static str>,
+    error_callback: Option<GlobalErrorCallback>,
 }
 
 impl Default for RouterBuilder {
@@ -16,7 +29,11 @@ impl Default for RouterBuilder {
     fn default() -> Self {
         RouterBuilder {
             pages: HashMap::new(),
+            routes: Vec::new(),
             not_found_page: None,
+            loading_page: None,
+            scroll_disabled_paths: HashSet::new(),
+            error_callback: None,
         }
     }
 }
@@ -30,9 +47,7 @@ impl RouterBuilder {
         let mut pages = self.pages;
         pages.insert(
             path,
-            PageRenderer::new(|| {
-                AnyComponentNode::new_root_routing(C::default(), dom::get_root_element())
-            }),
+            PageRenderer::new(|| AnyComponentNode::new_root(C::default(), dom::get_root_element())),
         );
         self.pages = pages;
 
@@ -43,6 +58,124 @@ impl RouterBuilder {
         self
     }
 
+    /// Adds page constructed asynchronously by the `loader` to application under provided path, e.g. after fetching its data or configuration.
+    /// Page is represented by **custom component** - struct implementing trait [Component].
+    /// The [loading page](RouterBuilder::add_loading_page) is rendered until the page is constructed.
+    /// If the user navigates elsewhere before that, construction of the page is cancelled by dropping the future returned by the `loader`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// RouterBuilder::default()
+    ///     .add_lazy_page("/admin", || async {
+    ///         let config = fetch_config().await;
+    ///         AdminPage::new(config)
+    ///     })
+    ///     .build()
+    ///     .start();
+    /// ```
+    pub fn add_lazy_page<C, F, Fut>(mut self, path: &'static str, loader: F) -> RouterBuilder
+    where
+        C: Component + 'static,
+        F: Fn() -> Fut + 'static,
+        Fut: Future<Output = C> + 'static,
+    {
+        self.pages.insert(
+            path,
+            PageRenderer::new_lazy(move || {
+                let component = loader();
+                Box::pin(async move {
+                    AnyComponentNode::new_root(component.await, dom::get_root_element())
+                })
+            }),
+        );
+        self
+    }
+
+    /// Adds provided page to application under provided path, which is created with the data loaded by the `loader` as its properties.
+    /// Page is represented by **custom component** - struct implementing trait [Component].
+    /// Loading of the data starts on the navigation, so the page is rendered already with the data instead of fetching it after being rendered.
+    /// The [loading page](RouterBuilder::add_loading_page) is rendered until the data is loaded.
+    /// If the user navigates elsewhere before that, loading is cancelled by dropping the future returned by the `loader`,
+    /// which also aborts the requests [sent](crate::fetch::send) by it.
+    ///
+    /// To pass the error of loading to the page, use [Result] as its properties.
+    ///
+    /// # Example
+    /// ```ignore
+    /// impl Component for UsersPage {
+    ///     type Properties = Result<Vec<User>, FetchError>;
+    ///     // ..
+    /// }
+    ///
+    /// RouterBuilder::default()
+    ///     .add_page_with_loader::<UsersPage, _, _>("/users", || async {
+    ///         fetch::send(Request::get("/api/users")).await?.error_for_status()?.json()
+    ///     })
+    ///     .build()
+    ///     .start();
+    /// ```
+    pub fn add_page_with_loader<C, F, Fut>(self, path: &'static str, loader: F) -> RouterBuilder
+    where
+        C: Component + 'static,
+        F: Fn() -> Fut + 'static,
+        Fut: Future<Output = C::Properties> + 'static,
+    {
+        self.add_lazy_page(path, move || {
+            let props = loader();
+            async move { C::new(props.await) }
+        })
+    }
+
+    /// Adds the pages of [typed routes](Routable) ***R*** to application. The page is represented by **custom component** ***C***,
+    /// which is created with the route parsed from the path as its properties, so it can render the content of every route.
+    /// Pages added under provided paths take precedence over the routes, which are tried in the order of adding.
+    /// If ***R*** has a [not found route](Routable::not_found), the page of this route is added as the [not found page](RouterBuilder::add_not_found_page).
+    ///
+    /// # Example
+    /// ```ignore
+    /// impl Component for RoutePage {
+    ///     type Properties = Route;
+    ///     // ..
+    /// }
+    ///
+    /// RouterBuilder::default()
+    ///     .add_routes::<Route, RoutePage>()
+    ///     .build()
+    ///     .start();
+    /// ```
+    pub fn add_routes<R, C>(mut self) -> RouterBuilder
+    where
+        R: Routable,
+        C: Component<Properties = R> + 'static,
+    {
+        self.routes.push(Box::new(|pathname| {
+            R::from_path(pathname)
+                .map(|route| AnyComponentNode::new_root(C::new(route), dom::get_root_element()))
+        }));
+        if R::not_found().is_some() {
+            self.not_found_page = Some(PageRenderer::new(|| {
+                let route = R::not_found().unwrap();
+                AnyComponentNode::new_root(C::new(route), dom::get_root_element())
+            }));
+        }
+        self
+    }
+
+    /// Adds provided loading page to application. It is rendered while the [lazy pages](RouterBuilder::add_lazy_page)
+    /// and the data of the [pages with loaders](RouterBuilder::add_page_with_loader) are being loaded.
+    /// If loading page was *not* specified, default one is provided.
+    pub fn add_loading_page<C>(self) -> RouterBuilder
+    where
+        C: Component + Default + 'static,
+    {
+        RouterBuilder {
+            loading_page: Some(PageRenderer::new(|| {
+                AnyComponentNode::new_root(C::default(), dom::get_root_element())
+            })),
+            ..self
+        }
+    }
+
     /// Adds provided not found page to application.
     /// All routes which cannot be resolved will be redirected to this page.
     /// Adding more than one **not found page** results in undefined behavior.
@@ -51,10 +184,33 @@ impl RouterBuilder {
         C: Component + Default + 'static,
     {
         RouterBuilder {
-            pages: self.pages,
             not_found_page: Some(PageRenderer::new(|| {
-                AnyComponentNode::new_root_routing(C::default(), dom::get_root_element())
+                AnyComponentNode::new_root(C::default(), dom::get_root_element())
             })),
+            ..self
+        }
+    }
+
+    /// Disables scrolling of the page under provided path on navigation.
+    /// By default the scroll position is restored when the user goes back or forward in the history,
+    /// the page is scrolled to the element identified by the fragment of the url (e.g. `/docs#installation`),
+    /// or to the top otherwise. Useful for pages managing the scroll themselves, e.g. with an infinite list.
+    pub fn disable_scroll_restoration(mut self, path: &'static str) -> RouterBuilder {
+        self.scroll_disabled_paths.insert(path);
+        self
+    }
+
+    /// Sets the callback of the component failures, which are not caught by any [error boundary](crate::component::ErrorBoundary).
+    /// Useful for reporting errors to the server or showing a notification.
+    /// Without the callback, errors returned by [fallible components](crate::component::FallibleComponent) are logged to the console
+    /// and panics abort the application.
+    pub fn on_error<F>(self, callback: F) -> RouterBuilder
+    where
+        F: Fn(&ComponentError) + 'static,
+    {
+        RouterBuilder {
+            error_callback: Some(Rc::new(callback)),
+            ..self
         }
     }
 }
@@ -62,16 +218,23 @@ impl RouterBuilder {
 impl RouterBuilder {
     /// Builds [router](Router). If **not found page** was *not* specified, default one is provided.
     pub fn build(self) -> Router {
-        if let Some(not_found_page) = self.not_found_page {
+        let mut router = if let Some(not_found_page) = self.not_found_page {
             Router::new(self.pages, not_found_page)
         } else {
             Router::new(
                 self.pages,
                 PageRenderer::new(|| {
-                    AnyComponentNode::new_root_routing(NotFoundComponent, dom::get_root_element())
+                    AnyComponentNode::new_root(NotFoundComponent, dom::get_root_element())
                 }),
             )
+        };
+        if let Some(loading_page) = self.loading_page {
+            router.loading_page = loading_page;
         }
+        router.routes = self.routes;
+        router.scroll_disabled_paths = self.scroll_disabled_paths;
+        router.error_callback = self.error_callback;
+        router
     }
 }
 
@@ -80,6 +243,7 @@ mod tests {
     use super::RouterBuilder;
     use crate::{
         component::{behavior::Behavior, Component},
+        router::{PageRenderer, Routable},
         virtual_dom::{VNode, VText},
     };
     use wasm_bindgen_test::wasm_bindgen_test;
@@ -194,6 +358,128 @@ mod tests {
         assert!(router.current.is_none());
     }
 
+    #[wasm_bindgen_test]
+    fn build_with_error_callback() {
+        let router = RouterBuilder::default()
+            .add_page::<Root>("/")
+            .on_error(|_| {})
+            .build();
+
+        assert_eq!(router.pages.len(), 1);
+        assert!(router.error_callback.is_some());
+    }
+
+    #[wasm_bindgen_test]
+    fn valid_lazy_page() {
+        let valid = RouterBuilder::default()
+            .add_page::<Root>("/")
+            .add_lazy_page("/lazy", || async { Root2 })
+            .add_loading_page::<Root>();
+        assert!(valid.pages.contains_key("/lazy"));
+        assert!(matches!(valid.pages["/lazy"], PageRenderer::Lazy(_)));
+        assert_eq!(valid.pages.len(), 2);
+        assert!(valid.loading_page.is_some());
+    }
+
+    #[derive(PartialEq)]
+    struct Route;
+    impl Routable for Route {
+        fn from_path(path: &str) -> Option<Self> {
+            (path == "/route").then_some(Route)
+        }
+        fn to_path(&self) -> String {
+            "/route".to_string()
+        }
+    }
+
+    struct RoutePage;
+    impl Component for RoutePage {
+        type Message = ();
+        type Properties = Route;
+        fn new(_props: Self::Properties) -> Self {
+            RoutePage
+        }
+        fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+            VText::new(VALID_TEXT).into()
+        }
+        fn update(&mut self, _message: Self::Message) -> bool {
+            false
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn build_with_routes() {
+        let router = RouterBuilder::default()
+            .add_page::<Root>("/")
+            .add_routes::<Route, RoutePage>()
+            .build();
+
+        assert_eq!(router.pages.len(), 1);
+        assert_eq!(router.routes.len(), 1);
+        assert!(router.routes[0]("/route").is_some());
+        assert!(router.routes[0]("/other").is_none());
+    }
+
+    #[derive(PartialEq)]
+    enum RouteWithNotFound {
+        Route,
+        NotFound,
+    }
+    impl Routable for RouteWithNotFound {
+        fn from_path(path: &str) -> Option<Self> {
+            (path == "/route").then_some(RouteWithNotFound::Route)
+        }
+        fn to_path(&self) -> String {
+            match self {
+                RouteWithNotFound::Route => "/route".to_string(),
+                RouteWithNotFound::NotFound => "/404".to_string(),
+            }
+        }
+        fn not_found() -> Option<Self> {
+            Some(RouteWithNotFound::NotFound)
+        }
+    }
+
+    struct RouteWithNotFoundPage;
+    impl Component for RouteWithNotFoundPage {
+        type Message = ();
+        type Properties = RouteWithNotFound;
+        fn new(_props: Self::Properties) -> Self {
+            RouteWithNotFoundPage
+        }
+        fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+            VText::new(VALID_TEXT).into()
+        }
+        fn update(&mut self, _message: Self::Message) -> bool {
+            false
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn build_with_routes_with_not_found_route() {
+        let valid = RouterBuilder::default()
+            .add_page::<Root>("/")
+            .add_routes::<RouteWithNotFound, RouteWithNotFoundPage>();
+        assert!(valid.not_found_page.is_some());
+
+        let router = valid.build();
+        assert_eq!(router.routes.len(), 1);
+        assert!(router.routes[0]("/route").is_some());
+        assert!(router.routes[0]("/other").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    fn build_with_disabled_scroll_restoration() {
+        let router = RouterBuilder::default()
+            .add_page::<Root>("/")
+            .add_page::<Root2>("/feed")
+            .disable_scroll_restoration("/feed")
+            .build();
+
+        assert!(router.scroll_disabled_paths.contains("/feed"));
+        assert!(!router.scroll_disabled_paths.contains("/"));
+    }
+
     #[wasm_bindgen_test]
     fn build() {
         let valid = RouterBuilder::default()
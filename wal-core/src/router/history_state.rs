@@ -0,0 +1,83 @@
+use gloo::utils::history;
+use wasm_bindgen::JsValue;
+
+/// Key of the position of the history entry in the history, which is used to find how far the user went back or forward.
+pub(crate) const INDEX_KEY: &str = "walrustIndex";
+
+/// Returns the number saved under the `key` in `history.state` of the current history entry.
+pub(crate) fn get(key: &str) -> Option<f64> {
+    let state = history().state().ok()?;
+    js_sys::Reflect::get(&state, &JsValue::from_str(key))
+        .ok()
+        .and_then(|x| x.as_f64())
+}
+
+/// Saves the numbers in `history.state` of the current history entry, keeping the other numbers saved in it.
+pub(crate) fn write(entries: &[(&str, f64)]) {
+    let state = js_sys::Object::new();
+    if let Ok(current) = history().state() {
+        if current.is_object() {
+            js_sys::Object::assign(&state, &current.into());
+        }
+    }
+    set_entries(&state, entries);
+    let _ = history().replace_state(&state, "");
+}
+
+/// Adds the history entry of the `url` with the numbers saved in its `history.state`.
+pub(crate) fn push(url: &str, entries: &[(&str, f64)]) {
+    let state = js_sys::Object::new();
+    set_entries(&state, entries);
+    history()
+        .push_state_with_url(&state, "", Some(url))
+        .unwrap();
+}
+
+/// Replaces the current history entry with the entry of the `url` with the numbers saved in its `history.state`.
+pub(crate) fn replace(url: &str, entries: &[(&str, f64)]) {
+    let state = js_sys::Object::new();
+    set_entries(&state, entries);
+    history()
+        .replace_state_with_url(&state, "", Some(url))
+        .unwrap();
+}
+
+fn set_entries(state: &js_sys::Object, entries: &[(&str, f64)]) {
+    for (key, value) in entries {
+        let _ = js_sys::Reflect::set(state, &JsValue::from_str(key), &JsValue::from_f64(*value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn write_should_keep_other_entries() {
+        // Arrange
+        push("/history-state", &[(INDEX_KEY, 3.0)]);
+
+        // Act
+        write(&[("walrustTest", 1.0)]);
+
+        // Assert
+        assert_eq!(get(INDEX_KEY), Some(3.0));
+        assert_eq!(get("walrustTest"), Some(1.0));
+    }
+
+    #[wasm_bindgen_test]
+    fn replace_should_drop_entries_of_replaced_entry() {
+        // Arrange
+        push("/history-state-replaced", &[("walrustTest", 1.0)]);
+
+        // Act
+        replace("/history-state-replacement", &[(INDEX_KEY, 3.0)]);
+
+        // Assert
+        assert_eq!(get(INDEX_KEY), Some(3.0));
+        assert_eq!(get("walrustTest"), None);
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{
+    component::{behavior::Behavior, Component},
+    virtual_dom::{VNode, VText},
+};
+
+pub(crate) struct LoadingComponent;
+impl Default for LoadingComponent {
+    fn default() -> Self {
+        Self::new(())
+    }
+}
+impl Component for LoadingComponent {
+    type Message = ();
+    type Properties = ();
+    fn new(_props: Self::Properties) -> Self {
+        LoadingComponent
+    }
+
+    fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+        VText::new("Loading...").into()
+    }
+
+    fn update(&mut self, _message: Self::Message) -> bool {
+        false
+    }
+}
@@ -1,31 +1,75 @@
+pub(crate) mod active_links;
+pub(crate) mod blocker;
 #[doc(hidden)]
 pub mod builder;
+pub use self::blocker::BlockedNavigation;
 pub use self::builder::RouterBuilder;
+pub(crate) mod history_state;
+pub(crate) mod loading_component;
 pub(crate) mod not_found_component;
-
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
-
-use crate::{component::node::AnyComponentNode, virtual_dom::dom};
+#[doc(hidden)]
+pub mod routable;
+pub use self::routable::Routable;
+pub(crate) mod scroll;
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+};
+
+use crate::{
+    component::{
+        error_boundary::{self, GlobalErrorCallback},
+        node::AnyComponentNode,
+    },
+    virtual_dom::dom,
+};
+use futures::future::{abortable, AbortHandle, Abortable};
 use gloo::utils::{body, history, window};
-use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
-use web_sys::{Element, Event, EventTarget};
-
-use self::not_found_component::NotFoundComponent;
-
-pub(crate) struct PageRenderer {
-    generator: Box<dyn Fn() -> Rc<RefCell<AnyComponentNode>>>,
+use wasm_bindgen::{prelude::Closure, JsCast};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{Element, Event, EventTarget, MouseEvent};
+
+use self::{
+    loading_component::LoadingComponent,
+    not_found_component::NotFoundComponent,
+    scroll::{Navigation, Scroll},
+};
+
+pub(crate) type PageFuture = Pin<Box<dyn Future<Output = Rc<RefCell<AnyComponentNode>>>>>;
+
+pub(crate) enum PageRenderer {
+    Eager(Box<dyn Fn() -> Rc<RefCell<AnyComponentNode>>>),
+    /// Page, which is constructed asynchronously. The loading page is rendered until it is ready.
+    Lazy(Box<dyn Fn() -> PageFuture>),
 }
 
 impl PageRenderer {
     pub fn new(generator: impl Fn() -> Rc<RefCell<AnyComponentNode>> + 'static) -> PageRenderer {
-        PageRenderer {
-            generator: Box::new(generator),
-        }
+        PageRenderer::Eager(Box::new(generator))
     }
 
-    pub fn render(&self) -> Rc<RefCell<AnyComponentNode>> {
-        (*self.generator)()
+    pub fn new_lazy(loader: impl Fn() -> PageFuture + 'static) -> PageRenderer {
+        PageRenderer::Lazy(Box::new(loader))
     }
+
+    pub fn render(&self) -> RenderedPage {
+        match self {
+            PageRenderer::Eager(generator) => RenderedPage::Ready(generator()),
+            PageRenderer::Lazy(loader) => RenderedPage::Loading(loader()),
+        }
+    }
+}
+
+/// Renderer of the pages of [typed routes](Routable), which creates the page if the path matches any of the routes.
+pub(crate) type RoutesRenderer = Box<dyn Fn(&str) -> Option<Rc<RefCell<AnyComponentNode>>>>;
+
+pub(crate) enum RenderedPage {
+    Ready(Rc<RefCell<AnyComponentNode>>),
+    Loading(PageFuture),
 }
 
 thread_local! {
@@ -41,18 +85,41 @@ struct CurrentPage {
 /// Router of the application. Handles routing in the application and correctly resolves paths.
 pub struct Router {
     pages: HashMap<&'static str, PageRenderer>,
+    routes: Vec<RoutesRenderer>,
     not_found_page: PageRenderer,
+    loading_page: PageRenderer,
     current: Option<CurrentPage>,
+    /// Handle of the page being loaded, which is aborted when the user navigates elsewhere.
+    loading: Option<AbortHandle>,
+    /// Scroll applied once the page being loaded is rendered, as the scrolled content does not exist before.
+    pending_scroll: Option<Scroll>,
+    scroll_disabled_paths: HashSet<&'static str>,
+    /// Position of the current history entry in the history, saved in its `history.state`.
+    history_index: i32,
+    /// Set when the blocked navigation back or forward is reverted, so the navigation caused by reverting is not routed.
+    is_reverting_traversal: bool,
+    /// Set when the blocked navigation back or forward is resumed, so it is not blocked again.
+    is_traversal_unblocked: bool,
+    error_callback: Option<GlobalErrorCallback>,
 }
 
 impl Router {
     pub(crate) fn empty() -> Router {
         Router {
             pages: [].into(),
+            routes: Vec::new(),
             not_found_page: PageRenderer::new(|| {
-                AnyComponentNode::new_root_routing(NotFoundComponent, dom::get_root_element())
+                AnyComponentNode::new_root(NotFoundComponent, dom::get_root_element())
             }),
+            loading_page: Self::default_loading_page(),
             current: None,
+            loading: None,
+            pending_scroll: None,
+            scroll_disabled_paths: HashSet::new(),
+            history_index: 0,
+            is_reverting_traversal: false,
+            is_traversal_unblocked: false,
+            error_callback: None,
         }
     }
 
@@ -62,20 +129,48 @@ impl Router {
     ) -> Router {
         Router {
             pages,
+            routes: Vec::new(),
             not_found_page,
+            loading_page: Self::default_loading_page(),
             current: None,
+            loading: None,
+            pending_scroll: None,
+            scroll_disabled_paths: HashSet::new(),
+            history_index: 0,
+            is_reverting_traversal: false,
+            is_traversal_unblocked: false,
+            error_callback: None,
         }
     }
 
+    pub(crate) fn default_loading_page() -> PageRenderer {
+        PageRenderer::new(|| AnyComponentNode::new_root(LoadingComponent, dom::get_root_element()))
+    }
+
     /// Start of the application. Moves router instance. Should be called only *once* in application.
     ///
     /// # Example
-    /// ```
+    /// ```no_run
+    /// use wal_core::component::{behavior::Behavior, Component};
+    /// use wal_core::router::builder::RouterBuilder;
+    /// use wal_core::virtual_dom::{VNode, VText};
+    ///
     /// #[derive(Default)]
     /// struct MainPage;
-    /// impl Component for MainPage {...}
+    /// impl Component for MainPage {
+    ///     type Message = ();
+    ///     type Properties = ();
     ///
-    /// //..
+    ///     fn new(_props: Self::Properties) -> Self {
+    ///         MainPage
+    ///     }
+    ///     fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+    ///         VText::new("main page").into()
+    ///     }
+    ///     fn update(&mut self, _message: Self::Message) -> bool {
+    ///         false
+    ///     }
+    /// }
     ///
     /// // Start of the application
     /// RouterBuilder::default()
@@ -85,72 +180,210 @@ impl Router {
     /// ```
     pub fn start(self) {
         std::panic::set_hook(Box::new(console_error_panic_hook::hook));
+        error_boundary::set_global_error_callback(self.error_callback.clone());
 
         let click = Closure::<dyn Fn(Event)>::new(Self::click);
         Self::add_event_listener(body().into(), "click", &click);
         click.forget();
 
-        let route = Closure::<dyn Fn()>::new(Self::route);
-        Self::add_event_listener(window().into(), "popstate", &route);
-        route.forget();
+        let pop = Closure::<dyn Fn()>::new(Self::pop);
+        Self::add_event_listener(window().into(), "popstate", &pop);
+        pop.forget();
+
+        let before_unload = Closure::<dyn Fn(Event)>::new(blocker::before_unload);
+        Self::add_event_listener(window().into(), "beforeunload", &before_unload);
+        before_unload.forget();
+
+        scroll::enable_manual_restoration();
+        let save_scroll = Closure::<dyn Fn()>::new(scroll::schedule_save);
+        Self::add_event_listener(window().into(), "scroll", &save_scroll);
+        save_scroll.forget();
+
+        let history_index = history_state::get(history_state::INDEX_KEY).map_or(0, |x| x as i32);
+        history_state::write(&[(history_state::INDEX_KEY, history_index.into())]);
 
         ROUTER.with(move |router| {
             let mut router = router.borrow_mut();
             *router = self;
+            router.history_index = history_index;
+        });
+
+        Self::route(Navigation::Pop);
+    }
+
+    /// Handles the navigation back or forward in the history. If it is blocked, the url is reverted to the current history entry
+    /// by the opposite navigation, as the browser has already changed it.
+    fn pop() {
+        let history_index = history_state::get(history_state::INDEX_KEY).map(|x| x as i32);
+        let (delta, is_reverted, is_unblocked) = ROUTER.with(|router| {
+            let mut router = router.borrow_mut();
+            // Entries without the index, e.g. added by changing the fragment of the url, are not blocked
+            let delta = history_index.map_or(0, |x| x - router.history_index);
+            if let Some(history_index) = history_index {
+                router.history_index = history_index;
+            }
+            (
+                delta,
+                std::mem::take(&mut router.is_reverting_traversal),
+                std::mem::take(&mut router.is_traversal_unblocked),
+            )
         });
 
-        Self::route();
+        if is_reverted {
+            return;
+        }
+        if !is_unblocked && delta != 0 && blocker::block(BlockedNavigation::traverse(delta)) {
+            ROUTER.with(|router| router.borrow_mut().is_reverting_traversal = true);
+            let _ = history().go_with_delta(-delta);
+            return;
+        }
+        Self::route(Navigation::Pop);
     }
 
-    fn route() {
+    fn route(navigation: Navigation) {
         ROUTER.with(|router| {
             let mut router = router.borrow_mut();
             let pathname = window().location().pathname().unwrap();
 
-            if let Some(old_current) = &router.current {
-                if pathname.eq(&old_current.path) {
-                    return;
+            let is_current_path = router
+                .current
+                .as_ref()
+                .is_some_and(|x| pathname.eq(&x.path));
+            if !is_current_path {
+                active_links::invalidate_link_owners();
+                router.render_page(pathname.clone());
+            }
+
+            if !router.scroll_disabled_paths.contains(pathname.as_str()) {
+                let scroll = Scroll::new(navigation);
+                if router.loading.is_some() {
+                    router.pending_scroll = Some(scroll);
+                } else {
+                    scroll.apply();
                 }
             }
+        });
+    }
+
+    fn render_page(&mut self, pathname: String) {
+        let old_current = self.current.take();
+        if let Some(loading) = self.loading.take() {
+            loading.abort();
+        }
+        self.pending_scroll = None;
+        let rendered_page = match self.pages.get(pathname.as_str()) {
+            Some(page_renderer) => page_renderer.render(),
+            None => self
+                .routes
+                .iter()
+                .find_map(|routes_renderer| routes_renderer(&pathname))
+                .map_or_else(|| self.not_found_page.render(), RenderedPage::Ready),
+        };
+        let new_page = match rendered_page {
+            RenderedPage::Ready(page) => page,
+            RenderedPage::Loading(page_future) => {
+                let (page_future, loading) = abortable(page_future);
+                spawn_local(Self::load(page_future));
+                self.loading = Some(loading);
+                match self.loading_page.render() {
+                    RenderedPage::Ready(page) => page,
+                    RenderedPage::Loading(_) => panic!("Loading page can not be lazy"),
+                }
+            }
+        };
+        let old_page = old_current.map(|x| x.page);
+
+        new_page.borrow_mut().view();
+        new_page
+            .borrow_mut()
+            .patch(old_page, &dom::get_root_element());
 
-            let old_current = router.current.take();
-            let page_renderer = router
-                .pages
-                .get(pathname.as_str())
-                .unwrap_or(&router.not_found_page);
-            let new_page = page_renderer.render();
-            let old_page = old_current.map(|x| x.page);
-
-            new_page.borrow_mut().view();
-            new_page
-                .borrow_mut()
-                .patch(old_page, &dom::get_root_element());
-
-            router.current = Some(CurrentPage {
-                path: pathname,
-                page: new_page,
-            });
+        self.current = Some(CurrentPage {
+            path: pathname,
+            page: new_page,
+        });
+    }
+
+    /// Replaces the loading page with the page once it is loaded. Loading is aborted if the user navigates elsewhere in the meantime.
+    async fn load(page_future: Abortable<PageFuture>) {
+        let Ok(new_page) = page_future.await else {
+            return;
+        };
+        ROUTER.with(|router| {
+            let mut router = router.borrow_mut();
+            router.loading = None;
+            if let Some(current) = router.current.as_mut() {
+                new_page.borrow_mut().view();
+                new_page
+                    .borrow_mut()
+                    .patch(Some(current.page.clone()), &dom::get_root_element());
+                current.page = new_page;
+            }
+            if let Some(scroll) = router.pending_scroll.take() {
+                scroll.apply();
+            }
         });
     }
 
     const WAL_ROUTING_ATTR: &'static str = "data_link";
+    const WAL_ROUTING_REPLACE_ATTR: &'static str = "data_link_replace";
 
     fn click(e: Event) {
+        if e.default_prevented() || Self::is_modified_click(&e) {
+            return;
+        }
         let target = e.target().unwrap().unchecked_into::<Element>();
         let matches = target
             .closest(&format!("[{}]", Self::WAL_ROUTING_ATTR))
             .unwrap();
-        if let Some(el) = matches {
+        // Links opened in another browsing context are left to the browser
+        let el = matches.filter(|el| match el.get_attribute("target") {
+            Some(target) => target == "_self",
+            None => true,
+        });
+        if let Some(el) = el {
             e.prevent_default();
-            Self::navigate_to(el.get_attribute("href").unwrap().as_str());
+            let url = el.get_attribute("href").unwrap();
+            let navigation = if el.has_attribute(Self::WAL_ROUTING_REPLACE_ATTR) {
+                BlockedNavigation::replace(url)
+            } else {
+                BlockedNavigation::push(url)
+            };
+            if !blocker::block(navigation.clone()) {
+                navigation.perform();
+            }
         }
     }
 
+    /// Checks if the click is meant to open the link in a new tab or window.
+    fn is_modified_click(e: &Event) -> bool {
+        e.dyn_ref::<MouseEvent>().is_some_and(|e| {
+            e.button() != 0 || e.ctrl_key() || e.meta_key() || e.shift_key() || e.alt_key()
+        })
+    }
+
     fn navigate_to(url: &str) {
-        history()
-            .push_state_with_url(&JsValue::null(), "", Some(url))
-            .unwrap();
-        Self::route();
+        scroll::save();
+        let history_index = ROUTER.with(|router| {
+            let mut router = router.borrow_mut();
+            router.history_index += 1;
+            router.history_index
+        });
+        history_state::push(url, &[(history_state::INDEX_KEY, history_index.into())]);
+        Self::route(Navigation::Push);
+    }
+
+    /// Navigates to the `url` replacing the current history entry, so going back skips the current page.
+    fn replace_with(url: &str) {
+        let history_index = ROUTER.with(|router| router.borrow().history_index);
+        history_state::replace(url, &[(history_state::INDEX_KEY, history_index.into())]);
+        Self::route(Navigation::Push);
+    }
+
+    /// Navigates back or forward in the history by the number of entries without consulting the blockers.
+    fn traverse(delta: i32) {
+        ROUTER.with(|router| router.borrow_mut().is_traversal_unblocked = true);
+        let _ = history().go_with_delta(delta);
     }
 
     fn add_event_listener<T: ?Sized>(target: EventTarget, type_: &str, listener: &Closure<T>) {
@@ -160,15 +393,43 @@ impl Router {
     }
 }
 
+/// Checks if the link to `href` matches the current path, so it can be highlighted.
+/// Not exact match accepts also the nested paths, e.g. link to `/users` is active on `/users/1`,
+/// except for the link to `/`, which is active only on `/`.
+/// Query and fragment of the `href` are ignored. Component rendering the link is rerendered after the navigation.
+#[doc(hidden)]
+pub fn is_link_active(href: &str, exact: bool) -> bool {
+    active_links::register_link_owner();
+    let pathname = window().location().pathname().unwrap_or_default();
+    let href = href.split(['?', '#']).next().unwrap_or_default();
+    is_path_matching(&pathname, href, exact)
+}
+
+fn is_path_matching(pathname: &str, href: &str, exact: bool) -> bool {
+    let pathname = pathname.trim_end_matches('/');
+    let href = href.trim_end_matches('/');
+    if exact || href.is_empty() {
+        return pathname == href;
+    }
+    pathname
+        .strip_prefix(href)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
 #[cfg(test)]
 mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
     use crate::{
-        component::{behavior::Behavior, Component},
-        virtual_dom::{VNode, VText},
+        component::{behavior::Behavior, node::AnyComponentNode, Component},
+        virtual_dom::{VComponent, VNode, VText},
     };
     use wasm_bindgen_test::wasm_bindgen_test;
 
-    use super::{builder::RouterBuilder, Router, ROUTER};
+    use super::{
+        blocker::NavigationBlocker, builder::RouterBuilder, is_path_matching, BlockedNavigation,
+        Routable, Router, ROUTER,
+    };
     wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 
     const VALID_TEXT: &str = "";
@@ -227,6 +488,7 @@ mod tests {
         });
     }
 
+    #[derive(Default)]
     struct Root2;
     impl Component for Root2 {
         type Message = ();
@@ -277,4 +539,312 @@ mod tests {
             }
         });
     }
+
+    async fn next_tick() {
+        wasm_bindgen_futures::JsFuture::from(js_sys::Promise::resolve(
+            &wasm_bindgen::JsValue::NULL,
+        ))
+        .await
+        .unwrap();
+    }
+
+    fn get_current_page() -> Rc<RefCell<AnyComponentNode>> {
+        ROUTER.with(|router| router.borrow().current.as_ref().unwrap().page.clone())
+    }
+
+    #[wasm_bindgen_test]
+    async fn lazy_page_should_replace_loading_page_once_loaded() {
+        let router = RouterBuilder::default()
+            .add_page::<Root>("/")
+            .add_lazy_page("/lazy", || async { Root2 })
+            .build();
+        router.start();
+
+        Router::navigate_to("/lazy");
+        let loading_page = get_current_page();
+        next_tick().await;
+
+        assert!(!Rc::ptr_eq(&loading_page, &get_current_page()));
+        Router::navigate_to("/");
+    }
+
+    #[wasm_bindgen_test]
+    async fn lazy_page_should_be_cancelled_when_user_navigates_elsewhere() {
+        let (sender, receiver) = futures::channel::oneshot::channel::<()>();
+        let receiver = Rc::new(RefCell::new(Some(receiver)));
+        let router = RouterBuilder::default()
+            .add_page::<Root>("/")
+            .add_lazy_page("/lazy", move || {
+                let receiver = receiver.borrow_mut().take();
+                async move {
+                    if let Some(receiver) = receiver {
+                        let _ = receiver.await;
+                    }
+                    Root2
+                }
+            })
+            .build();
+        router.start();
+
+        Router::navigate_to("/lazy");
+        Router::navigate_to("/");
+        next_tick().await;
+
+        assert!(sender.is_canceled());
+    }
+
+    thread_local! {
+        static LOADED_DATA: RefCell<Option<Result<u32, String>>> = const { RefCell::new(None) };
+    }
+
+    struct DataPage;
+    impl Component for DataPage {
+        type Message = ();
+        type Properties = Result<u32, String>;
+        fn new(props: Self::Properties) -> Self {
+            LOADED_DATA.with(|x| *x.borrow_mut() = Some(props));
+            DataPage
+        }
+        fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+            VText::new(VALID_TEXT).into()
+        }
+        fn update(&mut self, _message: Self::Message) -> bool {
+            false
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn page_with_loader_should_be_created_with_loaded_data() {
+        let router = RouterBuilder::default()
+            .add_page::<Root>("/")
+            .add_page_with_loader::<DataPage, _, _>("/data", || async { Err("Not found".into()) })
+            .build();
+        router.start();
+
+        Router::navigate_to("/data");
+        next_tick().await;
+
+        LOADED_DATA.with(|x| assert_eq!(*x.borrow(), Some(Err("Not found".to_string()))));
+        Router::navigate_to("/");
+    }
+
+    #[derive(PartialEq, Debug)]
+    enum Route {
+        User { id: u32 },
+    }
+    impl Routable for Route {
+        fn from_path(path: &str) -> Option<Self> {
+            match super::routable::split_path(path).as_slice() {
+                [users, id] if users == "users" => id.parse().ok().map(|id| Route::User { id }),
+                _ => None,
+            }
+        }
+        fn to_path(&self) -> String {
+            match self {
+                Route::User { id } => format!("/users/{id}"),
+            }
+        }
+    }
+
+    thread_local! {
+        static RENDERED_ROUTE: RefCell<Option<Route>> = const { RefCell::new(None) };
+    }
+
+    struct RoutePage;
+    impl Component for RoutePage {
+        type Message = ();
+        type Properties = Route;
+        fn new(props: Self::Properties) -> Self {
+            RENDERED_ROUTE.with(|x| *x.borrow_mut() = Some(props));
+            RoutePage
+        }
+        fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+            VText::new(VALID_TEXT).into()
+        }
+        fn update(&mut self, _message: Self::Message) -> bool {
+            false
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn navigate_to_route_should_create_page_with_parsed_route() {
+        let router = RouterBuilder::default()
+            .add_page::<Root>("/")
+            .add_routes::<Route, RoutePage>()
+            .build();
+        router.start();
+
+        Router::navigate_to(&Route::User { id: 7 }.to_path());
+
+        RENDERED_ROUTE.with(|x| assert_eq!(*x.borrow(), Some(Route::User { id: 7 })));
+        ROUTER.with(|router| {
+            assert_eq!(router.borrow().current.as_ref().unwrap().path, "/users/7");
+        });
+        Router::navigate_to("/");
+    }
+
+    struct Nav;
+    impl Component for Nav {
+        type Message = ();
+        type Properties = ();
+        fn new(_props: Self::Properties) -> Self {
+            Nav
+        }
+        fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+            let class = if super::is_link_active("/nav/2", true) {
+                "active"
+            } else {
+                "inactive"
+            };
+            VText::new(class).into()
+        }
+        fn update(&mut self, _message: Self::Message) -> bool {
+            false
+        }
+    }
+
+    #[derive(Default)]
+    struct NavPage;
+    impl Component for NavPage {
+        type Message = ();
+        type Properties = ();
+        fn new(_props: Self::Properties) -> Self {
+            NavPage
+        }
+        fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+            VNode::Component(VComponent::new::<Nav>((), None))
+        }
+        fn update(&mut self, _message: Self::Message) -> bool {
+            false
+        }
+    }
+
+    #[derive(Default)]
+    struct NavPage2;
+    impl Component for NavPage2 {
+        type Message = ();
+        type Properties = ();
+        fn new(_props: Self::Properties) -> Self {
+            NavPage2
+        }
+        fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+            VNode::Component(VComponent::new::<Nav>((), None))
+        }
+        fn update(&mut self, _message: Self::Message) -> bool {
+            false
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn link_in_component_shared_by_pages_should_be_active_after_navigation() {
+        let router = RouterBuilder::default()
+            .add_page::<Root>("/")
+            .add_page::<NavPage>("/nav/1")
+            .add_page::<NavPage2>("/nav/2")
+            .build();
+        router.start();
+        Router::navigate_to("/nav/1");
+        let root = crate::virtual_dom::dom::get_root_element();
+        assert_eq!(root.text_content().unwrap(), "inactive");
+
+        Router::navigate_to("/nav/2");
+        next_tick().await;
+
+        assert_eq!(root.text_content().unwrap(), "active");
+        Router::navigate_to("/");
+    }
+
+    fn click_link(href: &str) {
+        let link = crate::virtual_dom::dom::create_element("a");
+        link.set_attribute("href", href).unwrap();
+        link.set_attribute(Router::WAL_ROUTING_ATTR, href).unwrap();
+        gloo::utils::body().append_child(&link).unwrap();
+        let init = web_sys::EventInit::new();
+        init.set_bubbles(true);
+        init.set_cancelable(true);
+        let event = web_sys::Event::new_with_event_init_dict("click", &init).unwrap();
+        link.dispatch_event(&event).unwrap();
+        link.remove();
+    }
+
+    fn get_current_path() -> String {
+        ROUTER.with(|router| router.borrow().current.as_ref().unwrap().path.clone())
+    }
+
+    #[wasm_bindgen_test]
+    async fn click_on_link_should_be_blocked_until_navigation_proceeds() {
+        let router = RouterBuilder::default()
+            .add_page::<Root>("/")
+            .add_page::<Root2>("/blocked")
+            .build();
+        router.start();
+        Router::navigate_to("/");
+        let blocked = Rc::new(RefCell::new(None));
+        let blocker_blocked = blocked.clone();
+        let blocker = NavigationBlocker::new(Box::new(move |navigation| {
+            *blocker_blocked.borrow_mut() = Some(navigation);
+        }));
+
+        click_link("/blocked");
+
+        assert_eq!(get_current_path(), "/");
+        let navigation = blocked.borrow_mut().take().unwrap();
+        assert_eq!(navigation, BlockedNavigation::push("/blocked"));
+
+        navigation.proceed();
+        next_tick().await;
+
+        assert_eq!(get_current_path(), "/blocked");
+        drop(blocker);
+        Router::navigate_to("/");
+    }
+
+    #[derive(Default)]
+    struct BlockingPage;
+    impl Component for BlockingPage {
+        type Message = BlockedNavigation;
+        type Properties = ();
+        fn new(_props: Self::Properties) -> Self {
+            BlockingPage
+        }
+        fn view(&self, behavior: &mut impl Behavior<Self>) -> VNode {
+            behavior.block_navigation(|navigation| navigation);
+            VText::new(VALID_TEXT).into()
+        }
+        fn update(&mut self, message: Self::Message) -> bool {
+            message.proceed();
+            false
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn navigation_proceeded_from_update_of_blocking_page_should_be_performed() {
+        let router = RouterBuilder::default()
+            .add_page::<Root>("/")
+            .add_page::<BlockingPage>("/blocking")
+            .build();
+        router.start();
+        Router::navigate_to("/blocking");
+
+        click_link("/");
+        next_tick().await;
+        next_tick().await;
+
+        assert_eq!(get_current_path(), "/");
+    }
+
+    #[wasm_bindgen_test]
+    fn path_matching_should_accept_nested_paths_unless_exact() {
+        assert!(is_path_matching("/users", "/users", false));
+        assert!(is_path_matching("/users/1", "/users", false));
+        assert!(is_path_matching("/users/", "/users", true));
+        assert!(!is_path_matching("/users/1", "/users", true));
+        assert!(!is_path_matching("/usersettings", "/users", false));
+    }
+
+    #[wasm_bindgen_test]
+    fn path_matching_of_root_should_accept_only_root() {
+        assert!(is_path_matching("/", "/", false));
+        assert!(!is_path_matching("/users", "/", false));
+    }
 }
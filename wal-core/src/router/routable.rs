@@ -0,0 +1,104 @@
+use std::fmt::Display;
+
+/// Typed routes of the application, which are parsed from and formatted to the paths of the url.
+/// It should be derived with `#[derive(Routable)]` from the `wal-rsx` crate on an enum with a variant for every route,
+/// so the paths are declared once and links to the renamed routes do not break silently.
+///
+/// Routes are registered with [RouterBuilder::add_routes](super::RouterBuilder::add_routes)
+/// and can be passed to the `to` attribute of the `Link` in the `rsx!` macro, as the derive also implements [Display].
+///
+/// # Example
+/// ```ignore
+/// #[derive(Routable, Clone, PartialEq)]
+/// enum Route {
+///     #[at("/")]
+///     Home,
+///     #[at("/users/:id")]
+///     User { id: u32 },
+///     #[not_found]
+///     #[at("/404")]
+///     NotFound,
+/// }
+/// ```
+pub trait Routable: Sized + 'static {
+    /// Parses the route from the path of the url. Returns `None` if the path does not match any route.
+    fn from_path(path: &str) -> Option<Self>;
+
+    /// Formats the route as the path of the url.
+    fn to_path(&self) -> String;
+
+    /// Returns the route rendered for the paths, which do not match any route. Returns `None` if there is no such route.
+    fn not_found() -> Option<Self> {
+        None
+    }
+}
+
+/// Splits the path of the url into decoded segments. Query, fragment and empty segments are ignored,
+/// so `/users/1/` and `/users/1?tab=posts` are both split into `["users", "1"]`.
+#[doc(hidden)]
+pub fn split_path(path: &str) -> Vec<String> {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(decode_path_segment)
+        .collect()
+}
+
+/// Formats the parameter of the route as the segment of the path, percent-encoding the characters
+/// other than ASCII letters, digits and `-`, `.`, `_`, `~`.
+#[doc(hidden)]
+pub fn encode_path_segment(segment: &impl Display) -> String {
+    segment
+        .to_string()
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// Decodes the percent-encoded segment of the path. Malformed segment is kept as it is.
+fn decode_path_segment(segment: &str) -> String {
+    if !segment.contains('%') {
+        return segment.to_string();
+    }
+    js_sys::decode_uri_component(segment)
+        .ok()
+        .and_then(|x| x.as_string())
+        .unwrap_or_else(|| segment.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::{encode_path_segment, split_path};
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn split_path_should_ignore_empty_segments_query_and_fragment() {
+        assert_eq!(split_path("/"), Vec::<String>::new());
+        assert_eq!(split_path("/users/1/"), vec!["users", "1"]);
+        assert_eq!(split_path("/users/1?tab=posts#top"), vec!["users", "1"]);
+    }
+
+    #[wasm_bindgen_test]
+    fn split_path_should_decode_segments() {
+        assert_eq!(split_path("/tags/rust%20wasm"), vec!["tags", "rust wasm"]);
+        assert_eq!(split_path("/tags/%C5%BC"), vec!["tags", "ż"]);
+        assert_eq!(split_path("/tags/%+F"), vec!["tags", "%+F"]);
+    }
+
+    #[wasm_bindgen_test]
+    fn encoded_path_segment_should_be_decoded_to_the_same_value() {
+        let segment = "a/b c?ż";
+
+        let encoded = encode_path_segment(&segment);
+
+        assert_eq!(encoded, "a%2Fb%20c%3F%C5%BC");
+        assert_eq!(split_path(&format!("/{encoded}")), vec![segment]);
+    }
+}
@@ -0,0 +1,156 @@
+use std::cell::RefCell;
+
+use gloo::{
+    timers::callback::Timeout,
+    utils::{document, history, window},
+};
+use web_sys::ScrollRestoration;
+
+use super::history_state;
+
+/// Keys of the scroll position saved in `history.state` of the history entries.
+const SCROLL_X_KEY: &str = "walrustScrollX";
+const SCROLL_Y_KEY: &str = "walrustScrollY";
+
+/// Delay of saving the scroll position after the page was scrolled, as browsers limit the number of history updates.
+const SAVE_DELAY_MILLIS: u32 = 100;
+
+thread_local! {
+    static SAVE_TIMEOUT: RefCell<Option<Timeout>> = const { RefCell::new(None) };
+}
+
+/// Kind of the navigation, which determines how the page is scrolled after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Navigation {
+    /// Navigation to the new history entry, e.g. by clicking a link.
+    Push,
+    /// Navigation to the existing history entry, e.g. by the back button of the browser, as well as the initial load of the page.
+    Pop,
+}
+
+/// Scroll applied to the page after the navigation.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Scroll {
+    Position { x: f64, y: f64 },
+    Fragment(String),
+    Top,
+}
+
+impl Scroll {
+    /// Returns the scroll position saved in the current history entry if it is revisited,
+    /// otherwise the element identified by the fragment of the url or the top of the page.
+    pub(crate) fn new(navigation: Navigation) -> Scroll {
+        let saved_position = match navigation {
+            Navigation::Push => None,
+            Navigation::Pop => get_saved_position(),
+        };
+        saved_position
+            .or_else(|| get_fragment().map(Scroll::Fragment))
+            .unwrap_or(Scroll::Top)
+    }
+
+    pub(crate) fn apply(&self) {
+        match self {
+            Scroll::Position { x, y } => window().scroll_to_with_x_and_y(*x, *y),
+            Scroll::Fragment(fragment) => match document().get_element_by_id(fragment) {
+                Some(element) => element.scroll_into_view(),
+                None => window().scroll_to_with_x_and_y(0.0, 0.0),
+            },
+            Scroll::Top => window().scroll_to_with_x_and_y(0.0, 0.0),
+        }
+    }
+}
+
+/// Disables the scroll restoration of the browser, which would restore the position before the page is rendered.
+pub(crate) fn enable_manual_restoration() {
+    let _ = history().set_scroll_restoration(ScrollRestoration::Manual);
+}
+
+/// Saves the scroll position in the current history entry after a delay. Called on every scroll of the page.
+pub(crate) fn schedule_save() {
+    let timeout = Timeout::new(SAVE_DELAY_MILLIS, write_position);
+    SAVE_TIMEOUT.with(|x| *x.borrow_mut() = Some(timeout));
+}
+
+/// Saves the scroll position in the current history entry immediately, e.g. before it is left by the navigation.
+pub(crate) fn save() {
+    SAVE_TIMEOUT.with(|x| x.borrow_mut().take());
+    write_position();
+}
+
+fn write_position() {
+    let window = window();
+    history_state::write(&[
+        (SCROLL_X_KEY, window.scroll_x().unwrap_or_default()),
+        (SCROLL_Y_KEY, window.scroll_y().unwrap_or_default()),
+    ]);
+}
+
+fn get_saved_position() -> Option<Scroll> {
+    Some(Scroll::Position {
+        x: history_state::get(SCROLL_X_KEY)?,
+        y: history_state::get(SCROLL_Y_KEY)?,
+    })
+}
+
+fn get_fragment() -> Option<String> {
+    let hash = window().location().hash().ok()?;
+    let fragment = hash.strip_prefix('#').filter(|x| !x.is_empty())?;
+    js_sys::decode_uri_component(fragment)
+        .ok()
+        .and_then(|x| x.as_string())
+        .or_else(|| Some(fragment.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn push_url(url: &str) {
+        history()
+            .push_state_with_url(&JsValue::null(), "", Some(url))
+            .unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn pop_navigation_should_restore_saved_position() {
+        // Arrange
+        push_url("/scroll-saved");
+        save();
+
+        // Act
+        let scroll = Scroll::new(Navigation::Pop);
+
+        // Assert
+        assert!(matches!(scroll, Scroll::Position { .. }));
+    }
+
+    #[wasm_bindgen_test]
+    fn push_navigation_should_ignore_saved_position() {
+        // Arrange
+        push_url("/scroll-push");
+        save();
+
+        // Act
+        let scroll = Scroll::new(Navigation::Push);
+
+        // Assert
+        assert_eq!(scroll, Scroll::Top);
+    }
+
+    #[wasm_bindgen_test]
+    fn navigation_to_url_with_fragment_should_scroll_to_fragment() {
+        // Arrange
+        push_url("/scroll-fragment#first%20section");
+
+        // Act
+        let scroll = Scroll::new(Navigation::Pop);
+
+        // Assert
+        assert_eq!(scroll, Scroll::Fragment("first section".to_string()));
+    }
+}
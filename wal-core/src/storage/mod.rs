@@ -0,0 +1,351 @@
+use std::{
+    cell::RefCell,
+    error::Error,
+    fmt,
+    marker::PhantomData,
+    rc::{Rc, Weak},
+};
+
+use gloo::{events::EventListener, utils::window};
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::JsCast;
+use web_sys::{Storage, StorageEvent};
+
+/// Web storage area, in which the values are persisted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StorageArea {
+    /// `localStorage`, which is shared by all of the tabs of the origin and kept after the browser is closed.
+    Local,
+    /// `sessionStorage`, which is kept only for the lifetime of the tab.
+    Session,
+}
+
+impl StorageArea {
+    fn get_storage(&self) -> Result<Storage, StorageError> {
+        match self {
+            StorageArea::Local => window().local_storage(),
+            StorageArea::Session => window().session_storage(),
+        }
+        .map_err(|error| StorageError::Unavailable(format!("{error:?}")))?
+        .ok_or_else(|| StorageError::Unavailable(format!("{self:?} storage is not supported")))
+    }
+
+    fn from_storage(storage: &Storage) -> Option<Self> {
+        [StorageArea::Local, StorageArea::Session]
+            .into_iter()
+            .find(|area| area.get_storage().is_ok_and(|x| x == *storage))
+    }
+}
+
+/// Error of reading or writing the [Persisted] value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageError {
+    /// Storage area can not be accessed, e.g. because it is disabled by the privacy settings of the browser.
+    Unavailable(String),
+    /// Value could not be written, e.g. because the quota of the storage area was exceeded.
+    Write(String),
+    /// Value could not be serialized.
+    Serialization(String),
+    /// Stored value could not be deserialized, e.g. because it was written by an older version of the application.
+    Deserialization(String),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageError::Unavailable(message) => write!(f, "Storage is unavailable: {message}"),
+            StorageError::Write(message) => write!(f, "Write error: {message}"),
+            StorageError::Serialization(message) => write!(f, "Serialization error: {message}"),
+            StorageError::Deserialization(message) => {
+                write!(f, "Deserialization error: {message}")
+            }
+        }
+    }
+}
+
+impl Error for StorageError {}
+
+/// Value of type `T` persisted as JSON under the `key` of the [StorageArea].
+///
+/// Writes made by [set](Persisted::set) and [remove](Persisted::remove) rerender the components subscribed
+/// to the value using [Behavior](crate::component::Behavior), as well as changes made in other tabs of the application.
+///
+/// # Examples
+///
+/// ```ignore
+/// const THEME: Persisted<Theme> = Persisted::local("theme");
+///
+/// fn view(&self, behavior: &mut impl Behavior<Self>) -> VNode {
+///     let theme = behavior.subscribe_to_storage(&THEME).unwrap_or_default();
+///     let toggle = Callback::new(move |_: MouseEvent| { let _ = THEME.set(&theme.toggled()); });
+///     rsx! { <button class={theme.class()} onclick={toggle}>{ "Toggle theme" }</button> }
+/// }
+/// ```
+pub struct Persisted<T> {
+    area: StorageArea,
+    key: &'static str,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T> Persisted<T> {
+    /// Creates [Persisted] value stored under the `key` of the [StorageArea].
+    pub const fn new(area: StorageArea, key: &'static str) -> Self {
+        Self {
+            area,
+            key,
+            _value: PhantomData,
+        }
+    }
+
+    /// Creates [Persisted] value stored under the `key` of [StorageArea::Local].
+    pub const fn local(key: &'static str) -> Self {
+        Self::new(StorageArea::Local, key)
+    }
+
+    /// Creates [Persisted] value stored under the `key` of [StorageArea::Session].
+    pub const fn session(key: &'static str) -> Self {
+        Self::new(StorageArea::Session, key)
+    }
+
+    pub fn get_area(&self) -> StorageArea {
+        self.area
+    }
+
+    pub fn get_key(&self) -> &'static str {
+        self.key
+    }
+
+    /// Removes the value from the storage and notifies the subscribed components.
+    pub fn remove(&self) -> Result<(), StorageError> {
+        self.area
+            .get_storage()?
+            .remove_item(self.key)
+            .map_err(|error| StorageError::Write(format!("{error:?}")))?;
+        notify_observers(self.area, Some(self.key));
+        Ok(())
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> Persisted<T> {
+    /// Reads the value from the storage. Returns [None] if there is no value stored under the key.
+    pub fn get(&self) -> Result<Option<T>, StorageError> {
+        let storage = self.area.get_storage()?;
+        let Some(json) = storage
+            .get_item(self.key)
+            .map_err(|error| StorageError::Unavailable(format!("{error:?}")))?
+        else {
+            return Ok(None);
+        };
+        serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|error| StorageError::Deserialization(error.to_string()))
+    }
+
+    /// Serializes the value, writes it to the storage and notifies the subscribed components.
+    pub fn set(&self, value: &T) -> Result<(), StorageError> {
+        let json = serde_json::to_string(value)
+            .map_err(|error| StorageError::Serialization(error.to_string()))?;
+        self.area
+            .get_storage()?
+            .set_item(self.key, &json)
+            .map_err(|error| StorageError::Write(format!("{error:?}")))?;
+        notify_observers(self.area, Some(self.key));
+        Ok(())
+    }
+}
+
+impl<T> Clone for Persisted<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Persisted<T> {}
+
+impl<T> fmt::Debug for Persisted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Persisted")
+            .field("area", &self.area)
+            .field("key", &self.key)
+            .finish()
+    }
+}
+
+impl<T> PartialEq for Persisted<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.area == other.area && self.key == other.key
+    }
+}
+
+impl<T> Eq for Persisted<T> {}
+
+/// Observer of the value stored under the `key` of the [StorageArea]. It is notified as long as it is kept alive.
+pub(crate) struct StorageObserver {
+    area: StorageArea,
+    key: &'static str,
+    notify: Box<dyn Fn()>,
+}
+
+impl StorageObserver {
+    pub(crate) fn new(area: StorageArea, key: &'static str, notify: Box<dyn Fn()>) -> Rc<Self> {
+        let observer = Rc::new(Self { area, key, notify });
+        OBSERVERS.with(|x| x.borrow_mut().push(Rc::downgrade(&observer)));
+        STORAGE_LISTENER.with(|x| {
+            x.borrow_mut()
+                .get_or_insert_with(|| EventListener::new(&window(), "storage", on_storage_event));
+        });
+        observer
+    }
+
+    pub(crate) fn is_observing(&self, area: StorageArea, key: &str) -> bool {
+        self.area == area && self.key == key
+    }
+}
+
+thread_local! {
+    static OBSERVERS: RefCell<Vec<Weak<StorageObserver>>> = const { RefCell::new(Vec::new()) };
+    static STORAGE_LISTENER: RefCell<Option<EventListener>> = const { RefCell::new(None) };
+}
+
+/// Handles `storage` event, which is dispatched when the storage area is modified by the other tab of the application.
+fn on_storage_event(event: &web_sys::Event) {
+    let event = event.unchecked_ref::<StorageEvent>();
+    if let Some(area) = event
+        .storage_area()
+        .and_then(|storage| StorageArea::from_storage(&storage))
+    {
+        notify_observers(area, event.key().as_deref());
+    }
+}
+
+/// Notifies the observers of the `key` in the [StorageArea]. Key [None] means that the whole storage area was cleared.
+fn notify_observers(area: StorageArea, key: Option<&str>) {
+    // Observers are collected first, so they can be created or dropped while being notified
+    let observers: Vec<Rc<StorageObserver>> = OBSERVERS.with(|x| {
+        let mut observers = x.borrow_mut();
+        observers.retain(|observer| observer.strong_count() > 0);
+        observers
+            .iter()
+            .filter_map(Weak::upgrade)
+            .filter(|observer| {
+                observer.area == area
+                    && match key {
+                        Some(key) => observer.key == key,
+                        None => true,
+                    }
+            })
+            .collect()
+    });
+    for observer in observers {
+        (observer.notify)();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+    use web_sys::StorageEventInit;
+
+    use super::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn create_counting_observer(
+        area: StorageArea,
+        key: &'static str,
+        counter: &Rc<RefCell<u32>>,
+    ) -> Rc<StorageObserver> {
+        let counter = counter.clone();
+        StorageObserver::new(area, key, Box::new(move || *counter.borrow_mut() += 1))
+    }
+
+    #[wasm_bindgen_test]
+    fn set_value_should_be_returned_by_get() {
+        // Arrange
+        let persisted = Persisted::<Vec<u32>>::local("test-set-get");
+
+        // Act
+        persisted.set(&vec![1, 2]).unwrap();
+
+        // Assert
+        assert_eq!(persisted.get(), Ok(Some(vec![1, 2])));
+        persisted.remove().unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn removed_value_should_not_be_returned_by_get() {
+        // Arrange
+        let persisted = Persisted::<Vec<u32>>::session("test-remove");
+        persisted.set(&vec![1, 2]).unwrap();
+
+        // Act
+        persisted.remove().unwrap();
+
+        // Assert
+        assert_eq!(persisted.get(), Ok(None));
+    }
+
+    #[wasm_bindgen_test]
+    fn get_of_invalid_value_should_return_deserialization_error() {
+        // Arrange
+        let storage = StorageArea::Local.get_storage().unwrap();
+        storage.set_item("test-invalid", "not json").unwrap();
+
+        // Act
+        let result = Persisted::<Vec<u32>>::local("test-invalid").get();
+
+        // Assert
+        assert!(matches!(result, Err(StorageError::Deserialization(_))));
+        storage.remove_item("test-invalid").unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn set_should_notify_only_observers_of_the_same_area_and_key() {
+        // Arrange
+        let counter = Rc::new(RefCell::new(0));
+        let other_counter = Rc::new(RefCell::new(0));
+        let _observer = create_counting_observer(StorageArea::Local, "test-notify", &counter);
+        let _other_key_observer =
+            create_counting_observer(StorageArea::Local, "test-notify-other", &other_counter);
+        let _other_area_observer =
+            create_counting_observer(StorageArea::Session, "test-notify", &other_counter);
+
+        // Act
+        Persisted::<u32>::local("test-notify").set(&1).unwrap();
+
+        // Assert
+        assert_eq!(*counter.borrow(), 1);
+        assert_eq!(*other_counter.borrow(), 0);
+        Persisted::<u32>::local("test-notify").remove().unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn dropped_observer_should_not_be_notified() {
+        // Arrange
+        let counter = Rc::new(RefCell::new(0));
+        let observer = create_counting_observer(StorageArea::Local, "test-dropped", &counter);
+
+        // Act
+        drop(observer);
+        Persisted::<u32>::local("test-dropped").remove().unwrap();
+
+        // Assert
+        assert_eq!(*counter.borrow(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn storage_event_from_other_tab_should_notify_observers() {
+        // Arrange
+        let counter = Rc::new(RefCell::new(0));
+        let _observer = create_counting_observer(StorageArea::Local, "test-other-tab", &counter);
+        let init = StorageEventInit::new();
+        init.set_key(Some("test-other-tab"));
+        init.set_storage_area(Some(&StorageArea::Local.get_storage().unwrap()));
+        let event = StorageEvent::new_with_event_init_dict("storage", &init).unwrap();
+
+        // Act
+        window().dispatch_event(&event).unwrap();
+
+        // Assert
+        assert_eq!(*counter.borrow(), 1);
+    }
+}
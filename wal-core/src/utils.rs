@@ -39,97 +39,82 @@ pub mod debug {
 }
 
 pub mod any_utils {
-    use std::{any::Any, mem};
-
-    pub fn raw_memory_compare(a: &Box<dyn Any>, b: &Box<dyn Any>) -> bool {
-        let size_a = mem::size_of_val(&**a);
-        let size_b = mem::size_of_val(&**b);
-        if size_a != size_b {
-            return false;
-        }
-
-        unsafe {
-            let a_ptr = &**a as *const dyn Any as *const u8;
-            let b_ptr = &**b as *const dyn Any as *const u8;
+    use std::any::Any;
 
-            for i in 0..size_a {
-                if *a_ptr.add(i) != *b_ptr.add(i) {
-                    return false;
-                }
-            }
+    /// Compares two type-erased values using [PartialEq] implementation of `T`.
+    /// Values of other types than `T` are never equal.
+    pub fn any_eq<T: PartialEq + 'static>(a: &dyn Any, b: &dyn Any) -> bool {
+        match (a.downcast_ref::<T>(), b.downcast_ref::<T>()) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
         }
-
-        true
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::any::Any;
-
-    use super::any_utils::raw_memory_compare;
+    use super::any_utils::any_eq;
 
     #[test]
-    fn raw_memory_compare_should_return_true_when_values_are_the_same() {
+    fn any_eq_should_return_true_when_values_are_the_same() {
         // Arrange
-        let val = 1;
-        let a: Box<dyn Any> = Box::new(val);
-        let b: Box<dyn Any> = Box::new(val);
+        let a = 1;
+        let b = 1;
 
         // Act
-        let result = raw_memory_compare(&a, &b);
+        let result = any_eq::<i32>(&a, &b);
 
         // Assert
         assert!(result);
     }
 
     #[test]
-    fn raw_memory_compare_should_return_false_when_values_are_not_the_same() {
+    fn any_eq_should_return_false_when_values_are_not_the_same() {
         // Arrange
-        let a: Box<dyn Any> = Box::new(1);
-        let b: Box<dyn Any> = Box::new(2);
+        let a = 1;
+        let b = 2;
 
         // Act
-        let result = raw_memory_compare(&a, &b);
+        let result = any_eq::<i32>(&a, &b);
 
         // Assert
         assert!(!result);
     }
 
     #[test]
-    fn raw_memory_compare_should_return_false_when_values_are_not_the_same_type() {
+    fn any_eq_should_return_false_when_values_are_not_the_same_type() {
         // Arrange
-        let a: Box<dyn Any> = Box::new(1);
-        let b: Box<dyn Any> = Box::new(1.0);
+        let a = 1;
+        let b = 1.0;
 
         // Act
-        let result = raw_memory_compare(&a, &b);
+        let result = any_eq::<i32>(&a, &b);
 
         // Assert
         assert!(!result);
     }
 
     #[test]
-    fn raw_memory_compare_should_return_false_when_values_are_not_the_same_size() {
+    fn any_eq_should_compare_heap_allocated_values_by_content() {
         // Arrange
-        let a: Box<dyn Any> = Box::new(vec![1, 2, 3]);
-        let b: Box<dyn Any> = Box::new(vec![1, 2, 3, 4]);
+        let a = String::from("message");
+        let b = String::from("message");
 
         // Act
-        let result = raw_memory_compare(&a, &b);
+        let result = any_eq::<String>(&a, &b);
 
         // Assert
-        assert!(!result);
+        assert!(result);
     }
 
     #[test]
-    fn raw_memory_compare_should_return_false_when_values_are_collections_not_the_same_type() {
+    fn any_eq_should_return_false_when_collections_have_different_content() {
         // Arrange
-        let a: Box<dyn Any> = Box::new(vec![1]);
-        let b: Box<dyn Any> = Box::new([1]);
+        let a = vec![1, 2, 3];
+        let b = vec![1, 2, 3, 4];
 
         // Act
-        let result = raw_memory_compare(&a, &b);
+        let result = any_eq::<Vec<i32>>(&a, &b);
 
         // Assert
         assert!(!result);
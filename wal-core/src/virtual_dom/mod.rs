@@ -1,24 +1,60 @@
+use web_sys::Node;
+
 #[doc(hidden)]
 pub mod vcomponent;
 #[doc(hidden)]
 pub mod velement;
 #[doc(hidden)]
+pub mod vhead;
+#[doc(hidden)]
 pub mod vlist;
 #[doc(hidden)]
+pub mod vmemo;
+#[doc(hidden)]
 pub mod vnode;
 #[doc(hidden)]
+pub mod vportal;
+#[doc(hidden)]
 pub mod vtext;
 
 pub use self::vcomponent::VComponent;
 pub use self::velement::VElement;
+pub use self::vhead::VHead;
 pub use self::vlist::VList;
+pub use self::vmemo::VMemo;
 pub use self::vnode::VNode;
+pub use self::vportal::{PortalTarget, VPortal};
 pub use self::vtext::VText;
 
+// Subtree rendered under a different ancestor (e.g. when parent element was swapped) has to be patched anyway
+pub(crate) fn is_attached_to(node: &VNode, ancestor: &Node) -> bool {
+    let is_child_of_ancestor = |dom: &Node| dom.parent_node().is_some_and(|x| x.eq(ancestor));
+
+    match node {
+        VNode::Element(velement) => velement
+            .dom
+            .as_ref()
+            .is_some_and(|x| is_child_of_ancestor(x)),
+        VNode::Text(vtext) => vtext.dom.as_ref().is_some_and(|x| is_child_of_ancestor(x)),
+        VNode::List(vlist) => vlist.nodes.iter().all(|x| is_attached_to(x, ancestor)),
+        VNode::Component(vcomp) => vcomp.comp.as_ref().is_some_and(|x| {
+            x.borrow()
+                .vdom
+                .as_ref()
+                .is_some_and(|x| is_attached_to(x, ancestor))
+        }),
+        VNode::Memo(vmemo) => is_attached_to(&vmemo.node, ancestor),
+        // Portal is not rendered into the ancestor, so only its own container matters
+        VNode::Portal(vportal) => vportal.is_attached(),
+        // Head is not rendered at all, so it only has to be declared
+        VNode::Head(vhead) => vhead.is_declared(),
+    }
+}
+
 pub(crate) mod dom {
     use std::borrow::Cow;
 
-    use gloo::events::EventListener;
+    use gloo::events::{EventListener, EventListenerOptions, EventListenerPhase};
     use gloo::utils::{body, document};
     use web_sys::{Element, Event, Node, Text};
 
@@ -37,6 +73,13 @@ pub(crate) mod dom {
         )
     }
 
+    /// Returns the root element only if it was already created.
+    pub fn find_root_element() -> Option<Node> {
+        document()
+            .get_element_by_id(ROOT_ELEMENT_ID)
+            .map(Node::from)
+    }
+
     pub fn create_element(local_name: &str) -> Element {
         document()
             .create_element(local_name)
@@ -77,14 +120,24 @@ pub(crate) mod dom {
         el.remove_attribute(name).expect("Couldnt remove attribute")
     }
 
-    pub fn create_event_listener<F>(
-        element: &Element,
+    pub fn create_delegated_event_listener<F>(
+        container: &Node,
         event_type: Cow<'static, str>,
+        capture: bool,
+        passive: bool,
         callback: F,
     ) -> EventListener
     where
         F: FnMut(&Event) + 'static,
     {
-        EventListener::new(element, event_type, callback)
+        let options = EventListenerOptions {
+            phase: if capture {
+                EventListenerPhase::Capture
+            } else {
+                EventListenerPhase::Bubble
+            },
+            passive,
+        };
+        EventListener::new_with_options(container, event_type, options, callback)
     }
 }
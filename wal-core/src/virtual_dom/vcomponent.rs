@@ -1,28 +1,35 @@
 use web_sys::Node;
 
-use crate::component::{node::AnyComponentNode, Component};
+use crate::{
+    component::{node::AnyComponentNode, Component},
+    utils::any_utils::any_eq,
+};
 
 use std::{
     any::{Any, TypeId},
     cell::RefCell,
-    collections::hash_map::DefaultHasher,
     fmt,
-    hash::{Hash, Hasher},
+    marker::PhantomData,
+    panic::{self, AssertUnwindSafe},
     rc::Rc,
 };
 
 use super::VNode;
 
-pub(crate) type PropertiesHash = u64;
-pub(crate) type AnyProps = Option<Box<dyn Any>>;
+pub(crate) type AnyProps = Box<dyn Any>;
+pub(crate) type PropertiesComparator = fn(&dyn Any, &dyn Any) -> bool;
+/// Takes the properties to be passed to the component. Cloneable properties are cloned, so they are kept for the comparison with the next ones.
+pub(crate) type PropertiesTaker = fn(&mut Option<AnyProps>) -> AnyProps;
 pub(crate) type ComponentNodeGenerator =
     Box<dyn Fn(AnyProps, &Node) -> Rc<RefCell<AnyComponentNode>> + 'static>;
 
 /// Special VNode type, which represents custom component node.
 /// There is no direct translation of [VComponent] to a single DOM node, but it translates to a subtree of DOM nodes.
 pub struct VComponent {
-    props: AnyProps,
-    hash: PropertiesHash,
+    props: Option<AnyProps>,
+    props_eq: PropertiesComparator,
+    take_props: PropertiesTaker,
+    type_id: TypeId,
     generator: ComponentNodeGenerator,
     key: Option<String>,
     depth: Option<u32>,
@@ -32,54 +39,100 @@ pub struct VComponent {
 
 impl VComponent {
     /// Creates [VComponent] out of provided properties. Function is generic, therefore type of [Component] ***C*** has to be specified.
+    /// Properties are cloned when passed to the component, so the component is [changed](Component::changed)
+    /// only if the properties differ from the last ones. See [new_with_owned_props](VComponent::new_with_owned_props) for properties, which are not [Clone].
     ///
     /// # Examples
     ///
     /// ```
+    /// use wal_core::component::{behavior::Behavior, Component};
+    /// use wal_core::virtual_dom::{VComponent, VNode, VText};
+    ///
     /// struct ExampleComponent;
     /// impl Component for ExampleComponent {
+    ///     type Message = ();
     ///     type Properties = ();
-    ///     ...
+    ///
+    ///     fn new(_props: Self::Properties) -> Self {
+    ///         ExampleComponent
+    ///     }
+    ///     fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+    ///         VText::new("example").into()
+    ///     }
+    ///     fn update(&mut self, _message: Self::Message) -> bool {
+    ///         false
+    ///     }
     /// }
     /// let props = ();
     /// let vcomp = VComponent::new::<ExampleComponent>(props, None);
     /// ```
     pub fn new<C>(props: C::Properties, key: Option<String>) -> VComponent
+    where
+        C: Component + 'static,
+        C::Properties: Clone,
+    {
+        Self::new_internal::<C>(props, key, Self::clone_props::<C::Properties>)
+    }
+
+    /// Creates [VComponent] out of provided properties, which are moved to the component.
+    /// As they can not be compared with the next ones, the component is [changed](Component::changed) on every rerender of its parent.
+    pub fn new_with_owned_props<C>(props: C::Properties, key: Option<String>) -> VComponent
+    where
+        C: Component + 'static,
+    {
+        Self::new_internal::<C>(props, key, Self::move_props)
+    }
+
+    fn new_internal<C>(
+        props: C::Properties,
+        key: Option<String>,
+        take_props: PropertiesTaker,
+    ) -> VComponent
     where
         C: Component + 'static,
     {
-        let hash = Self::calculate_hash::<C>(&props);
         let generator = Box::new(Self::generator::<C>);
         VComponent {
             props: Some(Box::new(props)),
+            props_eq: any_eq::<C::Properties>,
+            take_props,
             generator,
-            hash,
+            type_id: TypeId::of::<C>(),
             key,
             depth: None,
             comp: None,
         }
     }
 
-    fn calculate_hash<C>(props: &C::Properties) -> PropertiesHash
-    where
-        C: Component + 'static,
-    {
-        let mut hasher = DefaultHasher::new();
-        props.hash(&mut hasher);
-        TypeId::of::<C>().hash(&mut hasher);
-        hasher.finish()
+    fn clone_props<P: Clone + 'static>(props: &mut Option<AnyProps>) -> AnyProps {
+        let props = props
+            .as_ref()
+            .expect("Properties should not be moved")
+            .downcast_ref::<P>()
+            .expect("Trying to unpack others component properties");
+        Box::new(props.clone())
+    }
+
+    fn move_props(props: &mut Option<AnyProps>) -> AnyProps {
+        props.take().expect("Properties should not be moved")
     }
 
     fn generator<C: Component + 'static>(
         props: AnyProps,
         ancestor: &Node,
     ) -> Rc<RefCell<AnyComponentNode>> {
-        let props = props
-            .unwrap()
-            .downcast::<C::Properties>()
-            .expect("Trying to unpack others component properties");
-
-        AnyComponentNode::new(C::new(*props), ancestor.clone())
+        let component = panic::catch_unwind(AssertUnwindSafe(|| {
+            let props = props
+                .downcast::<C::Properties>()
+                .expect("Trying to unpack others component properties");
+            C::new(*props)
+        }));
+
+        match component {
+            Ok(component) => AnyComponentNode::new(component, ancestor.clone()),
+            // Failed component is passed to the nearest error boundary, which renders fallback instead
+            Err(payload) => AnyComponentNode::new_failed::<C>(payload, ancestor.clone()),
+        }
     }
 
     pub(crate) fn patch(&mut self, last: Option<VNode>, ancestor: &Node) {
@@ -99,6 +152,15 @@ impl VComponent {
             Some(VNode::List(v)) => {
                 v.erase();
             }
+            Some(VNode::Memo(v)) => {
+                v.erase();
+            }
+            Some(VNode::Portal(v)) => {
+                v.erase();
+            }
+            Some(VNode::Head(v)) => {
+                v.erase();
+            }
         }
 
         self.render(old_virt, ancestor);
@@ -110,20 +172,40 @@ impl VComponent {
         }
     }
 
+    fn is_failed(&self) -> bool {
+        self.comp
+            .as_ref()
+            .is_some_and(|node| node.borrow().is_failed())
+    }
+
     pub(crate) fn set_depth(&mut self, depth: u32) {
         self.depth = Some(depth);
     }
 
     fn render(&mut self, last: Option<VComponent>, ancestor: &Node) {
         match last {
-            Some(mut old_vcomp) if self.key.is_some() && old_vcomp.key == self.key => {
-                self.comp = old_vcomp.comp.take();
-            }
-            Some(mut old_vcomp) if old_vcomp.hash == self.hash => {
-                self.comp = old_vcomp.comp.take();
+            // the same component type under the same key, component node is reused, unless the component could not be created
+            Some(mut old_vcomp)
+                if old_vcomp.type_id == self.type_id
+                    && old_vcomp.key == self.key
+                    && !old_vcomp.is_failed() =>
+            {
+                let any_component_node_rc = old_vcomp
+                    .comp
+                    .take()
+                    .expect("Component node should be created while rendering last component");
+                if !self.has_equal_props(&old_vcomp) {
+                    let props = (self.take_props)(&mut self.props);
+                    let mut any_component_node = any_component_node_rc.borrow_mut();
+                    if any_component_node.changed(props) {
+                        any_component_node.view_and_patch();
+                    }
+                }
+                self.comp = Some(any_component_node_rc);
             }
             Some(old_vcomp) => {
-                let any_component_node_rc = (self.generator)(self.props.take(), ancestor);
+                let props = (self.take_props)(&mut self.props);
+                let any_component_node_rc = (self.generator)(props, ancestor);
                 {
                     let mut any_component_node = any_component_node_rc.borrow_mut();
                     any_component_node.depth = self.depth;
@@ -133,7 +215,8 @@ impl VComponent {
                 self.comp = Some(any_component_node_rc);
             }
             None => {
-                let any_component_node_rc = (self.generator)(self.props.take(), ancestor);
+                let props = (self.take_props)(&mut self.props);
+                let any_component_node_rc = (self.generator)(props, ancestor);
                 {
                     let mut any_component_node = any_component_node_rc.borrow_mut();
                     any_component_node.depth = self.depth;
@@ -146,11 +229,22 @@ impl VComponent {
     }
 }
 
+impl VComponent {
+    /// Moved properties are never equal, as they are not known anymore.
+    fn has_equal_props(&self, other: &VComponent) -> bool {
+        match (self.props.as_ref(), other.props.as_ref()) {
+            (Some(props), Some(other_props)) => {
+                (self.props_eq)(props.as_ref(), other_props.as_ref())
+            }
+            _ => false,
+        }
+    }
+}
+
 impl fmt::Debug for VComponent {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("VComponent")
             .field("props", &self.props)
-            .field("hash", &self.hash)
             .field("comp", &self.comp)
             .finish()
     }
@@ -158,14 +252,49 @@ impl fmt::Debug for VComponent {
 
 impl PartialEq for VComponent {
     fn eq(&self, other: &Self) -> bool {
-        self.hash == other.hash
-            && match (&self.props, &other.props) {
-                (Some(self_props), Some(other_props)) => {
-                    (*(*self_props)).type_id() == (*(*other_props)).type_id()
-                }
-                (None, None) => true,
-                _ => false,
-            }
+        self.type_id == other.type_id && self.has_equal_props(other)
+    }
+}
+
+/// Chooses the constructor of [VComponent] in the [rsx](../../../wal_rsx/macro.rsx.html) macro,
+/// depending on whether the properties of the component ***C*** are [Clone].
+/// Method resolution of `(&&VComponentFactory::<C>::default()).create(..)` prefers [CloneablePropertiesFactory],
+/// which is implemented only for cloneable properties, and falls back to [OwnedPropertiesFactory] otherwise.
+#[doc(hidden)]
+pub struct VComponentFactory<C>(PhantomData<C>);
+
+impl<C> Default for VComponentFactory<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait CloneablePropertiesFactory<C: Component> {
+    fn create(&self, props: C::Properties, key: Option<String>) -> VComponent;
+}
+
+impl<C> CloneablePropertiesFactory<C> for &VComponentFactory<C>
+where
+    C: Component + 'static,
+    C::Properties: Clone,
+{
+    fn create(&self, props: C::Properties, key: Option<String>) -> VComponent {
+        VComponent::new::<C>(props, key)
+    }
+}
+
+#[doc(hidden)]
+pub trait OwnedPropertiesFactory<C: Component> {
+    fn create(&self, props: C::Properties, key: Option<String>) -> VComponent;
+}
+
+impl<C> OwnedPropertiesFactory<C> for VComponentFactory<C>
+where
+    C: Component + 'static,
+{
+    fn create(&self, props: C::Properties, key: Option<String>) -> VComponent {
+        VComponent::new_with_owned_props::<C>(props, key)
     }
 }
 
@@ -174,11 +303,12 @@ mod tests {
     use wasm_bindgen_test::wasm_bindgen_test;
 
     use crate::{
-        component::{behavior::Behavior, Component},
+        component::{behavior::Behavior, node::AnyComponentNode, Component},
         virtual_dom::{dom, VElement, VList, VNode, VText},
     };
 
     use super::VComponent;
+    use std::{cell::RefCell, rc::Rc};
     wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 
     macro_rules! function_name {
@@ -344,4 +474,228 @@ mod tests {
         target.set_depth(0);
         target.patch(Some(list), &ancestor);
     }
+
+    struct Counter {
+        step: u32,
+        count: u32,
+    }
+    impl Component for Counter {
+        type Message = ();
+        type Properties = u32;
+
+        fn new(props: Self::Properties) -> Self {
+            Counter {
+                step: props,
+                count: 0,
+            }
+        }
+        fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+            VText::new(self.count).into()
+        }
+        fn update(&mut self, _message: Self::Message) -> bool {
+            self.count += self.step;
+            true
+        }
+        fn changed(&mut self, props: Self::Properties) -> bool {
+            self.step = props;
+            false
+        }
+    }
+
+    fn get_component_node(vnode: &VNode) -> Rc<RefCell<AnyComponentNode>> {
+        match vnode {
+            VNode::Component(vcomp) => vcomp.comp.clone().unwrap(),
+            _ => panic!("Expected component node"),
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_last_comp_same_type_different_props_should_reuse_component_node() {
+        let ancestor = dom::create_element("div");
+        dom::set_attribute(&ancestor, "id", function_name!());
+        dom::append_child(&dom::get_root_element(), &ancestor);
+
+        let mut comp = VNode::Component(VComponent::new::<Counter>(1, None));
+        comp.set_depth(0);
+        comp.patch(None, &ancestor);
+        let last_component_node = get_component_node(&comp);
+        last_component_node.borrow_mut().update(Box::new(()));
+
+        let mut target = VNode::Component(VComponent::new::<Counter>(2, None));
+        target.set_depth(0);
+        target.patch(Some(comp), &ancestor);
+
+        let component_node = get_component_node(&target);
+        assert!(Rc::ptr_eq(&last_component_node, &component_node));
+        component_node.borrow_mut().update(Box::new(()));
+        component_node.borrow_mut().view_and_patch();
+        assert_eq!(ancestor.text_content().unwrap(), "3");
+    }
+
+    struct Draft {
+        draft: String,
+    }
+    impl Component for Draft {
+        type Message = String;
+        type Properties = u32;
+
+        fn new(_props: Self::Properties) -> Self {
+            Draft {
+                draft: String::new(),
+            }
+        }
+        fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+            VText::new(&self.draft).into()
+        }
+        fn update(&mut self, message: Self::Message) -> bool {
+            self.draft = message;
+            true
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_last_comp_same_props_should_keep_draft_of_component_not_overriding_changed() {
+        let ancestor = dom::create_element("div");
+        dom::set_attribute(&ancestor, "id", function_name!());
+        dom::append_child(&dom::get_root_element(), &ancestor);
+
+        let mut comp = VNode::Component(VComponent::new::<Draft>(1, None));
+        comp.set_depth(0);
+        comp.patch(None, &ancestor);
+        let last_component_node = get_component_node(&comp);
+        last_component_node
+            .borrow_mut()
+            .update(Box::new(String::from("draft")));
+        last_component_node.borrow_mut().view_and_patch();
+
+        let mut target = VNode::Component(VComponent::new::<Draft>(1, None));
+        target.set_depth(0);
+        target.patch(Some(comp), &ancestor);
+
+        assert!(Rc::ptr_eq(
+            &last_component_node,
+            &get_component_node(&target)
+        ));
+        assert_eq!(ancestor.text_content().unwrap(), "draft");
+    }
+
+    #[derive(PartialEq)]
+    struct NotCloneableStep(u32);
+
+    struct NotCloneableCounter {
+        changed_count: u32,
+    }
+    impl Component for NotCloneableCounter {
+        type Message = ();
+        type Properties = NotCloneableStep;
+
+        fn new(_props: Self::Properties) -> Self {
+            NotCloneableCounter { changed_count: 0 }
+        }
+        fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+            VText::new(self.changed_count).into()
+        }
+        fn update(&mut self, _message: Self::Message) -> bool {
+            false
+        }
+        fn changed(&mut self, _props: Self::Properties) -> bool {
+            self.changed_count += 1;
+            true
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_last_comp_with_owned_props_should_reuse_component_node_and_call_changed() {
+        let ancestor = dom::create_element("div");
+        dom::set_attribute(&ancestor, "id", function_name!());
+        dom::append_child(&dom::get_root_element(), &ancestor);
+
+        let mut comp = VNode::Component(VComponent::new_with_owned_props::<NotCloneableCounter>(
+            NotCloneableStep(1),
+            None,
+        ));
+        comp.set_depth(0);
+        comp.patch(None, &ancestor);
+        let last_component_node = get_component_node(&comp);
+
+        let mut target = VNode::Component(VComponent::new_with_owned_props::<NotCloneableCounter>(
+            NotCloneableStep(1),
+            None,
+        ));
+        target.set_depth(0);
+        target.patch(Some(comp), &ancestor);
+
+        assert!(Rc::ptr_eq(
+            &last_component_node,
+            &get_component_node(&target)
+        ));
+        assert_eq!(ancestor.text_content().unwrap(), "1");
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_last_comp_same_type_different_keys_should_create_new_component_node() {
+        let ancestor = dom::create_element("div");
+        dom::set_attribute(&ancestor, "id", function_name!());
+        dom::append_child(&dom::get_root_element(), &ancestor);
+
+        let mut comp = VNode::Component(VComponent::new::<Counter>(1, Some("1".into())));
+        comp.set_depth(0);
+        comp.patch(None, &ancestor);
+        let last_component_node = get_component_node(&comp);
+
+        let mut target = VNode::Component(VComponent::new::<Counter>(1, Some("2".into())));
+        target.set_depth(0);
+        target.patch(Some(comp), &ancestor);
+
+        assert!(!Rc::ptr_eq(
+            &last_component_node,
+            &get_component_node(&target)
+        ));
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_last_comp_different_type_same_keys_should_create_new_component_node() {
+        let ancestor = dom::create_element("div");
+        dom::set_attribute(&ancestor, "id", function_name!());
+        dom::append_child(&dom::get_root_element(), &ancestor);
+
+        let key = Some(String::from("Same_key"));
+        let mut comp = VNode::Component(VComponent::new::<Comp>((), key.clone()));
+        comp.set_depth(0);
+        comp.patch(None, &ancestor);
+        let last_component_node = get_component_node(&comp);
+
+        let mut target = VNode::Component(VComponent::new::<Tmp>((), key));
+        target.set_depth(0);
+        target.patch(Some(comp), &ancestor);
+
+        assert!(!Rc::ptr_eq(
+            &last_component_node,
+            &get_component_node(&target)
+        ));
+    }
+
+    #[wasm_bindgen_test]
+    fn vcomponents_with_the_same_type_and_equal_props_should_be_equal() {
+        assert_eq!(
+            VComponent::new::<Counter>(1, None),
+            VComponent::new::<Counter>(1, None)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn vcomponents_with_different_props_should_not_be_equal() {
+        assert_ne!(
+            VComponent::new::<Counter>(1, None),
+            VComponent::new::<Counter>(2, None)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn vcomponents_with_different_types_should_not_be_equal() {
+        assert_ne!(
+            VComponent::new::<Comp>((), None),
+            VComponent::new::<Tmp>((), None)
+        );
+    }
 }
@@ -1,5 +1,5 @@
 use itertools::{EitherOrBoth, Itertools};
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use web_sys::{Element, Node};
 
 use crate::{events::EventHandler, virtual_dom::dom};
@@ -24,8 +24,10 @@ impl VElement {
     /// # Example
     ///
     /// ```
+    /// use wal_core::virtual_dom::{VElement, VNode, VText};
+    ///
     /// let element = VElement::new(
-    ///     String::from("div")                                     // tag_name,
+    ///     String::from("div"),                                    // tag_name,
     ///     [(String::from("id"),String::from("example"))].into(),  // attr,
     ///     vec![],                                                 // event_handlers,
     ///     None,                                                   // key,
@@ -56,11 +58,11 @@ impl VElement {
         match last {
             None => {}
             Some(VNode::Element(mut velement)) => {
-                if velement
+                let is_attached_to_ancestor = velement
                     .dom
                     .as_ref()
-                    .is_some_and(|x| x.parent_node().is_some_and(|y| y.eq(ancestor)))
-                {
+                    .is_some_and(|x| x.parent_node().is_some_and(|y| y.eq(ancestor)));
+                if is_attached_to_ancestor {
                     self.dom = velement.dom.take();
                     old_virt = Some(velement);
                 }
@@ -74,9 +76,18 @@ impl VElement {
             Some(VNode::List(v)) => {
                 v.erase();
             }
+            Some(VNode::Memo(v)) => {
+                v.erase();
+            }
+            Some(VNode::Portal(v)) => {
+                v.erase();
+            }
+            Some(VNode::Head(v)) => {
+                v.erase();
+            }
         }
 
-        self.render(old_virt.as_ref(), ancestor);
+        self.render(old_virt.as_mut(), ancestor);
         self.handle_children(old_virt);
         self.check_if_parents_match(ancestor);
     }
@@ -95,11 +106,12 @@ impl VElement {
 }
 
 impl VElement {
-    fn render(&mut self, last: Option<&VElement>, ancestor: &Node) {
+    fn render(&mut self, last: Option<&mut VElement>, ancestor: &Node) {
         match last {
-            // comparison over user-defined key, if match dont do anything
+            // comparison over user-defined key, if match only event handlers are patched
             Some(last) if last.key.is_some() && last.key == self.key => {
                 dom::append_child(ancestor, self.dom.as_ref().unwrap());
+                self.patch_event_handlers(&mut last.event_handlers);
             }
 
             Some(last) if last.tag_name == self.tag_name => {
@@ -117,9 +129,7 @@ impl VElement {
                     }
                 }
 
-                for event_handler in &mut self.event_handlers {
-                    event_handler.attach(target);
-                }
+                self.patch_event_handlers(&mut last.event_handlers);
             }
             _ => {
                 // inverted check, if last == None || last = Some(x) that x.tag_name !=
@@ -144,6 +154,32 @@ impl VElement {
         }
     }
 
+    fn patch_event_handlers(&mut self, last_event_handlers: &mut Vec<EventHandler>) {
+        let target = self
+            .dom
+            .as_ref()
+            .expect("Target dom object not created before patching event handlers");
+
+        for event_handler in &mut self.event_handlers {
+            let reusable = last_event_handlers
+                .iter()
+                .position(|x| x.is_attached() && x.is_same(event_handler))
+                .or_else(|| {
+                    last_event_handlers
+                        .iter()
+                        .position(|x| x.is_attached() && x.is_same_kind(event_handler))
+                });
+
+            match reusable {
+                Some(index) => event_handler.reuse(&mut last_event_handlers[index]),
+                None => event_handler.attach(target),
+            }
+        }
+
+        // handlers which are not present anymore are detached
+        last_event_handlers.clear();
+    }
+
     fn handle_children(&mut self, old_element: Option<VElement>) {
         let target = self.dom.as_mut().unwrap();
         let old_children = old_element.map_or(Vec::new(), |e| e.children.into_iter().collect());
@@ -177,14 +213,19 @@ impl VElement {
 
 impl PartialEq for VElement {
     fn eq(&self, other: &Self) -> bool {
-        let self_event_handlers: HashSet<_> = self.event_handlers.iter().collect();
-        let other_event_handlers: HashSet<_> = other.event_handlers.iter().collect();
+        let contains_all = |event_handlers: &[EventHandler],
+                            other_event_handlers: &[EventHandler]| {
+            event_handlers
+                .iter()
+                .all(|x| other_event_handlers.contains(x))
+        };
 
         self.tag_name == other.tag_name
             && self.attr == other.attr
             && self.children == other.children
             && self.dom == other.dom
-            && self_event_handlers == other_event_handlers
+            && contains_all(&self.event_handlers, &other.event_handlers)
+            && contains_all(&other.event_handlers, &self.event_handlers)
     }
 }
 
@@ -192,8 +233,11 @@ impl PartialEq for VElement {
 mod tests {
     use wasm_bindgen_test::wasm_bindgen_test;
 
+    use std::{cell::RefCell, rc::Rc};
+
     use crate::{
-        component::{behavior::Behavior, Component},
+        component::{behavior::Behavior, callback::Callback, Component},
+        events::{onclick, EventHandler, MouseEvent},
         virtual_dom::{dom, VComponent, VList, VNode, VText},
     };
 
@@ -374,4 +418,90 @@ mod tests {
         );
         target.patch(Some(list), &ancestor);
     }
+
+    fn create_clickable_element(callbacks: Vec<Callback<MouseEvent>>) -> VElement {
+        VElement::new(
+            "button".into(),
+            [].into(),
+            callbacks
+                .into_iter()
+                .map(|callback| EventHandler::new(onclick(callback)))
+                .collect(),
+            None,
+            vec![],
+        )
+    }
+
+    fn create_counting_callback(counter: &Rc<RefCell<u32>>) -> Callback<MouseEvent> {
+        let counter = counter.clone();
+        Callback::new(move |_| *counter.borrow_mut() += 1)
+    }
+
+    fn click(velement: &VElement) {
+        let event_init = web_sys::EventInit::new();
+        event_init.set_bubbles(true);
+        let event = web_sys::Event::new_with_event_init_dict("click", &event_init).unwrap();
+        velement
+            .dom
+            .as_ref()
+            .unwrap()
+            .dispatch_event(&event)
+            .unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_with_the_same_callback_should_keep_event_handler_registration() {
+        let ancestor = dom::create_element("div");
+        dom::set_attribute(&ancestor, "id", function_name!());
+        dom::append_child(&dom::get_root_element(), &ancestor);
+        let counter = Rc::new(RefCell::new(0));
+        let callback = create_counting_callback(&counter);
+
+        let mut last = VNode::Element(create_clickable_element(vec![callback.clone()]));
+        last.patch(None, &ancestor);
+        let mut target = create_clickable_element(vec![callback]);
+        target.patch(Some(last), &ancestor);
+        click(&target);
+
+        assert!(target.event_handlers[0].is_attached());
+        assert_eq!(*counter.borrow(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_with_different_callback_should_swap_event_handler_callback() {
+        let ancestor = dom::create_element("div");
+        dom::set_attribute(&ancestor, "id", function_name!());
+        dom::append_child(&dom::get_root_element(), &ancestor);
+        let last_counter = Rc::new(RefCell::new(0));
+        let counter = Rc::new(RefCell::new(0));
+
+        let mut last = VNode::Element(create_clickable_element(vec![create_counting_callback(
+            &last_counter,
+        )]));
+        last.patch(None, &ancestor);
+        let mut target = create_clickable_element(vec![create_counting_callback(&counter)]);
+        target.patch(Some(last), &ancestor);
+        click(&target);
+
+        assert_eq!(*last_counter.borrow(), 0);
+        assert_eq!(*counter.borrow(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_without_event_handler_should_detach_last_event_handler() {
+        let ancestor = dom::create_element("div");
+        dom::set_attribute(&ancestor, "id", function_name!());
+        dom::append_child(&dom::get_root_element(), &ancestor);
+        let last_counter = Rc::new(RefCell::new(0));
+
+        let mut last = VNode::Element(create_clickable_element(vec![create_counting_callback(
+            &last_counter,
+        )]));
+        last.patch(None, &ancestor);
+        let mut target = create_clickable_element(vec![]);
+        target.patch(Some(last), &ancestor);
+        click(&target);
+
+        assert_eq!(*last_counter.borrow(), 0);
+    }
 }
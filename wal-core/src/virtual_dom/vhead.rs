@@ -0,0 +1,148 @@
+use std::fmt;
+
+use crate::head::{self, HeadDeclaration};
+
+use super::VNode;
+
+/// Declaration of the elements of the document `<head>`: `title`, `meta` identified by `charset`, `name`, `property` or `http-equiv` attribute
+/// and `link` identified by `rel` and `href` attributes, or by `rel` alone if it is `canonical` or `manifest`.
+/// The elements are not rendered in place of the [VHead].
+///
+/// If the same element is declared by multiple [VHeads](VHead), the deepest one in the component tree wins,
+/// and among the ones at the same depth the one declared last. When the [VHead] is removed, e.g. its component is unmounted
+/// or the route changes, the elements are restored to the values declared by the others or to the values they had before.
+pub struct VHead {
+    pub(crate) node: Box<VNode>,
+    depth: Option<u32>,
+    declaration: Option<HeadDeclaration>,
+}
+
+impl VHead {
+    /// Creates [VHead] declaring provided elements.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let title = VElement::new("title".into(), [].into(), vec![], None, vec![VText::new("Users").into()]);
+    /// let vhead = VHead::new(VNode::Element(title));
+    /// ```
+    pub fn new(node: VNode) -> VHead {
+        VHead {
+            node: Box::new(node),
+            depth: None,
+            declaration: None,
+        }
+    }
+
+    pub(crate) fn patch(&mut self, last: Option<VNode>) {
+        match last {
+            Some(VNode::Head(mut vhead)) => self.declaration = vhead.declaration.take(),
+            Some(last) => last.erase(),
+            None => {}
+        }
+
+        let depth = self.depth.expect("Depth is not set");
+        let entries = head::get_entries(&self.node);
+        match self.declaration.as_ref() {
+            Some(declaration) => declaration.update(depth, entries),
+            None => self.declaration = Some(HeadDeclaration::new(depth, entries)),
+        }
+    }
+
+    pub(crate) fn erase(&self) {
+        if let Some(declaration) = self.declaration.as_ref() {
+            declaration.remove();
+        }
+    }
+
+    pub(crate) fn set_depth(&mut self, depth: u32) {
+        self.depth = Some(depth);
+    }
+
+    pub(crate) fn is_declared(&self) -> bool {
+        self.declaration.is_some()
+    }
+}
+
+impl fmt::Debug for VHead {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VHead").field("node", &self.node).finish()
+    }
+}
+
+impl PartialEq for VHead {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gloo::utils::document;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::virtual_dom::{dom, VElement, VNode, VText};
+
+    use super::VHead;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn create_title(title: &str) -> VNode {
+        VNode::Head(VHead::new(VNode::Element(VElement::new(
+            "title".to_string(),
+            [].into(),
+            vec![],
+            None,
+            vec![VText::new(title).into()],
+        ))))
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_should_set_title_without_rendering_into_ancestor() {
+        // Arrange
+        let ancestor = dom::create_element("div");
+        let mut vhead = create_title("Head title");
+        vhead.set_depth(0);
+
+        // Act
+        vhead.patch(None, &ancestor);
+
+        // Assert
+        assert_eq!(document().title(), "Head title");
+        assert_eq!(ancestor.child_element_count(), 0);
+        vhead.erase();
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_with_last_head_should_update_title() {
+        // Arrange
+        let ancestor = dom::create_element("div");
+        let mut last = create_title("Old title");
+        last.set_depth(0);
+        last.patch(None, &ancestor);
+
+        // Act
+        let mut vhead = create_title("New title");
+        vhead.set_depth(0);
+        vhead.patch(Some(last), &ancestor);
+
+        // Assert
+        assert_eq!(document().title(), "New title");
+        vhead.erase();
+    }
+
+    #[wasm_bindgen_test]
+    fn erase_should_restore_title() {
+        // Arrange
+        document().set_title("Before head");
+        let ancestor = dom::create_element("div");
+        let mut vhead = create_title("Head title");
+        vhead.set_depth(0);
+        vhead.patch(None, &ancestor);
+
+        // Act
+        vhead.erase();
+
+        // Assert
+        assert_eq!(document().title(), "Before head");
+    }
+}
@@ -41,6 +41,15 @@ impl VList {
             Some(VNode::Component(v)) => {
                 v.erase();
             }
+            Some(VNode::Memo(v)) => {
+                v.erase();
+            }
+            Some(VNode::Portal(v)) => {
+                v.erase();
+            }
+            Some(VNode::Head(v)) => {
+                v.erase();
+            }
         }
 
         self.render(old_virt, ancestor);
@@ -0,0 +1,159 @@
+use std::{any::Any, fmt};
+
+use web_sys::Node;
+
+use crate::utils::any_utils::any_eq;
+
+use super::VNode;
+
+pub(crate) type DependenciesComparator = fn(&dyn Any, &dyn Any) -> bool;
+
+/// Wrapper over a subtree of virtual DOM, which marks it as pure.
+/// If dependencies of the [VMemo] are equal to the dependencies of the last [VMemo] at the same place,
+/// the whole subtree is skipped during patching and the previously rendered one is kept.
+pub struct VMemo {
+    deps: Box<dyn Any>,
+    deps_eq: DependenciesComparator,
+    pub(crate) node: Box<VNode>,
+}
+
+impl VMemo {
+    /// Creates [VMemo] out of provided dependencies and subtree. Dependencies are compared using their [PartialEq] implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let count = 5;
+    /// let vmemo = VMemo::new(count, VNode::Text(VText::new(format!("Count: {count}"))));
+    /// ```
+    pub fn new<D: PartialEq + 'static>(deps: D, node: VNode) -> VMemo {
+        VMemo {
+            deps: Box::new(deps),
+            deps_eq: any_eq::<D>,
+            node: Box::new(node),
+        }
+    }
+
+    pub(crate) fn patch(&mut self, last: Option<VNode>, ancestor: &Node) {
+        match last {
+            Some(VNode::Memo(vmemo)) if self.is_reusable(&vmemo, ancestor) => {
+                self.node = vmemo.node;
+            }
+            Some(VNode::Memo(vmemo)) => self.node.patch(Some(*vmemo.node), ancestor),
+            last => self.node.patch(last, ancestor),
+        }
+    }
+
+    pub(crate) fn erase(&self) {
+        self.node.erase();
+    }
+
+    pub(crate) fn set_depth(&mut self, depth: u32) {
+        self.node.set_depth(depth);
+    }
+
+    fn is_reusable(&self, last: &VMemo, ancestor: &Node) -> bool {
+        (self.deps_eq)(self.deps.as_ref(), last.deps.as_ref())
+            && super::is_attached_to(&last.node, ancestor)
+    }
+}
+
+impl fmt::Debug for VMemo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VMemo").field("node", &self.node).finish()
+    }
+}
+
+impl PartialEq for VMemo {
+    fn eq(&self, other: &Self) -> bool {
+        (self.deps_eq)(self.deps.as_ref(), other.deps.as_ref()) && self.node == other.node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::virtual_dom::{dom, VElement, VNode, VText};
+
+    use super::VMemo;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn create_ancestor() -> web_sys::Element {
+        let ancestor = dom::create_element("div");
+        dom::append_child(&dom::get_root_element(), &ancestor);
+        ancestor
+    }
+
+    fn create_element(text: &str) -> VNode {
+        VNode::Element(VElement::new(
+            "div".to_string(),
+            [].into(),
+            vec![],
+            None,
+            vec![VText::new(text).into()],
+        ))
+    }
+
+    #[wasm_bindgen_test]
+    fn memos_with_the_same_deps_and_node_should_be_equal() {
+        assert_eq!(
+            VMemo::new(1, create_element("text")),
+            VMemo::new(1, create_element("text"))
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn memos_with_different_deps_should_not_be_equal() {
+        assert_ne!(
+            VMemo::new(1, create_element("text")),
+            VMemo::new(2, create_element("text"))
+        );
+        assert_ne!(
+            VMemo::new(1, create_element("text")),
+            VMemo::new(1u8, create_element("text"))
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_with_the_same_deps_should_keep_last_subtree() {
+        let ancestor = create_ancestor();
+        let mut last = VNode::Memo(VMemo::new(1, create_element("old")));
+        last.set_depth(0);
+        last.patch(None, &ancestor);
+
+        let mut target = VNode::Memo(VMemo::new(1, create_element("new")));
+        target.set_depth(0);
+        target.patch(Some(last), &ancestor);
+
+        assert_eq!(ancestor.text_content().unwrap(), "old");
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_with_different_deps_should_patch_subtree() {
+        let ancestor = create_ancestor();
+        let mut last = VNode::Memo(VMemo::new(1, create_element("old")));
+        last.set_depth(0);
+        last.patch(None, &ancestor);
+
+        let mut target = VNode::Memo(VMemo::new(2, create_element("new")));
+        target.set_depth(0);
+        target.patch(Some(last), &ancestor);
+
+        assert_eq!(ancestor.text_content().unwrap(), "new");
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_last_not_memo_should_patch_subtree() {
+        let ancestor = create_ancestor();
+        let mut last = create_element("old");
+        last.set_depth(0);
+        last.patch(None, &ancestor);
+
+        let mut target = VNode::Memo(VMemo::new(1, create_element("new")));
+        target.set_depth(0);
+        target.patch(Some(last), &ancestor);
+
+        assert_eq!(ancestor.text_content().unwrap(), "new");
+    }
+}
@@ -1,6 +1,6 @@
 use web_sys::Node;
 
-use super::{VComponent, VElement, VList, VText};
+use super::{VComponent, VElement, VHead, VList, VMemo, VPortal, VText};
 
 /// VNode is enum representing node in virtual DOM tree.
 /// Provides a wrapper over different types of nodes along with concise and convinient API for VDOM manipulation.
@@ -14,6 +14,12 @@ pub enum VNode {
     List(VList),
     /// Represents user-defined custom component, contains [VComponent].
     Component(VComponent),
+    /// Represents a subtree, which is skipped during patching as long as its dependencies do not change, contains [VMemo].
+    Memo(VMemo),
+    /// Represents a subtree rendered into another DOM element, contains [VPortal].
+    Portal(VPortal),
+    /// Represents a declaration of the elements of the document `<head>`, contains [VHead].
+    Head(VHead),
 }
 
 impl VNode {
@@ -23,6 +29,9 @@ impl VNode {
             VNode::Text(vtext) => vtext.patch(last, ancestor),
             VNode::Component(vcomp) => vcomp.patch(last, ancestor),
             VNode::List(vlist) => vlist.patch(last, ancestor),
+            VNode::Memo(vmemo) => vmemo.patch(last, ancestor),
+            VNode::Portal(vportal) => vportal.patch(last, ancestor),
+            VNode::Head(vhead) => vhead.patch(last),
         };
     }
 
@@ -32,6 +41,9 @@ impl VNode {
             VNode::Text(v) => v.erase(),
             VNode::List(v) => v.erase(),
             VNode::Component(v) => v.erase(),
+            VNode::Memo(v) => v.erase(),
+            VNode::Portal(v) => v.erase(),
+            VNode::Head(v) => v.erase(),
         }
     }
 
@@ -40,6 +52,9 @@ impl VNode {
             VNode::Component(vcomp) => vcomp.set_depth(depth),
             VNode::List(vlist) => vlist.set_depth(depth),
             VNode::Element(velem) => velem.set_depth(depth),
+            VNode::Memo(vmemo) => vmemo.set_depth(depth),
+            VNode::Portal(vportal) => vportal.set_depth(depth),
+            VNode::Head(vhead) => vhead.set_depth(depth),
             VNode::Text(_) => {}
         }
     }
@@ -69,6 +84,24 @@ impl From<VList> for VNode {
     }
 }
 
+impl From<VMemo> for VNode {
+    fn from(vmemo: VMemo) -> Self {
+        Self::Memo(vmemo)
+    }
+}
+
+impl From<VPortal> for VNode {
+    fn from(vportal: VPortal) -> Self {
+        Self::Portal(vportal)
+    }
+}
+
+impl From<VHead> for VNode {
+    fn from(vhead: VHead) -> Self {
+        Self::Head(vhead)
+    }
+}
+
 impl<T: ToString> From<T> for VNode {
     fn from(t: T) -> Self {
         Self::Text(VText::new(t))
@@ -0,0 +1,293 @@
+use std::fmt;
+
+use gloo::utils::document;
+use web_sys::{Element, Node};
+
+use crate::events::delegation::{self, ContainerRegistration};
+
+use super::VNode;
+
+/// DOM element, into which the [VPortal] renders its subtree.
+#[derive(PartialEq, Debug, Clone)]
+pub enum PortalTarget {
+    /// CSS selector of the element, e.g. `#modal-root`. The first element matching the selector is used.
+    /// Subtree is not rendered while no element matches the selector.
+    Selector(String),
+    /// Element itself.
+    Element(Element),
+}
+
+impl PortalTarget {
+    /// Finds the target element. Invalid selector or missing element is reported in the console and [None] is returned.
+    fn resolve(&self) -> Option<Element> {
+        match self {
+            PortalTarget::Selector(selector) => match document().query_selector(selector) {
+                Ok(Some(element)) => Some(element),
+                Ok(None) => {
+                    web_sys::console::warn_1(
+                        &format!("Portal target \"{selector}\" does not exist").into(),
+                    );
+                    None
+                }
+                Err(_) => {
+                    web_sys::console::warn_1(
+                        &format!("Invalid portal target selector \"{selector}\"").into(),
+                    );
+                    None
+                }
+            },
+            PortalTarget::Element(element) => Some(element.clone()),
+        }
+    }
+}
+
+impl From<&str> for PortalTarget {
+    fn from(selector: &str) -> Self {
+        Self::Selector(selector.to_string())
+    }
+}
+
+impl From<String> for PortalTarget {
+    fn from(selector: String) -> Self {
+        Self::Selector(selector)
+    }
+}
+
+impl From<Element> for PortalTarget {
+    fn from(element: Element) -> Self {
+        Self::Element(element)
+    }
+}
+
+/// Wrapper over a subtree of virtual DOM, which renders it into the target element instead of the ancestor of the [VPortal].
+/// The subtree is still part of the component tree, so messages and context work as usual
+/// and events dispatched inside of the subtree bubble to the ancestors of the [VPortal].
+pub struct VPortal {
+    target: PortalTarget,
+    pub(crate) node: Box<VNode>,
+    container: Option<Element>,
+    registration: Option<ContainerRegistration>,
+}
+
+impl VPortal {
+    /// Creates [VPortal] rendering provided subtree into the target element.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let vportal = VPortal::new("#modal-root", VNode::Text(VText::new("Modal")));
+    /// ```
+    pub fn new(target: impl Into<PortalTarget>, node: VNode) -> VPortal {
+        VPortal {
+            target: target.into(),
+            node: Box::new(node),
+            container: None,
+            registration: None,
+        }
+    }
+
+    pub(crate) fn patch(&mut self, last: Option<VNode>, ancestor: &Node) {
+        let last_node = match last {
+            Some(VNode::Portal(mut vportal)) if vportal.target == self.target => {
+                self.container = vportal.container.take();
+                self.registration = vportal.registration.take();
+                Some(*vportal.node)
+            }
+            Some(last) => {
+                last.erase();
+                None
+            }
+            None => None,
+        };
+
+        let container = match self.container.as_ref() {
+            Some(container) => container.clone(),
+            None => {
+                // Subtree is not rendered until the target exists, resolving is retried on the next patch
+                let Some(container) = self.target.resolve() else {
+                    if let Some(last_node) = last_node {
+                        last_node.erase();
+                    }
+                    return;
+                };
+                self.registration = Some(delegation::register_container(&container));
+                self.container = Some(container.clone());
+                container
+            }
+        };
+
+        self.node.patch(last_node, &container);
+        for node in get_top_dom_nodes(&self.node) {
+            delegation::set_portal_parent(&node, ancestor);
+        }
+    }
+
+    pub(crate) fn erase(&self) {
+        self.node.erase();
+    }
+
+    pub(crate) fn set_depth(&mut self, depth: u32) {
+        self.node.set_depth(depth);
+    }
+
+    pub(crate) fn is_attached(&self) -> bool {
+        self.container
+            .as_ref()
+            .is_some_and(|x| super::is_attached_to(&self.node, x))
+    }
+}
+
+// Nested portals are skipped, as their nodes are rendered into a different container
+fn get_top_dom_nodes(node: &VNode) -> Vec<Node> {
+    match node {
+        VNode::Element(velement) => velement.dom.iter().map(|x| x.clone().into()).collect(),
+        VNode::Text(vtext) => vtext.dom.iter().map(|x| x.clone().into()).collect(),
+        VNode::List(vlist) => vlist.nodes.iter().flat_map(get_top_dom_nodes).collect(),
+        VNode::Component(vcomp) => vcomp
+            .comp
+            .as_ref()
+            .and_then(|x| x.borrow().vdom.as_ref().map(get_top_dom_nodes))
+            .unwrap_or_default(),
+        VNode::Memo(vmemo) => get_top_dom_nodes(&vmemo.node),
+        VNode::Portal(_) | VNode::Head(_) => Vec::new(),
+    }
+}
+
+impl fmt::Debug for VPortal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VPortal")
+            .field("target", &self.target)
+            .field("node", &self.node)
+            .finish()
+    }
+}
+
+impl PartialEq for VPortal {
+    fn eq(&self, other: &Self) -> bool {
+        self.target == other.target && self.node == other.node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::virtual_dom::{dom, VElement, VNode, VText};
+
+    use super::VPortal;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn create_ancestor() -> web_sys::Element {
+        let ancestor = dom::create_element("div");
+        dom::append_child(&dom::get_root_element(), &ancestor);
+        ancestor
+    }
+
+    fn create_target(id: &str) -> web_sys::Element {
+        let target = dom::create_element("div");
+        dom::set_attribute(&target, "id", id);
+        dom::append_child(&gloo::utils::body(), &target);
+        target
+    }
+
+    fn create_element(text: &str) -> VNode {
+        VNode::Element(VElement::new(
+            "div".to_string(),
+            [].into(),
+            vec![],
+            None,
+            vec![VText::new(text).into()],
+        ))
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_should_render_subtree_into_target() {
+        // Arrange
+        let ancestor = create_ancestor();
+        let target = create_target("portal-render");
+        let mut vportal = VNode::Portal(VPortal::new("#portal-render", create_element("modal")));
+        vportal.set_depth(0);
+
+        // Act
+        vportal.patch(None, &ancestor);
+
+        // Assert
+        assert_eq!(ancestor.text_content().unwrap(), "");
+        assert_eq!(target.text_content().unwrap(), "modal");
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_with_missing_or_invalid_target_should_not_render_subtree() {
+        // Arrange
+        let ancestor = create_ancestor();
+        let mut missing = VNode::Portal(VPortal::new("#portal-missing", create_element("modal")));
+        let mut invalid = VNode::Portal(VPortal::new("#[", create_element("modal")));
+        missing.set_depth(0);
+        invalid.set_depth(0);
+
+        // Act
+        missing.patch(None, &ancestor);
+        invalid.patch(None, &ancestor);
+
+        // Assert
+        assert_eq!(ancestor.text_content().unwrap(), "");
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_with_the_same_target_should_patch_subtree() {
+        // Arrange
+        let ancestor = create_ancestor();
+        let target = create_target("portal-patch");
+        let mut last = VNode::Portal(VPortal::new("#portal-patch", create_element("old")));
+        last.set_depth(0);
+        last.patch(None, &ancestor);
+
+        // Act
+        let mut vportal = VNode::Portal(VPortal::new("#portal-patch", create_element("new")));
+        vportal.set_depth(0);
+        vportal.patch(Some(last), &ancestor);
+
+        // Assert
+        assert_eq!(target.child_element_count(), 1);
+        assert_eq!(target.text_content().unwrap(), "new");
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_with_different_target_should_move_subtree() {
+        // Arrange
+        let ancestor = create_ancestor();
+        let old_target = create_target("portal-old-target");
+        let new_target = create_target("portal-new-target");
+        let mut last = VNode::Portal(VPortal::new("#portal-old-target", create_element("old")));
+        last.set_depth(0);
+        last.patch(None, &ancestor);
+
+        // Act
+        let mut vportal = VNode::Portal(VPortal::new(new_target.clone(), create_element("new")));
+        vportal.set_depth(0);
+        vportal.patch(Some(last), &ancestor);
+
+        // Assert
+        assert_eq!(old_target.text_content().unwrap(), "");
+        assert_eq!(new_target.text_content().unwrap(), "new");
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_last_portal_with_element_should_erase_subtree_from_target() {
+        // Arrange
+        let ancestor = create_ancestor();
+        let target = create_target("portal-erase");
+        let mut last = VNode::Portal(VPortal::new("#portal-erase", create_element("modal")));
+        last.set_depth(0);
+        last.patch(None, &ancestor);
+
+        // Act
+        let mut element = create_element("element");
+        element.set_depth(0);
+        element.patch(Some(last), &ancestor);
+
+        // Assert
+        assert_eq!(target.text_content().unwrap(), "");
+        assert_eq!(ancestor.text_content().unwrap(), "element");
+    }
+}
@@ -15,6 +15,8 @@ impl VText {
     /// # Example
     ///
     /// ```
+    /// use wal_core::virtual_dom::VText;
+    ///
     /// let vtext1 = VText::new(321);
     /// let vtext2 = VText::new("321");
     /// assert_eq!(vtext1, vtext2);
@@ -33,11 +35,11 @@ impl VText {
         match last {
             None => {}
             Some(VNode::Text(vtext)) => {
-                if vtext
+                let is_attached_to_ancestor = vtext
                     .dom
                     .as_ref()
-                    .is_some_and(|x| x.parent_node().is_some_and(|y| y.eq(ancestor)))
-                {
+                    .is_some_and(|x| x.parent_node().is_some_and(|y| y.eq(ancestor)));
+                if is_attached_to_ancestor {
                     self.dom = vtext.dom.clone();
                     old_virt = Some(vtext);
                 }
@@ -51,6 +53,15 @@ impl VText {
             Some(VNode::List(v)) => {
                 v.erase();
             }
+            Some(VNode::Memo(v)) => {
+                v.erase();
+            }
+            Some(VNode::Portal(v)) => {
+                v.erase();
+            }
+            Some(VNode::Head(v)) => {
+                v.erase();
+            }
         }
 
         self.render(old_virt, ancestor);
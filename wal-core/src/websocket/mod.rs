@@ -0,0 +1,655 @@
+use std::{
+    cell::{Cell, RefCell},
+    error::Error,
+    fmt,
+    rc::{Rc, Weak},
+};
+
+#[cfg(feature = "serde")]
+use std::marker::PhantomData;
+
+use gloo::timers::callback::Timeout;
+
+pub use self::transport::{
+    set_transport, BrowserTransport, Connection, ConnectionHandlers, Transport,
+};
+
+mod transport;
+
+/// Frame sent or received through the WebSocket connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Frame {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+/// Information about the closed WebSocket connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloseInfo {
+    pub code: u16,
+    pub reason: String,
+    pub was_clean: bool,
+}
+
+/// Error of the WebSocket connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebSocketError {
+    /// Connection could not be established or failed.
+    Connection(String),
+    /// Frame could not be sent, because the connection is not open.
+    NotConnected,
+    /// Frame could not be sent.
+    Send(String),
+    /// Received [Frame] could not be decoded by the [Decoder].
+    Decode(String),
+}
+
+impl fmt::Display for WebSocketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebSocketError::Connection(message) => write!(f, "Connection error: {message}"),
+            WebSocketError::NotConnected => write!(f, "Connection is not open"),
+            WebSocketError::Send(message) => write!(f, "Send error: {message}"),
+            WebSocketError::Decode(message) => write!(f, "Decode error: {message}"),
+        }
+    }
+}
+
+impl Error for WebSocketError {}
+
+/// Event of the WebSocket connection delivered to the component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebSocketEvent<T> {
+    /// Connection was opened, also after reconnecting.
+    Opened,
+    /// [Frame] was received and decoded by the [Decoder].
+    Message(T),
+    /// Connection was closed.
+    Closed(CloseInfo),
+    /// Connection is going to be reestablished after `delay_millis` milliseconds, according to the [ReconnectPolicy].
+    Reconnecting { attempt: u32, delay_millis: u32 },
+    /// Connection failed or received [Frame] could not be decoded.
+    Error(WebSocketError),
+}
+
+/// Decoder of received [frames](Frame) into typed messages.
+/// Every closure taking a [Frame] and returning a result of the decoded message is a decoder as well.
+pub trait Decoder: 'static {
+    type Output;
+
+    fn decode(&self, frame: Frame) -> Result<Self::Output, String>;
+}
+
+impl<T, F> Decoder for F
+where
+    F: Fn(Frame) -> Result<T, String> + 'static,
+{
+    type Output = T;
+
+    fn decode(&self, frame: Frame) -> Result<Self::Output, String> {
+        self(frame)
+    }
+}
+
+/// [Decoder] passing received [frames](Frame) unchanged.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FrameDecoder;
+
+impl Decoder for FrameDecoder {
+    type Output = Frame;
+
+    fn decode(&self, frame: Frame) -> Result<Self::Output, String> {
+        Ok(frame)
+    }
+}
+
+/// [Decoder] of text frames. Binary frames are decoded only if they are valid UTF-8.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextDecoder;
+
+impl Decoder for TextDecoder {
+    type Output = String;
+
+    fn decode(&self, frame: Frame) -> Result<Self::Output, String> {
+        match frame {
+            Frame::Text(text) => Ok(text),
+            Frame::Binary(bytes) => String::from_utf8(bytes).map_err(|error| error.to_string()),
+        }
+    }
+}
+
+/// [Decoder] deserializing JSON from text frames.
+#[cfg(feature = "serde")]
+pub struct JsonDecoder<T>(PhantomData<T>);
+
+#[cfg(feature = "serde")]
+impl<T> Default for JsonDecoder<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::de::DeserializeOwned + 'static> Decoder for JsonDecoder<T> {
+    type Output = T;
+
+    fn decode(&self, frame: Frame) -> Result<Self::Output, String> {
+        match frame {
+            Frame::Text(text) => serde_json::from_str(&text).map_err(|error| error.to_string()),
+            Frame::Binary(bytes) => {
+                serde_json::from_slice(&bytes).map_err(|error| error.to_string())
+            }
+        }
+    }
+}
+
+/// Policy of reestablishing closed connections. Delay of the following attempts grows exponentially by the `multiplier`,
+/// starting from `initial_delay_millis` up to `max_delay_millis`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    pub initial_delay_millis: u32,
+    pub max_delay_millis: u32,
+    pub multiplier: u32,
+    /// Maximal number of consecutive attempts, [None] means reconnecting forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay_millis: 500,
+            max_delay_millis: 30_000,
+            multiplier: 2,
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    fn get_delay_millis(&self, attempt: u32) -> Option<u32> {
+        if self
+            .max_attempts
+            .is_some_and(|max_attempts| attempt > max_attempts)
+        {
+            return None;
+        }
+        let delay = (1..attempt).fold(self.initial_delay_millis, |delay, _| {
+            delay.saturating_mul(self.multiplier)
+        });
+        Some(delay.min(self.max_delay_millis))
+    }
+}
+
+/// Options of the WebSocket connection.
+///
+/// # Examples
+///
+/// ```ignore
+/// let options = WebSocketOptions::new("wss://example.com/live")
+///     .protocol("dashboard")
+///     .reconnect(ReconnectPolicy::default());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebSocketOptions {
+    url: String,
+    protocols: Vec<String>,
+    reconnect: Option<ReconnectPolicy>,
+}
+
+impl WebSocketOptions {
+    /// Creates [WebSocketOptions] of the connection to the `url`, which is not reestablished when closed.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            protocols: Vec::new(),
+            reconnect: None,
+        }
+    }
+
+    /// Adds a subprotocol, which the server may select.
+    pub fn protocol(mut self, protocol: impl Into<String>) -> Self {
+        self.protocols.push(protocol.into());
+        self
+    }
+
+    /// Sets the [ReconnectPolicy] used when the connection is closed by the server or fails.
+    pub fn reconnect(mut self, reconnect: ReconnectPolicy) -> Self {
+        self.reconnect = Some(reconnect);
+        self
+    }
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn get_protocols(&self) -> &[String] {
+        &self.protocols
+    }
+}
+
+pub(crate) type SocketEventHandler = Rc<dyn Fn(WebSocketEvent<Frame>)>;
+
+/// WebSocket connection, which is reestablished according to the [ReconnectPolicy] and closed when dropped.
+pub(crate) struct Socket {
+    options: WebSocketOptions,
+    handler: RefCell<SocketEventHandler>,
+    connection: RefCell<Option<Box<dyn Connection>>>,
+    open: Cell<bool>,
+    attempt: Cell<u32>,
+    reconnect_timeout: RefCell<Option<Timeout>>,
+}
+
+impl Socket {
+    pub(crate) fn new(options: WebSocketOptions, handler: SocketEventHandler) -> Rc<Self> {
+        let socket = Rc::new(Self {
+            options,
+            handler: RefCell::new(handler),
+            connection: RefCell::new(None),
+            open: Cell::new(false),
+            attempt: Cell::new(0),
+            reconnect_timeout: RefCell::new(None),
+        });
+        socket.connect();
+        socket
+    }
+
+    pub(crate) fn get_options(&self) -> &WebSocketOptions {
+        &self.options
+    }
+
+    pub(crate) fn set_handler(&self, handler: SocketEventHandler) {
+        *self.handler.borrow_mut() = handler;
+    }
+
+    fn connect(self: &Rc<Self>) {
+        let handlers = self.create_connection_handlers();
+        match transport::get_transport().connect(&self.options, handlers) {
+            Ok(connection) => *self.connection.borrow_mut() = Some(connection),
+            Err(error) => {
+                self.emit(WebSocketEvent::Error(error));
+                self.schedule_reconnect();
+            }
+        }
+    }
+
+    fn create_connection_handlers(self: &Rc<Self>) -> ConnectionHandlers {
+        let on_open = Rc::downgrade(self);
+        let on_frame = Rc::downgrade(self);
+        let on_close = Rc::downgrade(self);
+        let on_error = Rc::downgrade(self);
+        ConnectionHandlers {
+            on_open: Box::new(move || {
+                with_socket(&on_open, |socket| {
+                    socket.open.set(true);
+                    socket.attempt.set(0);
+                    socket.emit(WebSocketEvent::Opened);
+                })
+            }),
+            on_frame: Box::new(move |frame| {
+                with_socket(&on_frame, |socket| {
+                    socket.emit(WebSocketEvent::Message(frame))
+                })
+            }),
+            on_close: Box::new(move |close_info| {
+                with_socket(&on_close, |socket| {
+                    socket.connection.borrow_mut().take();
+                    socket.open.set(false);
+                    socket.emit(WebSocketEvent::Closed(close_info));
+                    socket.schedule_reconnect();
+                })
+            }),
+            on_error: Box::new(move |message| {
+                with_socket(&on_error, |socket| {
+                    socket.emit(WebSocketEvent::Error(WebSocketError::Connection(message)))
+                })
+            }),
+        }
+    }
+
+    fn schedule_reconnect(self: &Rc<Self>) {
+        let Some(reconnect) = &self.options.reconnect else {
+            return;
+        };
+        let attempt = self.attempt.get() + 1;
+        let Some(delay_millis) = reconnect.get_delay_millis(attempt) else {
+            return;
+        };
+        self.attempt.set(attempt);
+
+        let socket = Rc::downgrade(self);
+        *self.reconnect_timeout.borrow_mut() = Some(Timeout::new(delay_millis, move || {
+            with_socket(&socket, |socket| socket.connect())
+        }));
+        self.emit(WebSocketEvent::Reconnecting {
+            attempt,
+            delay_millis,
+        });
+    }
+
+    fn emit(&self, event: WebSocketEvent<Frame>) {
+        let handler = self.handler.borrow().clone();
+        handler(event);
+    }
+
+    fn send(&self, frame: Frame) -> Result<(), WebSocketError> {
+        self.connection
+            .borrow()
+            .as_ref()
+            .ok_or(WebSocketError::NotConnected)?
+            .send(frame)
+    }
+
+    fn close(&self) {
+        self.reconnect_timeout.borrow_mut().take();
+        self.connection.borrow_mut().take();
+        self.open.set(false);
+    }
+
+    fn is_open(&self) -> bool {
+        self.open.get()
+    }
+}
+
+fn with_socket(socket: &Weak<Socket>, action: impl FnOnce(&Rc<Socket>)) {
+    if let Some(socket) = socket.upgrade() {
+        action(&socket);
+    }
+}
+
+/// Handle of the WebSocket connection created using [Behavior](crate::component::Behavior), which allows to send frames.
+/// Dropping the handle does not close the connection, it is closed when the component is destroyed
+/// or when the connection is not declared during the view anymore.
+#[derive(Clone)]
+pub struct WebSocketHandle(Weak<Socket>);
+
+impl WebSocketHandle {
+    pub(crate) fn new(socket: &Rc<Socket>) -> Self {
+        Self(Rc::downgrade(socket))
+    }
+
+    /// Sends the [Frame] through the connection.
+    pub fn send(&self, frame: Frame) -> Result<(), WebSocketError> {
+        self.0
+            .upgrade()
+            .ok_or(WebSocketError::NotConnected)?
+            .send(frame)
+    }
+
+    /// Sends the text frame through the connection.
+    pub fn send_text(&self, text: impl Into<String>) -> Result<(), WebSocketError> {
+        self.send(Frame::Text(text.into()))
+    }
+
+    /// Sends the binary frame through the connection.
+    pub fn send_binary(&self, bytes: impl Into<Vec<u8>>) -> Result<(), WebSocketError> {
+        self.send(Frame::Binary(bytes.into()))
+    }
+
+    /// Serializes the `value` to JSON and sends it as the text frame through the connection.
+    #[cfg(feature = "serde")]
+    pub fn send_json<T: serde::Serialize + ?Sized>(&self, value: &T) -> Result<(), WebSocketError> {
+        let text = serde_json::to_string(value)
+            .map_err(|error| WebSocketError::Send(error.to_string()))?;
+        self.send_text(text)
+    }
+
+    /// Closes the connection. It is not reestablished.
+    pub fn close(&self) {
+        if let Some(socket) = self.0.upgrade() {
+            socket.close();
+        }
+    }
+
+    /// Checks whether the connection is established and not closed. Connection which is still being established is not open.
+    pub fn is_open(&self) -> bool {
+        self.0.upgrade().is_some_and(|socket| socket.is_open())
+    }
+}
+
+/// Decodes received frames of the [Socket] using the `decoder`.
+pub(crate) fn decode<D: Decoder>(
+    decoder: &D,
+    event: WebSocketEvent<Frame>,
+) -> WebSocketEvent<D::Output> {
+    match event {
+        WebSocketEvent::Opened => WebSocketEvent::Opened,
+        WebSocketEvent::Message(frame) => match decoder.decode(frame) {
+            Ok(message) => WebSocketEvent::Message(message),
+            Err(error) => WebSocketEvent::Error(WebSocketError::Decode(error)),
+        },
+        WebSocketEvent::Closed(close_info) => WebSocketEvent::Closed(close_info),
+        WebSocketEvent::Reconnecting {
+            attempt,
+            delay_millis,
+        } => WebSocketEvent::Reconnecting {
+            attempt,
+            delay_millis,
+        },
+        WebSocketEvent::Error(error) => WebSocketEvent::Error(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    /// In-process fake of the server, which records sent frames and exposes handlers of the last connection.
+    #[derive(Clone, Default)]
+    struct FakeServer {
+        sent: Rc<RefCell<Vec<Frame>>>,
+        handlers: Rc<RefCell<Option<ConnectionHandlers>>>,
+        connections: Rc<Cell<u32>>,
+    }
+
+    struct FakeConnection(Rc<RefCell<Vec<Frame>>>);
+
+    impl Connection for FakeConnection {
+        fn send(&self, frame: Frame) -> Result<(), WebSocketError> {
+            self.0.borrow_mut().push(frame);
+            Ok(())
+        }
+    }
+
+    impl Transport for FakeServer {
+        fn connect(
+            &self,
+            _options: &WebSocketOptions,
+            handlers: ConnectionHandlers,
+        ) -> Result<Box<dyn Connection>, WebSocketError> {
+            self.connections.set(self.connections.get() + 1);
+            *self.handlers.borrow_mut() = Some(handlers);
+            Ok(Box::new(FakeConnection(self.sent.clone())))
+        }
+    }
+
+    impl FakeServer {
+        fn install() -> Self {
+            let server = FakeServer::default();
+            set_transport(server.clone());
+            server
+        }
+
+        fn with_handlers(&self, action: impl FnOnce(&ConnectionHandlers)) {
+            action(self.handlers.borrow().as_ref().unwrap());
+        }
+    }
+
+    type ReceivedEvents = Rc<RefCell<Vec<WebSocketEvent<Frame>>>>;
+
+    fn create_socket(options: WebSocketOptions) -> (Rc<Socket>, ReceivedEvents) {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let events_clone = events.clone();
+        let socket = Socket::new(
+            options,
+            Rc::new(move |event| events_clone.borrow_mut().push(event)),
+        );
+        (socket, events)
+    }
+
+    #[wasm_bindgen_test]
+    fn socket_should_emit_opened_and_received_frames() {
+        // Arrange
+        let server = FakeServer::install();
+        let (_socket, events) = create_socket(WebSocketOptions::new("ws://test"));
+
+        // Act
+        server.with_handlers(|handlers| {
+            (handlers.on_open)();
+            (handlers.on_frame)(Frame::Text("hello".to_string()));
+        });
+
+        // Assert
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                WebSocketEvent::Opened,
+                WebSocketEvent::Message(Frame::Text("hello".to_string()))
+            ]
+        );
+        set_transport(BrowserTransport);
+    }
+
+    #[wasm_bindgen_test]
+    fn handle_should_send_frames_through_connection() {
+        // Arrange
+        let server = FakeServer::install();
+        let (socket, _events) = create_socket(WebSocketOptions::new("ws://test"));
+        let handle = WebSocketHandle::new(&socket);
+
+        // Act
+        let result = handle.send_text("ping");
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(*server.sent.borrow(), vec![Frame::Text("ping".to_string())]);
+        set_transport(BrowserTransport);
+    }
+
+    #[wasm_bindgen_test]
+    fn closed_socket_with_reconnect_policy_should_schedule_reconnect() {
+        // Arrange
+        let server = FakeServer::install();
+        let options = WebSocketOptions::new("ws://test").reconnect(ReconnectPolicy::default());
+        let (socket, events) = create_socket(options);
+        let close_info = CloseInfo {
+            code: 1006,
+            reason: String::new(),
+            was_clean: false,
+        };
+
+        // Act
+        server.with_handlers(|handlers| (handlers.on_close)(close_info.clone()));
+
+        // Assert
+        assert!(!socket.is_open());
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                WebSocketEvent::Closed(close_info),
+                WebSocketEvent::Reconnecting {
+                    attempt: 1,
+                    delay_millis: 500
+                }
+            ]
+        );
+        set_transport(BrowserTransport);
+    }
+
+    #[wasm_bindgen_test]
+    fn socket_should_be_open_only_after_connection_is_established() {
+        // Arrange
+        let server = FakeServer::install();
+        let (socket, _events) = create_socket(WebSocketOptions::new("ws://test"));
+        let connecting = socket.is_open();
+
+        // Act
+        server.with_handlers(|handlers| (handlers.on_open)());
+
+        // Assert
+        assert!(!connecting);
+        assert!(socket.is_open());
+        set_transport(BrowserTransport);
+    }
+
+    struct FailingTransport;
+
+    impl Transport for FailingTransport {
+        fn connect(
+            &self,
+            _options: &WebSocketOptions,
+            _handlers: ConnectionHandlers,
+        ) -> Result<Box<dyn Connection>, WebSocketError> {
+            Err(WebSocketError::Connection("invalid url".to_string()))
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn failed_connect_with_reconnect_policy_should_schedule_reconnect() {
+        // Arrange
+        set_transport(FailingTransport);
+        let options = WebSocketOptions::new("ws://test").reconnect(ReconnectPolicy::default());
+
+        // Act
+        let (socket, events) = create_socket(options);
+
+        // Assert
+        assert!(!socket.is_open());
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                WebSocketEvent::Error(WebSocketError::Connection("invalid url".to_string())),
+                WebSocketEvent::Reconnecting {
+                    attempt: 1,
+                    delay_millis: 500
+                }
+            ]
+        );
+        set_transport(BrowserTransport);
+    }
+
+    #[wasm_bindgen_test]
+    fn reconnect_policy_delay_should_grow_up_to_max_delay() {
+        // Arrange
+        let policy = ReconnectPolicy {
+            max_attempts: Some(5),
+            max_delay_millis: 5000,
+            ..Default::default()
+        };
+
+        // Act
+        let delays: Vec<_> = (1..=6).map(|x| policy.get_delay_millis(x)).collect();
+
+        // Assert
+        assert_eq!(
+            delays,
+            vec![
+                Some(500),
+                Some(1000),
+                Some(2000),
+                Some(4000),
+                Some(5000),
+                None
+            ]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn decode_should_map_decoding_failure_to_error() {
+        // Act
+        let event = decode(
+            &TextDecoder,
+            WebSocketEvent::Message(Frame::Binary(vec![0xff])),
+        );
+
+        // Assert
+        assert!(matches!(
+            event,
+            WebSocketEvent::Error(WebSocketError::Decode(_))
+        ));
+    }
+}
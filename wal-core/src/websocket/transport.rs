@@ -0,0 +1,132 @@
+use std::{cell::RefCell, rc::Rc};
+
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::{BinaryType, CloseEvent, MessageEvent, WebSocket};
+
+use super::{CloseInfo, Frame, WebSocketError, WebSocketOptions};
+
+/// Handlers of the events of the [Connection] called by the [Transport].
+pub struct ConnectionHandlers {
+    pub on_open: Box<dyn Fn()>,
+    pub on_frame: Box<dyn Fn(Frame)>,
+    pub on_close: Box<dyn Fn(CloseInfo)>,
+    pub on_error: Box<dyn Fn(String)>,
+}
+
+/// Established WebSocket connection. Dropping the connection closes it, after which its handlers are not called anymore.
+pub trait Connection {
+    fn send(&self, frame: Frame) -> Result<(), WebSocketError>;
+}
+
+/// Transport establishes WebSocket connections. By default [BrowserTransport] is used.
+/// It can be replaced with [set_transport], e.g. by an in-process fake in tests.
+pub trait Transport {
+    /// Establishes the connection described by `options`. Handlers must not be called before this function returns.
+    fn connect(
+        &self,
+        options: &WebSocketOptions,
+        handlers: ConnectionHandlers,
+    ) -> Result<Box<dyn Connection>, WebSocketError>;
+}
+
+/// [Transport] using [WebSocket] of the browser.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BrowserTransport;
+
+impl Transport for BrowserTransport {
+    fn connect(
+        &self,
+        options: &WebSocketOptions,
+        handlers: ConnectionHandlers,
+    ) -> Result<Box<dyn Connection>, WebSocketError> {
+        let protocols = options
+            .get_protocols()
+            .iter()
+            .map(|x| wasm_bindgen::JsValue::from_str(x))
+            .collect::<js_sys::Array>();
+        let socket = WebSocket::new_with_str_sequence(options.get_url(), &protocols)
+            .map_err(|error| WebSocketError::Connection(format!("{error:?}")))?;
+        socket.set_binary_type(BinaryType::Arraybuffer);
+
+        let ConnectionHandlers {
+            on_open,
+            on_frame,
+            on_close,
+            on_error,
+        } = handlers;
+        let listeners = [
+            EventListener::new(&socket, "open", move |_| on_open()),
+            EventListener::new(&socket, "message", move |event| {
+                let event = event.unchecked_ref::<MessageEvent>();
+                if let Some(frame) = get_frame(event) {
+                    on_frame(frame);
+                }
+            }),
+            EventListener::new(&socket, "close", move |event| {
+                let event = event.unchecked_ref::<CloseEvent>();
+                on_close(CloseInfo {
+                    code: event.code(),
+                    reason: event.reason(),
+                    was_clean: event.was_clean(),
+                });
+            }),
+            EventListener::new(&socket, "error", move |_| {
+                on_error("WebSocket connection failed".to_string())
+            }),
+        ];
+
+        Ok(Box::new(BrowserConnection {
+            socket,
+            _listeners: listeners,
+        }))
+    }
+}
+
+struct BrowserConnection {
+    socket: WebSocket,
+    _listeners: [EventListener; 4],
+}
+
+impl Connection for BrowserConnection {
+    fn send(&self, frame: Frame) -> Result<(), WebSocketError> {
+        if self.socket.ready_state() != WebSocket::OPEN {
+            return Err(WebSocketError::NotConnected);
+        }
+        match frame {
+            Frame::Text(text) => self.socket.send_with_str(&text),
+            Frame::Binary(bytes) => self.socket.send_with_u8_array(&bytes),
+        }
+        .map_err(|error| WebSocketError::Send(format!("{error:?}")))
+    }
+}
+
+impl Drop for BrowserConnection {
+    fn drop(&mut self) {
+        let _ = self.socket.close();
+    }
+}
+
+fn get_frame(event: &MessageEvent) -> Option<Frame> {
+    let data = event.data();
+    if let Some(text) = data.as_string() {
+        Some(Frame::Text(text))
+    } else {
+        data.dyn_into::<js_sys::ArrayBuffer>()
+            .ok()
+            .map(|buffer| Frame::Binary(js_sys::Uint8Array::new(&buffer).to_vec()))
+    }
+}
+
+thread_local! {
+    static TRANSPORT: RefCell<Rc<dyn Transport>> = RefCell::new(Rc::new(BrowserTransport));
+}
+
+/// Replaces the [Transport] used to establish all of the following connections.
+pub fn set_transport(transport: impl Transport + 'static) {
+    TRANSPORT.with(|x| *x.borrow_mut() = Rc::new(transport));
+}
+
+pub(crate) fn get_transport() -> Rc<dyn Transport> {
+    TRANSPORT.with(|x| x.borrow().clone())
+}
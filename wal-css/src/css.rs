@@ -10,19 +10,17 @@ use web_sys::Element;
 /// the name of the selector as an argument.
 ///
 /// # Example usage
-/// ```
-/// use wal_css::css:Css;
+/// ```no_run
+/// use wal_css::css::Css;
 /// use wal_css::css_stylesheet;
 ///
 /// thread_local! {
-///     static CSS: Css = css_stylesheet!("path-to-css-file");
+///     static CSS: Css = css_stylesheet!("path-to-css-file.css");
 /// }
 /// // ...
 /// CSS.with(|css| {
-///     rsx! {
-///         <div class={format!("{} {}", css["class1"], css["class2"])} />
-///     }
-/// })
+///     let class = format!("{} {}", css["class1"], css["class2"]);
+/// });
 /// ```
 pub struct Css {
     stylesheet_id: u8,
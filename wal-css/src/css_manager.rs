@@ -19,10 +19,10 @@ thread_local! {
 /// inside the [rsx macro](../../wal_rsx/macro.rsx.html).
 ///
 /// # Example usage
-/// ```
+/// ```no_run
 /// use wal_css::css_manager::CssManager;
 ///
-/// let manager = CssManager::new();
+/// let mut manager = CssManager::new();
 /// manager.attach_css(".class1 { background-color: red; }");
 /// ```
 pub struct CssManager {
@@ -34,7 +34,7 @@ impl CssManager {
     /// Creates new instance of CssManager struct.
     ///
     /// # Example usage
-    /// ```
+    /// ```no_run
     /// use wal_css::css_manager::CssManager;
     /// let manager = CssManager::new();
     /// ```
@@ -50,10 +50,10 @@ impl CssManager {
     /// inside the [rsx macro](../../wal_rsx/macro.rsx.html).
     ///
     /// # Example usage
-    /// ```
+    /// ```no_run
     /// use wal_css::css_manager::CssManager;
     ///
-    /// let manager = CssManager::new();
+    /// let mut manager = CssManager::new();
     /// manager.attach_css(".class1 { background-color: red; }");
     /// ```
     pub fn attach_css(&mut self, css: &str) -> Css {
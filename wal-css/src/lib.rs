@@ -14,19 +14,17 @@ mod parser;
 /// inside the [rsx macro](../wal_rsx/macro.rsx.html)
 ///  
 /// # Example usage
-/// ```
-/// use wal_css::css:Css;
+/// ```no_run
+/// use wal_css::css::Css;
 /// use wal_css::css_stylesheet;
 ///
 /// thread_local! {
-///     static CSS: Css = css_stylesheet!("path-to-css-file");
+///     static CSS: Css = css_stylesheet!("path-to-css-file.css");
 /// }
 /// // ...
 /// CSS.with(|css| {
-///     rsx! {
-///         <div class={format!("{} {}", css["class1"], css["class2"])} />
-///     }
-/// })
+///     let class = format!("{} {}", css["class1"], css["class2"]);
+/// });
 /// ```
 ///
 #[macro_export]
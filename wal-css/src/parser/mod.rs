@@ -9,7 +9,12 @@ mod types;
 /// generates css with prefixed selectors and stylesheet selector mapping
 pub(crate) fn process_css(input: &str, prefix: &str) -> (String, HashMap<String, String>) {
     let cleared_input = clear_css(input);
-    let (_, stylesheet) = parse_stylesheet(&cleared_input).unwrap();
+    let (rest, stylesheet) = parse_stylesheet(&cleared_input).unwrap();
+    assert!(
+        rest.trim().is_empty(),
+        "Could not parse css starting at: {}",
+        rest.trim()
+    );
 
     let mapping: HashMap<String, String> = stylesheet.gen_mapping(prefix).into_iter().collect();
     let updated_css = stylesheet.gen_css(prefix);
@@ -46,4 +51,50 @@ mod tests {
 
         assert_eq!((expected_css, expected_mapping), process_css(input, prefix));
     }
+    #[test]
+    fn process_css_prefixes_only_classes_and_ids_of_complex_selectors() {
+        let input = ".list > li:not(.active)::before, a[href].link:hover { color: red; }";
+        let prefix = "test-";
+
+        let mut expected_mapping = HashMap::<String, String>::new();
+        expected_mapping.insert("list".to_owned(), "test-list".to_owned());
+        expected_mapping.insert("active".to_owned(), "test-active".to_owned());
+        expected_mapping.insert("link".to_owned(), "test-link".to_owned());
+
+        let expected_css =
+            ".test-list > li:not(.test-active)::before, a[href].test-link:hover { color: red; }"
+                .to_owned();
+
+        assert_eq!((expected_css, expected_mapping), process_css(input, prefix));
+    }
+    #[test]
+    fn process_css_keeps_sections_after_attribute_selector_with_quoted_bracket() {
+        let input = ".a { x: y; } [title=\"]\"] { x: y; } .b { x: y; }";
+        let prefix = "test-";
+
+        let mut expected_mapping = HashMap::<String, String>::new();
+        expected_mapping.insert("a".to_owned(), "test-a".to_owned());
+        expected_mapping.insert("b".to_owned(), "test-b".to_owned());
+
+        let expected_css = ".test-a { x: y; } [title=\"]\"] { x: y; } .test-b { x: y; }".to_owned();
+
+        assert_eq!((expected_css, expected_mapping), process_css(input, prefix));
+    }
+    #[test]
+    fn process_css_maps_unescaped_names_of_escaped_classes() {
+        let input = ".sm\\:flex { display: flex; }";
+        let prefix = "test-";
+
+        let mut expected_mapping = HashMap::<String, String>::new();
+        expected_mapping.insert("sm:flex".to_owned(), "test-sm:flex".to_owned());
+
+        let expected_css = ".test-sm\\:flex { display: flex; }".to_owned();
+
+        assert_eq!((expected_css, expected_mapping), process_css(input, prefix));
+    }
+    #[test]
+    #[should_panic]
+    fn process_css_panics_if_css_could_not_be_parsed_entirely() {
+        process_css(".a { x: y; } .b { x: y;", "test-");
+    }
 }
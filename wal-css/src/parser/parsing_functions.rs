@@ -1,50 +1,136 @@
 use nom::branch::alt;
-use nom::bytes::complete::{tag, take_till, take_till1, take_until};
-use nom::character::complete::{multispace0, multispace1};
+use nom::bytes::complete::{tag, take_till, take_until};
+use nom::character::complete::{multispace0, multispace1, one_of};
+use nom::combinator::{all_consuming, opt};
 use nom::error::{Error, ErrorKind, ParseError};
-use nom::multi::{separated_list0, separated_list1};
-use nom::sequence::{delimited, separated_pair, tuple};
+use nom::multi::{many0, many1, separated_list0, separated_list1};
+use nom::sequence::{delimited, preceded, separated_pair, terminated, tuple};
 use nom::{combinator::map, sequence::pair, Err, IResult};
 
 use super::types::*;
 
 /// parses css string into Stylesheet object
-pub fn parse_stylesheet(i: &str) -> IResult<&str, Stylesheet> {
+pub fn parse_stylesheet(i: &str) -> IResult<&str, Stylesheet<'_>> {
     map(separated_list0(multispace1, p_section), Stylesheet::new)(i)
 }
 
-fn p_id(i: &str) -> IResult<&str, Selector> {
+fn p_id(i: &str) -> IResult<&str, Selector<'_>> {
+    map(pair(tag("#"), p_ident), |(_, ident)| Selector::Id(ident))(i)
+}
+
+fn p_class(i: &str) -> IResult<&str, Selector<'_>> {
+    map(pair(tag("."), p_ident), |(_, ident)| Selector::Class(ident))(i)
+}
+
+fn p_element(i: &str) -> IResult<&str, Selector<'_>> {
+    map(alt((tag("*"), tag("&"), p_ident)), Selector::Element)(i)
+}
+
+fn p_attribute(i: &str) -> IResult<&str, Selector<'_>> {
+    map(
+        delimited(tag("["), p_until_unbalanced('[', ']'), tag("]")),
+        Selector::Attribute,
+    )(i)
+}
+
+fn p_pseudo_class(i: &str) -> IResult<&str, Selector<'_>> {
+    map(
+        pair(preceded(tag(":"), p_ident), opt(p_pseudo_arguments)),
+        |(name, arguments)| Selector::PseudoClass {
+            name,
+            arguments: arguments.map(|a| to_pseudo_arguments(name, a)),
+        },
+    )(i)
+}
+
+fn p_pseudo_element(i: &str) -> IResult<&str, Selector<'_>> {
     map(
-        pair(tag("#"), take_till1(is_ident_terminator)),
-        |(_, ident)| Selector::Id(ident),
+        pair(preceded(tag("::"), p_ident), opt(p_pseudo_arguments)),
+        |(name, arguments)| Selector::PseudoElement {
+            name,
+            arguments: arguments.map(|a| to_pseudo_arguments(name, a)),
+        },
     )(i)
 }
 
-fn p_class(i: &str) -> IResult<&str, Selector> {
+fn p_pseudo_arguments(i: &str) -> IResult<&str, &str> {
+    delimited(tag("("), p_until_unbalanced('(', ')'), tag(")"))(i)
+}
+
+/// parses arguments of pseudo-classes taking selectors, so their classes and ids are prefixed too
+fn to_pseudo_arguments<'a>(name: &str, arguments: &'a str) -> PseudoArguments<'a> {
+    if takes_selector_arguments(name) {
+        if let Ok((_, selectors)) = all_consuming(p_selector_list)(arguments.trim()) {
+            return PseudoArguments::Selectors(selectors);
+        }
+    }
+    PseudoArguments::Literal(arguments)
+}
+
+fn p_selector(i: &str) -> IResult<&str, Selector<'_>> {
     map(
-        pair(tag("."), take_till1(is_ident_terminator)),
-        |(_, ident)| Selector::Class(ident),
+        alt((
+            p_class,
+            p_id,
+            p_attribute,
+            p_pseudo_element,
+            p_pseudo_class,
+            p_element,
+        )),
+        |s| s,
     )(i)
 }
 
-fn p_element(i: &str) -> IResult<&str, Selector> {
-    map(take_till1(is_ident_terminator), |ident| {
-        Selector::Element(ident)
+fn p_explicit_combinator(i: &str) -> IResult<&str, Combinator> {
+    map(one_of(">+~"), |c| match c {
+        '>' => Combinator::Child,
+        '+' => Combinator::NextSibling,
+        _ => Combinator::SubsequentSibling,
     })(i)
 }
 
-fn p_selector(i: &str) -> IResult<&str, Selector> {
-    map(alt((p_class, p_id, p_element)), |s| s)(i)
+fn p_combinator(i: &str) -> IResult<&str, Combinator> {
+    alt((
+        delimited(multispace0, p_explicit_combinator, multispace0),
+        map(multispace1, |_| Combinator::Descendant),
+    ))(i)
 }
 
-fn p_complex_selector(i: &str) -> IResult<&str, Instruction> {
+fn p_complex_selector(i: &str) -> IResult<&str, ComplexSelector<'_>> {
     map(
-        separated_list1(tuple((multispace0, tag(","), multispace0)), p_selector),
-        Instruction::ComplexSelector,
+        tuple((
+            // relative selectors, ex. in `:has(> img)`, start with a combinator
+            opt(terminated(p_explicit_combinator, multispace0)),
+            many1(p_selector),
+            many0(pair(p_combinator, many1(p_selector))),
+        )),
+        |(leading_combinator, compound, rest)| {
+            let mut selectors: Vec<Selector> = leading_combinator
+                .map(Selector::Combinator)
+                .into_iter()
+                .collect();
+            selectors.extend(compound);
+            for (combinator, compound) in rest {
+                selectors.push(Selector::Combinator(combinator));
+                selectors.extend(compound);
+            }
+            ComplexSelector::new(selectors)
+        },
+    )(i)
+}
+
+fn p_selector_list(i: &str) -> IResult<&str, Vec<ComplexSelector<'_>>> {
+    separated_list1(
+        tuple((multispace0, tag(","), multispace0)),
+        p_complex_selector,
     )(i)
 }
 
-fn p_special_instruction(i: &str) -> IResult<&str, Instruction> {
+fn p_selector_instruction(i: &str) -> IResult<&str, Instruction<'_>> {
+    map(p_selector_list, Instruction::ComplexSelector)(i)
+}
+
+fn p_special_instruction(i: &str) -> IResult<&str, Instruction<'_>> {
     map(
         pair(
             pair(tag("@"), take_until(" ")),      // ex @media
@@ -57,8 +143,8 @@ fn p_special_instruction(i: &str) -> IResult<&str, Instruction> {
     )(i)
 }
 
-fn p_instruction(i: &str) -> IResult<&str, Instruction> {
-    map(alt((p_complex_selector, p_special_instruction)), |s| s)(i)
+fn p_instruction(i: &str) -> IResult<&str, Instruction<'_>> {
+    map(alt((p_selector_instruction, p_special_instruction)), |s| s)(i)
 }
 
 fn p_body(i: &str) -> IResult<&str, &str> {
@@ -68,7 +154,7 @@ fn p_body(i: &str) -> IResult<&str, &str> {
     )(i)
 }
 
-fn p_body_section(i: &str) -> IResult<&str, Section> {
+fn p_body_section(i: &str) -> IResult<&str, Section<'_>> {
     let (remainig_input, (instruction, body_str)) =
         map(separated_pair(p_instruction, multispace0, p_body), |r| r)(i)?;
 
@@ -95,49 +181,75 @@ fn p_body_section(i: &str) -> IResult<&str, Section> {
     ))
 }
 
-fn p_bodyless_section(i: &str) -> IResult<&str, Section> {
+fn p_bodyless_section(i: &str) -> IResult<&str, Section<'_>> {
     map(pair(p_instruction, tag(";")), |(instr, _)| {
         Section::WithoutBody(instr)
     })(i)
 }
 
-fn p_section(i: &str) -> IResult<&str, Section> {
+fn p_section(i: &str) -> IResult<&str, Section<'_>> {
     map(alt((p_body_section, p_bodyless_section)), |s| s)(i)
 }
-/// used to parse self nested expression delimited with brackets
+/// parses an identifier, ex. name of a class, in which characters escaped with `\` are never terminators
+fn p_ident(i: &str) -> IResult<&str, &str> {
+    let mut chars = i.char_indices();
+    let mut end = 0;
+    while let Some((index, c)) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some((escaped_index, escaped)) => end = escaped_index + escaped.len_utf8(),
+                None => break,
+            }
+        } else if is_ident_terminator(c) {
+            break;
+        } else {
+            end = index + c.len_utf8();
+        }
+    }
+
+    if end == 0 {
+        Err(Err::Error(Error::from_error_kind(i, ErrorKind::TakeTill1)))
+    } else {
+        Ok((&i[end..], &i[..end]))
+    }
+}
+
+/// used to parse self nested expression delimited with brackets, ignoring brackets inside quoted strings
 pub fn p_until_unbalanced(
     opening_bracket: char,
     closing_bracket: char,
 ) -> impl Fn(&str) -> IResult<&str, &str> {
     move |i: &str| {
-        let mut index = 0;
+        let mut chars = i.char_indices();
         let mut bracket_counter = 0;
-        while let Some(n) = &i[index..].find(&[opening_bracket, closing_bracket][..]) {
-            index += n;
-            let mut it = i[index..].chars();
-            match it.next().unwrap_or_default() {
-                // Openieng bracket
-                c if c == opening_bracket => {
-                    bracket_counter += 1;
-                    index += opening_bracket.len_utf8();
+        let mut quote = None;
+        while let Some((index, c)) = chars.next() {
+            match (quote, c) {
+                // Escaped character, either in a quoted string or not, is never a bracket nor a quote
+                (_, '\\') => {
+                    chars.next();
                 }
+                // Closing quote
+                (Some(q), c) if c == q => quote = None,
+                // Any other character of a quoted string
+                (Some(_), _) => {}
+                // Opening quote
+                (None, '"' | '\'') => quote = Some(c),
+                // Opening bracket
+                (None, c) if c == opening_bracket => bracket_counter += 1,
                 // Closing bracket
-                c if c == closing_bracket => {
+                (None, c) if c == closing_bracket => {
                     bracket_counter -= 1;
-                    index += closing_bracket.len_utf8();
+                    // We found the unmatched closing bracket, which we do not consume.
+                    if bracket_counter == -1 {
+                        return Ok((&i[index..], &i[0..index]));
+                    }
                 }
-                // Can not happen.
-                _ => unreachable!(),
-            };
-            // We found the unmatched closing bracket.
-            if bracket_counter == -1 {
-                // We do not consume it.
-                index -= closing_bracket.len_utf8();
-                return Ok((&i[index..], &i[0..index]));
-            };
+                (None, _) => {}
+            }
         }
 
-        if bracket_counter == 0 {
+        if bracket_counter == 0 && quote.is_none() {
             Ok(("", i))
         } else {
             Err(Err::Error(Error::from_error_kind(i, ErrorKind::TakeUntil)))
@@ -146,10 +258,25 @@ pub fn p_until_unbalanced(
 }
 
 fn is_ident_terminator(c: char) -> bool {
-    let terminators = " \t\n\r.#,$@%^&*(){}[]<>";
+    let terminators = " \t\n\r.#,:;$@%^&*(){}[]<>+~=|!/\"'";
     terminators.contains(c)
 }
 
+fn takes_selector_arguments(pseudo_class: &str) -> bool {
+    let pseudo_classes = [
+        "not",
+        "is",
+        "where",
+        "has",
+        "matches",
+        "any",
+        "slotted",
+        "host",
+        "host-context",
+    ];
+    pseudo_classes.contains(&pseudo_class)
+}
+
 fn is_instruction_terminator(c: char) -> bool {
     let terminators = ";{}";
     terminators.contains(c)
@@ -200,15 +327,175 @@ mod tests {
         assert_eq!(class, Selector::Element("body"))
     }
     #[test]
-    fn parses_complex_selector() {
-        let (rest, complex_selector) = p_complex_selector(".class, body , #id").unwrap();
+    fn parses_selector_instruction() {
+        let (rest, complex_selector) = p_selector_instruction(".class, body , #id").unwrap();
         assert_eq!(rest, "");
         assert_eq!(
             complex_selector,
             Instruction::ComplexSelector(vec![
-                Selector::Class("class"),
-                Selector::Element("body"),
-                Selector::Id("id")
+                ComplexSelector::new(vec![Selector::Class("class")]),
+                ComplexSelector::new(vec![Selector::Element("body")]),
+                ComplexSelector::new(vec![Selector::Id("id")]),
+            ])
+        )
+    }
+    #[test]
+    fn parses_class_with_pseudo_class() {
+        let (rest, complex_selector) = p_complex_selector(".btn:hover").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            complex_selector,
+            ComplexSelector::new(vec![
+                Selector::Class("btn"),
+                Selector::PseudoClass {
+                    name: "hover",
+                    arguments: None
+                },
+            ])
+        )
+    }
+    #[test]
+    fn parses_pseudo_class_with_selector_arguments() {
+        let (rest, complex_selector) = p_complex_selector("li:not(.active, #first)").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            complex_selector,
+            ComplexSelector::new(vec![
+                Selector::Element("li"),
+                Selector::PseudoClass {
+                    name: "not",
+                    arguments: Some(PseudoArguments::Selectors(vec![
+                        ComplexSelector::new(vec![Selector::Class("active")]),
+                        ComplexSelector::new(vec![Selector::Id("first")]),
+                    ]))
+                },
+            ])
+        )
+    }
+    #[test]
+    fn parses_pseudo_class_with_relative_selector_arguments() {
+        let (rest, complex_selector) = p_complex_selector(".card:has(> img)").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            complex_selector,
+            ComplexSelector::new(vec![
+                Selector::Class("card"),
+                Selector::PseudoClass {
+                    name: "has",
+                    arguments: Some(PseudoArguments::Selectors(vec![ComplexSelector::new(
+                        vec![
+                            Selector::Combinator(Combinator::Child),
+                            Selector::Element("img"),
+                        ]
+                    )]))
+                },
+            ])
+        )
+    }
+    #[test]
+    fn parses_pseudo_class_with_literal_arguments() {
+        let (rest, complex_selector) = p_complex_selector(".item:nth-child(2n + 1)").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            complex_selector,
+            ComplexSelector::new(vec![
+                Selector::Class("item"),
+                Selector::PseudoClass {
+                    name: "nth-child",
+                    arguments: Some(PseudoArguments::Literal("2n + 1"))
+                },
+            ])
+        )
+    }
+    #[test]
+    fn parses_pseudo_element() {
+        let (rest, complex_selector) = p_complex_selector(".quote::before").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            complex_selector,
+            ComplexSelector::new(vec![
+                Selector::Class("quote"),
+                Selector::PseudoElement {
+                    name: "before",
+                    arguments: None
+                },
+            ])
+        )
+    }
+    #[test]
+    fn parses_attribute_selector() {
+        let (rest, complex_selector) = p_complex_selector("a[href^=\"https\"].link").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            complex_selector,
+            ComplexSelector::new(vec![
+                Selector::Element("a"),
+                Selector::Attribute("href^=\"https\""),
+                Selector::Class("link"),
+            ])
+        )
+    }
+    #[test]
+    fn parses_attribute_selector_with_quoted_bracket() {
+        let (rest, complex_selector) = p_complex_selector("[title=\"]\"].link").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            complex_selector,
+            ComplexSelector::new(vec![
+                Selector::Attribute("title=\"]\""),
+                Selector::Class("link"),
+            ])
+        )
+    }
+    #[test]
+    fn parses_pseudo_class_with_quoted_parenthesis_in_arguments() {
+        let (rest, complex_selector) = p_complex_selector("li:not([data-x=\")\"])").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            complex_selector,
+            ComplexSelector::new(vec![
+                Selector::Element("li"),
+                Selector::PseudoClass {
+                    name: "not",
+                    arguments: Some(PseudoArguments::Selectors(vec![ComplexSelector::new(
+                        vec![Selector::Attribute("data-x=\")\"")]
+                    )]))
+                },
+            ])
+        )
+    }
+    #[test]
+    fn parses_class_with_escaped_characters() {
+        let (rest, complex_selector) = p_complex_selector(".sm\\:flex:hover").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            complex_selector,
+            ComplexSelector::new(vec![
+                Selector::Class("sm\\:flex"),
+                Selector::PseudoClass {
+                    name: "hover",
+                    arguments: None
+                },
+            ])
+        )
+    }
+    #[test]
+    fn parses_complex_selector_with_combinators() {
+        let (rest, complex_selector) =
+            p_complex_selector(".list > .item .link+.icon ~ * { }").unwrap();
+        assert_eq!(rest, " { }");
+        assert_eq!(
+            complex_selector,
+            ComplexSelector::new(vec![
+                Selector::Class("list"),
+                Selector::Combinator(Combinator::Child),
+                Selector::Class("item"),
+                Selector::Combinator(Combinator::Descendant),
+                Selector::Class("link"),
+                Selector::Combinator(Combinator::NextSibling),
+                Selector::Class("icon"),
+                Selector::Combinator(Combinator::SubsequentSibling),
+                Selector::Element("*"),
             ])
         )
     }
@@ -250,6 +537,12 @@ mod tests {
         assert_eq!(body, " color: red; &:hover { color: green } ")
     }
     #[test]
+    fn parses_body_with_quoted_brackets() {
+        let (rest, body) = p_body("{ content: \"}\"; quotes: '{' '}'; } .rest").unwrap();
+        assert_eq!(rest, " .rest");
+        assert_eq!(body, " content: \"}\"; quotes: '{' '}'; ")
+    }
+    #[test]
     fn parses_nested_body_leaving_rest() {
         let (rest, body) = p_body("{ color: red; &:hover { color: green; } } }").unwrap();
         assert_eq!(rest, " }");
@@ -262,7 +555,9 @@ mod tests {
         assert_eq!(
             section,
             Section::WithBody {
-                instruction: Instruction::ComplexSelector(vec![Selector::Class("class")]),
+                instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                    Selector::Class("class")
+                ])]),
                 body: Body::LiteralBody(" color: green; ")
             }
         )
@@ -280,7 +575,9 @@ mod tests {
                     parameters: " (hover: hover) "
                 },
                 body: Body::ParsedBody(Stylesheet::new(vec![Section::WithBody {
-                    instruction: Instruction::ComplexSelector(vec![Selector::Class("class")]),
+                    instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                        Selector::Class("class")
+                    ])]),
                     body: Body::LiteralBody(" color: green; ")
                 }]))
             }
@@ -306,7 +603,9 @@ mod tests {
         assert_eq!(
             section,
             Section::WithBody {
-                instruction: Instruction::ComplexSelector(vec![Selector::Class("class")]),
+                instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                    Selector::Class("class")
+                ])]),
                 body: Body::LiteralBody(" color: green; "),
             }
         )
@@ -320,11 +619,15 @@ mod tests {
             stylesheet,
             Stylesheet::new(vec![
                 Section::WithBody {
-                    instruction: Instruction::ComplexSelector(vec![Selector::Class("class1")]),
+                    instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                        Selector::Class("class1")
+                    ])]),
                     body: Body::LiteralBody(" color: red; "),
                 },
                 Section::WithBody {
-                    instruction: Instruction::ComplexSelector(vec![Selector::Class("class2")]),
+                    instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                        Selector::Class("class2")
+                    ])]),
                     body: Body::LiteralBody(" color: green; "),
                 },
             ])
@@ -343,11 +646,15 @@ mod tests {
                     parameters: " svg url('http://www.w3.org/2000/svg')"
                 }),
                 Section::WithBody {
-                    instruction: Instruction::ComplexSelector(vec![Selector::Class("class1")]),
+                    instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                        Selector::Class("class1")
+                    ])]),
                     body: Body::LiteralBody(" color: red; "),
                 },
                 Section::WithBody {
-                    instruction: Instruction::ComplexSelector(vec![Selector::Id("id1")]),
+                    instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                        Selector::Id("id1")
+                    ])]),
                     body: Body::LiteralBody(" color: green; "),
                 },
             ])
@@ -367,16 +674,22 @@ mod tests {
                         parameters: " (hover: hover) "
                     },
                     body: Body::ParsedBody(Stylesheet::new(vec![Section::WithBody {
-                        instruction: Instruction::ComplexSelector(vec![Selector::Class("class1")]),
+                        instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(
+                            vec![Selector::Class("class1")]
+                        )]),
                         body: Body::LiteralBody(" color: green; ")
                     }]))
                 },
                 Section::WithBody {
-                    instruction: Instruction::ComplexSelector(vec![Selector::Class("class1")]),
+                    instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                        Selector::Class("class1")
+                    ])]),
                     body: Body::LiteralBody(" color: red; "),
                 },
                 Section::WithBody {
-                    instruction: Instruction::ComplexSelector(vec![Selector::Id("id1")]),
+                    instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                        Selector::Id("id1")
+                    ])]),
                     body: Body::LiteralBody(" color: green; "),
                 },
             ])
@@ -1,6 +1,6 @@
 #[derive(Debug, PartialEq)]
 pub enum Instruction<'a> {
-    ComplexSelector(Vec<Selector<'a>>),
+    ComplexSelector(Vec<ComplexSelector<'a>>),
     SpecialInstruction {
         command: &'a str,
         parameters: &'a str,
@@ -11,7 +11,7 @@ impl<'a> Instruction<'a> {
         if let Instruction::ComplexSelector(selectors) = self {
             return selectors
                 .iter()
-                .filter_map(|s| s.gen_mapping(prefix))
+                .flat_map(|s| s.gen_mapping(prefix))
                 .collect();
         }
         vec![]
@@ -31,18 +31,69 @@ impl<'a> Instruction<'a> {
     }
 }
 
+/// sequence of simple selectors joined by combinators, ex. `.list > li.item:hover`
+#[derive(Debug, PartialEq)]
+pub struct ComplexSelector<'a> {
+    selectors: Vec<Selector<'a>>,
+}
+impl<'a> ComplexSelector<'a> {
+    pub fn new(selectors: Vec<Selector<'a>>) -> Self {
+        ComplexSelector { selectors }
+    }
+    pub fn gen_mapping(&self, prefix: &str) -> Vec<(String, String)> {
+        self.selectors
+            .iter()
+            .flat_map(|s| s.gen_mapping(prefix))
+            .collect()
+    }
+    pub fn gen_css(&self, prefix: &str) -> String {
+        // relative selectors, ex. in `:has(> img)`, start with a combinator
+        self.selectors
+            .iter()
+            .map(|s| s.gen_css(prefix))
+            .collect::<String>()
+            .trim_start()
+            .to_string()
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum Selector<'a> {
     Id(&'a str),
     Class(&'a str),
     Element(&'a str),
+    /// ex. `[href^="https"]`, stored without brackets
+    Attribute(&'a str),
+    PseudoClass {
+        name: &'a str,
+        arguments: Option<PseudoArguments<'a>>,
+    },
+    PseudoElement {
+        name: &'a str,
+        arguments: Option<PseudoArguments<'a>>,
+    },
+    Combinator(Combinator),
 }
 impl<'a> Selector<'a> {
-    pub fn gen_mapping(&self, prefix: &str) -> Option<(String, String)> {
+    pub fn gen_mapping(&self, prefix: &str) -> Vec<(String, String)> {
         match self {
-            Selector::Id(id) => Some((id.to_string(), format!("{}{}", prefix, id))),
-            Selector::Class(class) => Some((class.to_string(), format!("{}{}", prefix, class))),
-            Selector::Element(_) => None,
+            Selector::Id(id) => {
+                let id = unescape(id);
+                vec![(id.clone(), format!("{}{}", prefix, id))]
+            }
+            Selector::Class(class) => {
+                let class = unescape(class);
+                vec![(class.clone(), format!("{}{}", prefix, class))]
+            }
+            Selector::PseudoClass {
+                arguments: Some(arguments),
+                ..
+            }
+            | Selector::PseudoElement {
+                arguments: Some(arguments),
+                ..
+            } => arguments.gen_mapping(prefix),
+            _ => vec![],
         }
     }
     pub fn gen_css(&self, prefix: &str) -> String {
@@ -50,6 +101,79 @@ impl<'a> Selector<'a> {
             Selector::Id(id) => format!("#{}{}", prefix, id),
             Selector::Class(class) => format!(".{}{}", prefix, class),
             Selector::Element(element) => element.to_string(),
+            Selector::Attribute(attribute) => format!("[{}]", attribute),
+            Selector::PseudoClass { name, arguments } => {
+                format!(
+                    ":{}{}",
+                    name,
+                    PseudoArguments::gen_css_opt(arguments, prefix)
+                )
+            }
+            Selector::PseudoElement { name, arguments } => {
+                format!(
+                    "::{}{}",
+                    name,
+                    PseudoArguments::gen_css_opt(arguments, prefix)
+                )
+            }
+            Selector::Combinator(combinator) => combinator.gen_css().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PseudoArguments<'a> {
+    /// arguments of pseudo-classes taking selectors, ex. `:not(.active)`
+    Selectors(Vec<ComplexSelector<'a>>),
+    /// other arguments, ex. `:nth-child(2n + 1)`
+    Literal(&'a str),
+}
+impl<'a> PseudoArguments<'a> {
+    pub fn gen_mapping(&self, prefix: &str) -> Vec<(String, String)> {
+        match self {
+            PseudoArguments::Selectors(selectors) => selectors
+                .iter()
+                .flat_map(|s| s.gen_mapping(prefix))
+                .collect(),
+            PseudoArguments::Literal(_) => vec![],
+        }
+    }
+    pub fn gen_css(&self, prefix: &str) -> String {
+        match self {
+            PseudoArguments::Selectors(selectors) => selectors
+                .iter()
+                .map(|s| s.gen_css(prefix))
+                .collect::<Vec<String>>()
+                .join(", "),
+            PseudoArguments::Literal(literal) => literal.trim().to_string(),
+        }
+    }
+    fn gen_css_opt(arguments: &Option<PseudoArguments<'a>>, prefix: &str) -> String {
+        arguments
+            .as_ref()
+            .map(|a| format!("({})", a.gen_css(prefix)))
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Combinator {
+    /// whitespace, ex. `.list .item`
+    Descendant,
+    /// ex. `.list > .item`
+    Child,
+    /// ex. `.title + .subtitle`
+    NextSibling,
+    /// ex. `.title ~ .paragraph`
+    SubsequentSibling,
+}
+impl Combinator {
+    pub fn gen_css(&self) -> &'static str {
+        match self {
+            Combinator::Descendant => " ",
+            Combinator::Child => " > ",
+            Combinator::NextSibling => " + ",
+            Combinator::SubsequentSibling => " ~ ",
         }
     }
 }
@@ -125,9 +249,24 @@ impl<'a> Stylesheet<'a> {
     }
 }
 
+/// removes `\` of escaped characters of an identifier, ex. `sm\:flex` becomes `sm:flex` as used in the `class` attribute
+fn unescape(ident: &str) -> String {
+    let mut unescaped = String::with_capacity(ident.len());
+    let mut chars = ident.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.extend(chars.next()),
+            c => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::parser::types::{Body, Instruction, Section, Stylesheet};
+    use crate::parser::types::{
+        Body, Combinator, ComplexSelector, Instruction, PseudoArguments, Section, Stylesheet,
+    };
 
     use super::Selector;
 
@@ -135,7 +274,7 @@ mod tests {
     fn class_selector_gens_correct_mapping() {
         let selector = Selector::Class("class");
         let prefix = "test-";
-        let expected = Some(("class".to_owned(), "test-class".to_owned()));
+        let expected = vec![("class".to_owned(), "test-class".to_owned())];
 
         assert_eq!(expected, selector.gen_mapping(prefix))
     }
@@ -143,7 +282,7 @@ mod tests {
     fn id_selector_gens_correct_mapping() {
         let selector = Selector::Id("id");
         let prefix = "test-";
-        let expected = Some(("id".to_owned(), "test-id".to_owned()));
+        let expected = vec![("id".to_owned(), "test-id".to_owned())];
 
         assert_eq!(expected, selector.gen_mapping(prefix))
     }
@@ -151,16 +290,16 @@ mod tests {
     fn element_selector_does_not_gen_mapping() {
         let selector = Selector::Element("body");
         let prefix = "test-";
-        let expected = None;
+        let expected: Vec<(String, String)> = vec![];
 
         assert_eq!(expected, selector.gen_mapping(prefix))
     }
     #[test]
     fn instruction_complex_selector_gens_correct_mapping() {
         let instruction = Instruction::ComplexSelector(vec![
-            Selector::Class("class"),
-            Selector::Element("body"),
-            Selector::Id("id"),
+            ComplexSelector::new(vec![Selector::Class("class")]),
+            ComplexSelector::new(vec![Selector::Element("body")]),
+            ComplexSelector::new(vec![Selector::Id("id")]),
         ]);
         let prefix = "test-";
         let expected = vec![
@@ -184,7 +323,9 @@ mod tests {
     #[test]
     fn section_with_literal_body_gens_correct_mapping() {
         let section = Section::WithBody {
-            instruction: Instruction::ComplexSelector(vec![Selector::Class("class")]),
+            instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                Selector::Class("class"),
+            ])]),
             body: Body::LiteralBody(" color: red; "),
         };
         let prefix = "test-";
@@ -200,7 +341,9 @@ mod tests {
                 parameters: " (hover: hover) ",
             },
             body: Body::ParsedBody(Stylesheet::new(vec![Section::WithBody {
-                instruction: Instruction::ComplexSelector(vec![Selector::Class("class")]),
+                instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                    Selector::Class("class"),
+                ])]),
                 body: Body::LiteralBody(" color: green; "),
             }])),
         };
@@ -229,16 +372,22 @@ mod tests {
                     parameters: " (hover: hover) ",
                 },
                 body: Body::ParsedBody(Stylesheet::new(vec![Section::WithBody {
-                    instruction: Instruction::ComplexSelector(vec![Selector::Class("class1")]),
+                    instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                        Selector::Class("class1"),
+                    ])]),
                     body: Body::LiteralBody(" color: green; "),
                 }])),
             },
             Section::WithBody {
-                instruction: Instruction::ComplexSelector(vec![Selector::Class("class2")]),
+                instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                    Selector::Class("class2"),
+                ])]),
                 body: Body::LiteralBody(" color: red; "),
             },
             Section::WithBody {
-                instruction: Instruction::ComplexSelector(vec![Selector::Id("id1")]),
+                instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                    Selector::Id("id1"),
+                ])]),
                 body: Body::LiteralBody(" color: green; "),
             },
         ]);
@@ -278,9 +427,9 @@ mod tests {
     #[test]
     fn instruction_complex_selector_gens_correct_css() {
         let instruction = Instruction::ComplexSelector(vec![
-            Selector::Class("class"),
-            Selector::Element("body"),
-            Selector::Id("id"),
+            ComplexSelector::new(vec![Selector::Class("class")]),
+            ComplexSelector::new(vec![Selector::Element("body")]),
+            ComplexSelector::new(vec![Selector::Id("id")]),
         ]);
         let prefix = "test-";
         let expected = ".test-class, body, #test-id".to_owned();
@@ -309,7 +458,9 @@ mod tests {
     #[test]
     fn parsed_body_gens_correct_css() {
         let body = Body::ParsedBody(Stylesheet::new(vec![Section::WithBody {
-            instruction: Instruction::ComplexSelector(vec![Selector::Class("class")]),
+            instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                Selector::Class("class"),
+            ])]),
             body: Body::LiteralBody(" color: green; "),
         }]));
         let prefix = "test-";
@@ -336,7 +487,9 @@ mod tests {
                 parameters: " (hover: hover) ",
             },
             body: Body::ParsedBody(Stylesheet::new(vec![Section::WithBody {
-                instruction: Instruction::ComplexSelector(vec![Selector::Class("class")]),
+                instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                    Selector::Class("class"),
+                ])]),
                 body: Body::LiteralBody(" color: green; "),
             }])),
         };
@@ -354,16 +507,22 @@ mod tests {
                     parameters: " (hover: hover) ",
                 },
                 body: Body::ParsedBody(Stylesheet::new(vec![Section::WithBody {
-                    instruction: Instruction::ComplexSelector(vec![Selector::Class("class1")]),
+                    instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                        Selector::Class("class1"),
+                    ])]),
                     body: Body::LiteralBody(" color: green; "),
                 }])),
             },
             Section::WithBody {
-                instruction: Instruction::ComplexSelector(vec![Selector::Class("class2")]),
+                instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                    Selector::Class("class2"),
+                ])]),
                 body: Body::LiteralBody(" color: red; "),
             },
             Section::WithBody {
-                instruction: Instruction::ComplexSelector(vec![Selector::Id("id1")]),
+                instruction: Instruction::ComplexSelector(vec![ComplexSelector::new(vec![
+                    Selector::Id("id1"),
+                ])]),
                 body: Body::LiteralBody(" color: green; "),
             },
         ]);
@@ -372,4 +531,72 @@ mod tests {
 
         assert_eq!(expected, stylesheet.gen_css(prefix))
     }
+    #[test]
+    fn complex_selector_gens_correct_css() {
+        let selector = ComplexSelector::new(vec![
+            Selector::Class("list"),
+            Selector::Combinator(Combinator::Child),
+            Selector::Element("li"),
+            Selector::Class("item"),
+            Selector::Combinator(Combinator::Descendant),
+            Selector::Element("a"),
+            Selector::Attribute("href^=\"https\""),
+            Selector::Combinator(Combinator::NextSibling),
+            Selector::Id("icon"),
+            Selector::PseudoElement {
+                name: "before",
+                arguments: None,
+            },
+        ]);
+        let prefix = "test-";
+        let expected = ".test-list > li.test-item a[href^=\"https\"] + #test-icon::before";
+
+        assert_eq!(expected, selector.gen_css(prefix))
+    }
+    #[test]
+    fn complex_selector_gens_mapping_of_class_and_id_parts() {
+        let selector = ComplexSelector::new(vec![
+            Selector::Class("list"),
+            Selector::Combinator(Combinator::SubsequentSibling),
+            Selector::Element("div"),
+            Selector::Id("id"),
+        ]);
+        let prefix = "test-";
+        let expected = vec![
+            ("list".to_owned(), "test-list".to_owned()),
+            ("id".to_owned(), "test-id".to_owned()),
+        ];
+
+        assert_eq!(expected, selector.gen_mapping(prefix))
+    }
+    #[test]
+    fn pseudo_class_with_selector_arguments_gens_correct_css_and_mapping() {
+        let selector = Selector::PseudoClass {
+            name: "not",
+            arguments: Some(PseudoArguments::Selectors(vec![
+                ComplexSelector::new(vec![Selector::Class("active")]),
+                ComplexSelector::new(vec![
+                    Selector::Combinator(Combinator::Child),
+                    Selector::Element("img"),
+                ]),
+            ])),
+        };
+        let prefix = "test-";
+        let expected_css = ":not(.test-active, > img)";
+        let expected_mapping = vec![("active".to_owned(), "test-active".to_owned())];
+
+        assert_eq!(expected_css, selector.gen_css(prefix));
+        assert_eq!(expected_mapping, selector.gen_mapping(prefix))
+    }
+    #[test]
+    fn pseudo_class_with_literal_arguments_gens_correct_css() {
+        let selector = Selector::PseudoClass {
+            name: "nth-child",
+            arguments: Some(PseudoArguments::Literal(" 2n + 1 ")),
+        };
+        let prefix = "test-";
+        let expected = ":nth-child(2n + 1)";
+
+        assert_eq!(expected, selector.gen_css(prefix))
+    }
 }
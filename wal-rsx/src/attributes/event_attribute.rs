@@ -1,12 +1,21 @@
 use std::collections::HashSet;
 
 use once_cell::sync::Lazy;
-use quote::ToTokens;
-use syn::parse::{Parse, ParseStream};
+use quote::{quote, ToTokens};
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+};
 
 use super::Attribute;
 
-pub(crate) type EventAttributeValue = syn::ExprBlock;
+const PREVENT_MODIFIER: &str = "prevent";
+const STOP_MODIFIER: &str = "stop";
+const ONCE_MODIFIER: &str = "once";
+const PASSIVE_MODIFIER: &str = "passive";
+const CAPTURE_MODIFIER: &str = "capture";
+
+pub(crate) const CUSTOM_EVENT_ATTR: &str = "on";
 
 pub(crate) struct EventAttribute {
     pub(crate) ident: proc_macro2::Ident,
@@ -16,22 +25,161 @@ pub(crate) struct EventAttribute {
 impl Parse for EventAttribute {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let ident = input.parse()?;
+        let modifiers = input.parse()?;
+        input.parse::<syn::token::Eq>()?;
+        let expr_block = input.parse::<syn::ExprBlock>()?;
+        if expr_block.block.stmts.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &expr_block,
+                "Expected a non-empty expression block",
+            ));
+        }
+
+        Ok(EventAttribute {
+            ident,
+            value: EventAttributeValue {
+                modifiers,
+                expr_block,
+            },
+        })
+    }
+}
+
+/// Attribute of an event, which is not a standard DOM event, e.g. `on:item-selected={callback}`.
+pub(crate) struct CustomEventAttribute {
+    pub(crate) event_type: syn::LitStr,
+    pub(crate) value: EventAttributeValue,
+}
+
+impl Parse for CustomEventAttribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<proc_macro2::Ident>()?;
+        input.parse::<syn::token::Colon>()?;
+        let event_type = parse_custom_event_type(input)?;
+        let modifiers = input.parse()?;
         input.parse::<syn::token::Eq>()?;
-        let value = input.parse::<syn::ExprBlock>()?;
-        if value.block.stmts.is_empty() {
+        let expr_block = input.parse::<syn::ExprBlock>()?;
+        if expr_block.block.stmts.is_empty() {
             return Err(syn::Error::new_spanned(
-                &value,
+                &expr_block,
                 "Expected a non-empty expression block",
             ));
         }
 
-        Ok(EventAttribute { ident, value })
+        Ok(CustomEventAttribute {
+            event_type,
+            value: EventAttributeValue {
+                modifiers,
+                expr_block,
+            },
+        })
+    }
+}
+
+impl CustomEventAttribute {
+    pub(crate) fn peek(input: ParseStream) -> bool {
+        input.peek(syn::Ident) && input.peek2(syn::token::Colon)
+    }
+}
+
+/// Parses event type consisting of identifiers joined with `-`, e.g. `item-selected`.
+fn parse_custom_event_type(input: ParseStream) -> syn::Result<syn::LitStr> {
+    let first = proc_macro2::Ident::parse_any(input)?;
+    let mut event_type = first.to_string();
+    while input.peek(syn::token::Minus) {
+        input.parse::<syn::token::Minus>()?;
+        let part = proc_macro2::Ident::parse_any(input)?;
+        event_type.push('-');
+        event_type.push_str(&part.to_string());
     }
+    Ok(syn::LitStr::new(&event_type, first.span()))
 }
 
 impl ToTokens for EventAttribute {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        self.value.to_tokens(tokens);
+        self.value.expr_block.to_tokens(tokens);
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct EventAttributeValue {
+    pub(crate) modifiers: EventModifiers,
+    pub(crate) expr_block: syn::ExprBlock,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct EventModifiers {
+    prevent: bool,
+    stop: bool,
+    once: bool,
+    passive: bool,
+    capture: bool,
+}
+
+impl Parse for EventModifiers {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut modifiers = EventModifiers::default();
+
+        while input.peek(syn::token::Colon) {
+            input.parse::<syn::token::Colon>()?;
+            let modifier = input.parse::<proc_macro2::Ident>()?;
+            let flag = match modifier.to_string().as_str() {
+                PREVENT_MODIFIER => &mut modifiers.prevent,
+                STOP_MODIFIER => &mut modifiers.stop,
+                ONCE_MODIFIER => &mut modifiers.once,
+                PASSIVE_MODIFIER => &mut modifiers.passive,
+                CAPTURE_MODIFIER => &mut modifiers.capture,
+                _ => {
+                    return Err(syn::Error::new(
+                        modifier.span(),
+                        format!(
+                            "Unknown event modifier `{modifier}`, expected one of: `{PREVENT_MODIFIER}`, `{STOP_MODIFIER}`, `{ONCE_MODIFIER}`, `{PASSIVE_MODIFIER}`, `{CAPTURE_MODIFIER}`"
+                        ),
+                    ))
+                }
+            };
+            if *flag {
+                return Err(syn::Error::new(
+                    modifier.span(),
+                    format!("Duplicate event modifier `{modifier}`"),
+                ));
+            }
+            *flag = true;
+
+            if modifiers.prevent && modifiers.passive {
+                return Err(syn::Error::new(
+                    modifier.span(),
+                    format!("Event modifiers `{PREVENT_MODIFIER}` and `{PASSIVE_MODIFIER}` cannot be used together"),
+                ));
+            }
+        }
+
+        Ok(modifiers)
+    }
+}
+
+impl EventModifiers {
+    pub(crate) fn is_empty(&self) -> bool {
+        !(self.prevent || self.stop || self.once || self.passive || self.capture)
+    }
+}
+
+impl ToTokens for EventModifiers {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let EventModifiers {
+            prevent,
+            stop,
+            once,
+            passive,
+            capture,
+        } = self;
+        tokens.extend(quote!(::wal_core::events::EventOptions {
+            prevent_default: #prevent,
+            stop_propagation: #stop,
+            once: #once,
+            passive: #passive,
+            capture: #capture,
+        }));
     }
 }
 
@@ -64,6 +212,14 @@ static EVENTS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
         "onanimationend",
         "onanimationiteration",
         "onanimationstart",
+        // Clipboard Events
+        "oncopy",
+        "oncut",
+        "onpaste",
+        // Composition Events
+        "oncompositionend",
+        "oncompositionstart",
+        "oncompositionupdate",
         // Drag Events
         "ondrag",
         "ondragend",
@@ -79,8 +235,9 @@ static EVENTS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
         "onfocusin",
         "onfocusout",
         // FormData Events
-        "onformdata", // web_sys is missing `FormDataEvent`` so it is handled as Unspecialized Event
+        "onformdata",
         // Input Events
+        "onbeforeinput",
         "oninput",
         // Keyboard Events
         "onkeydown",
@@ -113,6 +270,8 @@ static EVENTS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
         "onloadend",
         "onloadstart",
         "onprogress",
+        // SecurityPolicyViolation Events
+        "onsecuritypolicyviolation",
         // Submit Events
         "onsubmit",
         // Touch Events
@@ -132,9 +291,7 @@ static EVENTS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
         "oncanplaythrough",
         "onchange",
         "onclose",
-        "oncopy",
         "oncuechange",
-        "oncut",
         "ondurationchange",
         "onemptied",
         "onended",
@@ -143,7 +300,6 @@ static EVENTS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
         "onload",
         "onloadeddata",
         "onloadedmetadata",
-        "onpaste",
         "onpause",
         "onplay",
         "onplaying",
@@ -153,7 +309,6 @@ static EVENTS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
         "onreset",
         "onresize",
         "onscroll",
-        "onsecuritypolicyviolation",
         "onseeked",
         "onseeking",
         "onselect",
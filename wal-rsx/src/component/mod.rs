@@ -36,10 +36,16 @@ impl ToTokens for Component {
         let props = self.attributes.get_props_attribute_token_stream(ty);
         let key = self.attributes.get_key_attribute_token_stream();
 
+        // Properties, which are not `Clone`, are moved to the component instead of being kept for the comparison
         tokens.extend(quote_spanned! { self.error_span() =>
-            ::wal_core::virtual_dom::VNode::Component(
-                ::wal_core::virtual_dom::VComponent::new::<#ty>(#props, #key)
-            )
+            ::wal_core::virtual_dom::VNode::Component({
+                #[allow(unused_imports)]
+                use ::wal_core::virtual_dom::vcomponent::{
+                    CloneablePropertiesFactory as _, OwnedPropertiesFactory as _,
+                };
+                (&&::wal_core::virtual_dom::vcomponent::VComponentFactory::<#ty>::default())
+                    .create(#props, #key)
+            })
         });
     }
 }
@@ -4,7 +4,7 @@ use syn::{
 };
 
 use crate::attributes::{
-    event_attribute::{EventAttribute, IsEvent},
+    event_attribute::{CustomEventAttribute, EventAttribute, IsEvent, CUSTOM_EVENT_ATTR},
     normal_attribute::NormalAttribute,
     wal_class_attribute::WalClassAttribute,
     KEY_ATTR,
@@ -16,6 +16,7 @@ const WAL_CLASS_ATTR: &str = "wal_class";
 pub(crate) enum ElementAttribute {
     Normal(NormalAttribute),
     Event(EventAttribute),
+    CustomEvent(CustomEventAttribute),
     Key(NormalAttribute),
     Class(NormalAttribute),
     WalClass(WalClassAttribute),
@@ -32,6 +33,8 @@ impl Parse for ElementAttribute {
             Ok(ElementAttribute::Class(input.parse()?))
         } else if ident == WAL_CLASS_ATTR {
             Ok(ElementAttribute::WalClass(input.parse()?))
+        } else if ident == CUSTOM_EVENT_ATTR && CustomEventAttribute::peek(input) {
+            Ok(ElementAttribute::CustomEvent(input.parse()?))
         } else if ident.is_event() {
             Ok(ElementAttribute::Event(input.parse()?))
         } else {
@@ -4,7 +4,7 @@ use quote::{quote, quote_spanned};
 use syn::{parse::Parse, spanned::Spanned};
 
 use crate::attributes::{
-    event_attribute::EventAttributeValue,
+    event_attribute::{CustomEventAttribute, EventAttributeValue},
     normal_attribute::{NormalAttribute, NormalAttributeValue},
     process_specialized_attribute, process_unspecialized_attribute,
     wal_class_attribute::WalClassAttribute,
@@ -14,7 +14,8 @@ use super::element_attribute::{ElementAttribute, CLASS_ATTR};
 
 pub(crate) struct ElementAttributes {
     normal: HashMap<proc_macro2::Ident, NormalAttributeValue>,
-    events: HashMap<proc_macro2::Ident, syn::ExprBlock>,
+    events: HashMap<proc_macro2::Ident, EventAttributeValue>,
+    custom_events: HashMap<String, EventAttributeValue>,
     key: Option<NormalAttribute>,
     class: Option<NormalAttribute>,
     wal_class: Option<WalClassAttribute>,
@@ -24,6 +25,7 @@ impl Parse for ElementAttributes {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let mut normal = HashMap::<proc_macro2::Ident, NormalAttributeValue>::new();
         let mut events = HashMap::<proc_macro2::Ident, EventAttributeValue>::new();
+        let mut custom_events = HashMap::<String, EventAttributeValue>::new();
         let mut key = None;
         let mut class = None;
         let mut wal_class = None;
@@ -33,6 +35,7 @@ impl Parse for ElementAttributes {
             Self::process_attribute(
                 &mut normal,
                 &mut events,
+                &mut custom_events,
                 &mut key,
                 &mut class,
                 &mut wal_class,
@@ -43,6 +46,7 @@ impl Parse for ElementAttributes {
         Ok(ElementAttributes {
             normal,
             events,
+            custom_events,
             key,
             class,
             wal_class,
@@ -54,6 +58,7 @@ impl ElementAttributes {
     fn process_attribute(
         normal: &mut HashMap<proc_macro2::Ident, NormalAttributeValue>,
         events: &mut HashMap<proc_macro2::Ident, EventAttributeValue>,
+        custom_events: &mut HashMap<String, EventAttributeValue>,
         key: &mut Option<NormalAttribute>,
         class: &mut Option<NormalAttribute>,
         wal_class: &mut Option<WalClassAttribute>,
@@ -66,6 +71,9 @@ impl ElementAttributes {
             ElementAttribute::Event(incoming_attribute) => {
                 process_unspecialized_attribute(events, &incoming_attribute)
             }
+            ElementAttribute::CustomEvent(incoming_attribute) => {
+                Self::process_custom_event_attribute(custom_events, incoming_attribute)
+            }
             ElementAttribute::Key(incoming_attribute) => {
                 process_specialized_attribute(key, incoming_attribute)
             }
@@ -78,6 +86,34 @@ impl ElementAttributes {
         }
     }
 
+    fn process_custom_event_attribute(
+        custom_events: &mut HashMap<String, EventAttributeValue>,
+        incoming_attribute: CustomEventAttribute,
+    ) -> syn::Result<()> {
+        let CustomEventAttribute {
+            event_type, value, ..
+        } = incoming_attribute;
+        if custom_events.insert(event_type.value(), value).is_some() {
+            Err(syn::Error::new(
+                event_type.span(),
+                format!("Duplicate attribute `on:{}`", event_type.value()),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub(crate) fn contains_normal_attribute(&self, name: &str) -> bool {
+        self.normal.keys().any(|ident| ident == name)
+    }
+
+    /// Removes the normal attribute with provided name, so it can be handled by the tag in a specialized way.
+    pub(crate) fn take_normal_attribute(&mut self, name: &str) -> Option<NormalAttribute> {
+        let ident = self.normal.keys().find(|ident| *ident == name)?.clone();
+        let value = self.normal.remove(&ident)?;
+        Some(NormalAttribute { ident, value })
+    }
+
     pub(crate) fn get_attributes_token_stream(&self) -> Vec<proc_macro2::TokenStream> {
         let mut attributes_token_stream: Vec<proc_macro2::TokenStream> = self
             .normal
@@ -130,14 +166,41 @@ impl ElementAttributes {
     }
 
     pub(crate) fn get_event_handlers_token_stream(&self) -> Vec<proc_macro2::TokenStream> {
-        self.events
-            .iter()
-            .map(|(ident, expr_block)| -> proc_macro2::TokenStream {
-                quote_spanned!(expr_block.span() => ::wal_core::events::EventHandler::new(
-                    #[allow(unused_braces)]
-                    ::wal_core::events::#ident(#expr_block)
-                ))
-            })
-            .collect()
+        let events = self.events.iter().map(|(ident, value)| {
+            let expr_block = &value.expr_block;
+            let event_creator = quote_spanned!(expr_block.span() =>
+                #[allow(unused_braces)]
+                ::wal_core::events::#ident(#expr_block)
+            );
+            Self::get_event_handler_token_stream(event_creator, value)
+        });
+        let custom_events = self.custom_events.iter().map(|(event_type, value)| {
+            let expr_block = &value.expr_block;
+            let event_creator = quote_spanned!(expr_block.span() =>
+                #[allow(unused_braces)]
+                ::wal_core::events::on_custom_event(#event_type, #expr_block)
+            );
+            Self::get_event_handler_token_stream(event_creator, value)
+        });
+
+        events.chain(custom_events).collect()
+    }
+
+    fn get_event_handler_token_stream(
+        event_creator: proc_macro2::TokenStream,
+        value: &EventAttributeValue,
+    ) -> proc_macro2::TokenStream {
+        let EventAttributeValue {
+            modifiers,
+            expr_block,
+        } = value;
+        if modifiers.is_empty() {
+            quote_spanned!(expr_block.span() => ::wal_core::events::EventHandler::new(#event_creator))
+        } else {
+            quote_spanned!(expr_block.span() => ::wal_core::events::EventHandler::new_with_options(
+                #event_creator,
+                #modifiers
+            ))
+        }
     }
 }
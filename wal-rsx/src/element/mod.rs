@@ -9,13 +9,13 @@ use syn::{
 use super::tree::Tree;
 
 mod element_attribute;
-mod element_attributes;
+pub(crate) mod element_attributes;
 mod element_closing_tag;
 mod element_opening_tag;
 
 pub(crate) struct Element {
-    opening_tag: ElementOpeningTag,
-    children: Vec<Tree>,
+    pub(crate) opening_tag: ElementOpeningTag,
+    pub(crate) children: Vec<Tree>,
     closing_tag: Option<ElementClosingTag>,
 }
 
@@ -13,7 +13,7 @@ mod fragment_opening_tag;
 
 pub(crate) struct Fragment {
     opening_tag: FragmentOpeningTag,
-    children: Vec<Tree>,
+    pub(crate) children: Vec<Tree>,
     closing_tag: FragmentClosingTag,
 }
 
@@ -0,0 +1,51 @@
+use quote::{quote, ToTokens};
+use syn::parse::Parse;
+
+use crate::attributes::normal_attribute::NormalAttribute;
+
+pub(crate) struct HeadOpeningTag {
+    lt: syn::token::Lt,
+    pub(crate) name: proc_macro2::Ident,
+    slash: Option<syn::token::Slash>,
+    gt: syn::token::Gt,
+}
+
+impl Parse for HeadOpeningTag {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lt = input.parse()?;
+        let name = input.parse::<proc_macro2::Ident>()?;
+
+        if NormalAttribute::peek(input) {
+            let attribute = input.parse::<NormalAttribute>()?;
+            return Err(syn::Error::new(
+                attribute.ident.span(),
+                format!(
+                    "Unsupported attribute `{}`. `{}` does not support attributes",
+                    attribute.ident, name
+                ),
+            ));
+        }
+
+        let slash = input.parse().ok();
+        let gt = input.parse()?;
+
+        Ok(HeadOpeningTag {
+            lt,
+            name,
+            slash,
+            gt,
+        })
+    }
+}
+
+impl HeadOpeningTag {
+    pub(crate) fn is_self_closing(&self) -> bool {
+        self.slash.is_some()
+    }
+
+    pub(crate) fn error_spanned(&self) -> impl ToTokens {
+        let lt = &self.lt;
+        let gt = &self.gt;
+        quote! { #lt #gt }
+    }
+}
@@ -0,0 +1,171 @@
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+use self::{head_closing_tag::HeadClosingTag, head_opening_tag::HeadOpeningTag};
+
+use super::{element::Element, tree::Tree};
+
+mod head_closing_tag;
+mod head_opening_tag;
+
+pub(crate) const HEAD_TAG: &str = "Head";
+const TITLE_TAG: &str = "title";
+const META_TAG: &str = "meta";
+const META_KEY_ATTRS: [&str; 3] = ["charset", "name", "property"];
+const LINK_TAG: &str = "link";
+const LINK_KEY_ATTRS: [&str; 2] = ["rel", "href"];
+
+pub(crate) struct Head {
+    opening_tag: HeadOpeningTag,
+    children: Vec<Tree>,
+    closing_tag: Option<HeadClosingTag>,
+}
+
+impl Parse for Head {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek2(syn::token::Slash) {
+            let closing_tag = input.parse::<HeadClosingTag>()?;
+            return Err(syn::Error::new_spanned(
+                closing_tag.error_spanned(),
+                format!(
+                    "This closing tag does not have a corresponding opening tag. (hint: try adding `<{}>`)",
+                    closing_tag.name
+                )
+            ));
+        }
+
+        let opening_tag = input.parse::<HeadOpeningTag>()?;
+        if opening_tag.is_self_closing() {
+            return Ok(Head {
+                opening_tag,
+                children: Vec::new(),
+                closing_tag: None,
+            });
+        }
+
+        let children = Self::parse_children(&opening_tag, input)?;
+        let closing_tag = input.parse()?;
+
+        Ok(Head {
+            opening_tag,
+            children,
+            closing_tag: Some(closing_tag),
+        })
+    }
+}
+
+impl Head {
+    fn parse_children(opening_tag: &HeadOpeningTag, input: ParseStream) -> syn::Result<Vec<Tree>> {
+        let mut children = Vec::new();
+
+        loop {
+            if input.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    opening_tag.error_spanned(),
+                    format!(
+                        "This opening tag does not have a corresponding closing tag. (hint: try adding `</{}>`)",
+                        opening_tag.name
+                    ),
+                ));
+            }
+
+            if HeadClosingTag::peek(input) {
+                break;
+            }
+
+            let child = input.parse()?;
+            Self::validate_child(&child)?;
+            children.push(child);
+        }
+
+        Ok(children)
+    }
+
+    /// Checks that the child can be declared in the document `<head>`. Conditional and iterated children are checked when rendered.
+    fn validate_child(child: &Tree) -> syn::Result<()> {
+        match child {
+            Tree::Element(element) => Self::validate_element(element),
+            Tree::Fragment(fragment) => fragment.children.iter().try_for_each(Self::validate_child),
+            Tree::If(_) | Tree::For(_) => Ok(()),
+            _ => Err(Self::unsupported_child_error(child)),
+        }
+    }
+
+    fn validate_element(element: &Element) -> syn::Result<()> {
+        let opening_tag = &element.opening_tag;
+        let has_attribute = |name: &&str| opening_tag.attributes.contains_normal_attribute(name);
+        let name = opening_tag.name.to_string();
+        match name.as_str() {
+            TITLE_TAG => element.children.iter().try_for_each(|child| match child {
+                Tree::Literal(_) | Tree::ExpressionBlock(_) => Ok(()),
+                _ => Err(syn::Error::new_spanned(
+                    child,
+                    format!("`{TITLE_TAG}` in {HEAD_TAG} supports only text"),
+                )),
+            }),
+            META_TAG if !META_KEY_ATTRS.iter().any(has_attribute) => Err(syn::Error::new_spanned(
+                opening_tag.error_spanned(),
+                format!(
+                    "`{META_TAG}` in {HEAD_TAG} requires one of the attributes: {}",
+                    META_KEY_ATTRS.join(", ")
+                ),
+            )),
+            LINK_TAG if !LINK_KEY_ATTRS.iter().all(has_attribute) => Err(syn::Error::new_spanned(
+                opening_tag.error_spanned(),
+                format!(
+                    "`{LINK_TAG}` in {HEAD_TAG} requires the attributes: {}",
+                    LINK_KEY_ATTRS.join(", ")
+                ),
+            )),
+            META_TAG | LINK_TAG => Ok(()),
+            _ => Err(Self::unsupported_child_error(&opening_tag.name)),
+        }
+    }
+
+    fn unsupported_child_error(spanned: impl ToTokens) -> syn::Error {
+        syn::Error::new_spanned(
+            spanned,
+            format!(
+                "{HEAD_TAG} supports only `{TITLE_TAG}`, `{META_TAG}` and `{LINK_TAG}` elements"
+            ),
+        )
+    }
+}
+
+impl ToTokens for Head {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let children = &self.children;
+
+        tokens.extend(quote_spanned! { self.error_span() =>
+            ::wal_core::virtual_dom::VNode::Head(
+                ::wal_core::virtual_dom::VHead::new(
+                    ::wal_core::virtual_dom::VNode::List(
+                        ::wal_core::virtual_dom::VList::new(
+                            ::std::vec![#(#children,)*],
+                            None,
+                        ),
+                    ),
+                ),
+            )
+        });
+    }
+}
+
+impl Head {
+    fn error_span(&self) -> proc_macro2::Span {
+        self.error_spanned().span()
+    }
+
+    fn error_spanned(&self) -> impl ToTokens {
+        let start_error_spanned = self.opening_tag.error_spanned();
+        let end_error_spanned = self.closing_tag.as_ref().map(HeadClosingTag::error_spanned);
+        if end_error_spanned.is_some() {
+            quote!(#start_error_spanned #end_error_spanned)
+        } else {
+            quote!(#start_error_spanned)
+        }
+    }
+}
@@ -12,10 +12,14 @@ mod expression_block;
 mod r#for;
 mod forest;
 mod fragment;
+mod head;
 mod r#if;
 mod link;
 mod literal;
+mod memo;
+mod portal;
 mod root;
+mod routable;
 mod tree;
 
 /// The `rsx!` procedural macro allows you to write JSX-like syntax in Rust.
@@ -41,7 +45,7 @@ mod tree;
 /// - A character literal: `'a'`
 /// - An integer literal: `1`, `1i32`, `1u32`, ...
 /// - A floating point literal: `1.0`, `1.0f32`, `1.0e1`, ... .
-/// The only restriction here is that it must be finite. So it can not be infinity or NaN.
+///   The only restriction here is that it must be finite. So it can not be infinity or NaN.
 /// - A boolean literal: `true` or `false`
 ///
 /// A byte string literal: `b"foo"` is not supported.
@@ -141,6 +145,42 @@ mod tree;
 /// };
 /// ```
 ///
+/// Event attributes can be followed by modifiers separated with a colon `:`, which change the way the event is handled:
+/// - `prevent` - calls `prevent_default` on the event before the callback is called,
+/// - `stop` - calls `stop_propagation` on the event before the callback is called,
+/// - `once` - calls the callback only for the first event,
+/// - `passive` - listens to the event with a passive listener, it cannot be used together with `prevent`,
+/// - `capture` - calls the callback in the capture phase, before the callbacks of descendant elements.
+///
+/// ```
+/// use wal_rsx::rsx;
+/// use wal_core::component::callback::Callback;
+/// use wal_core::events::{MouseEvent, SubmitEvent};
+///
+/// rsx! {
+///     <form onsubmit:prevent={Callback::new(|_event: SubmitEvent| {})}>
+///         <button onclick:stop:once={Callback::new(|_event: MouseEvent| {})} />
+///     </form>
+/// };
+/// ```
+///
+/// Events, which are not standard DOM events, e.g. events dispatched by web components or by the application itself,
+/// can be handled using `on:` prefix followed by the type of the event.
+/// The result of the expression must be a [`Callback<CustomEvent>`](../wal/component/callback/struct.Callback.html)
+/// (see [CustomEvent](../wal/events/struct.CustomEvent.html)). Event modifiers can be used as well.
+///
+/// ```
+/// use wal_rsx::rsx;
+/// use wal_core::component::callback::Callback;
+/// use wal_core::events::CustomEvent;
+///
+/// rsx! {
+///     <div on:item-selected:stop={Callback::new(|event: CustomEvent| {
+///         let _detail = event.detail();
+///     })} />
+/// };
+/// ```
+///
 /// ###### Wal class attribute
 /// Defining a `class` attribute could be accomplished by using a normal attribute `class`.
 /// The problem with that is that it can become very verbose and bloated while defining many classes from for example variables.
@@ -317,7 +357,17 @@ mod tree;
 /// Links support `to` attribute, which is used to define a path to which the link should route.
 /// `to` attribute can be assigned with a literal or an expression wrapped in curly braces `{}`.
 /// The only restriction is that the result of the expression must implement [Display](::std::fmt::Display) trait, so it supports [to_string()](::std::string::ToString::to_string) function.
+/// In particular it can be a route of the enum deriving [Routable](derive@Routable), e.g. `to={Route::User { id }}`,
+/// so the link does not break silently when the path of the route changes.
 /// Links support `key` attribute. More about this attribute could be found [here](#key-attribute-1).
+/// Links support the same attributes and events as the elements, e.g. `class`, `id`, `target` or `title`,
+/// except for `href`, which is set from the `to` attribute.
+///
+/// Links support options, which can be assigned with a literal or an expression wrapped in curly braces `{}`:
+/// - `active_class` - class added to the link when it matches the current path of the router, e.g. to highlight it in the navigation.
+///   The link matches also the nested paths, e.g. link to `/users` matches `/users/1`.
+/// - `exact` - if `true`, the link matches only exactly the same path. Defaults to `false`.
+/// - `replace` - if `true`, clicking on the link replaces the current history entry instead of adding a new one. Defaults to `false`.
 ///
 /// Links can have childrens, which could be anything that is supported by this macro except:
 /// - expression not wrapped in curly braces `{}`
@@ -335,6 +385,107 @@ mod tree;
 /// };
 /// ```
 ///
+/// The example usage of links with options:
+///
+/// ```no_run
+/// use wal_rsx::rsx;
+/// use wal_core::component::callback::Callback;
+/// use wal_core::events::MouseEvent;
+///
+/// let on_click = Callback::new(|_event: MouseEvent| {});
+/// rsx! {
+///    <nav>
+///        <Link to="/" class="nav-link" active_class="active" exact=true onclick={on_click}> "Home" </Link>
+///        <Link to="/users" class="nav-link" active_class="active" title="Users"> "Users" </Link>
+///        <Link to="/login" replace=true> "Log in" </Link>
+///    </nav>
+/// };
+/// ```
+///
+/// ## Memo
+/// Macro supports memo, which is used to skip patching of a subtree that has not changed.
+///
+/// Memo requires `deps` attribute. `deps` attribute can be assigned with a literal or an expression wrapped in curly braces `{}`.
+/// The only restriction is that the value must implement [PartialEq] trait and be `'static`.
+/// While rerendering, if `deps` are equal to the `deps` from the last render, the children are not patched at all and the previously rendered DOM is kept.
+/// Therefore the children should depend only on the values passed as `deps`.
+///
+/// Memo children could be anything that is supported by this macro except:
+/// - expression not wrapped in curly braces `{}`
+/// - for expression not wrapped in curly braces `{}`
+///
+/// The example usage of memo:
+///
+/// ```
+/// use wal_rsx::rsx;
+///
+/// let items = vec![1, 2, 3];
+/// rsx! {
+///     <Memo deps={items.clone()}>
+///         <ul> for { items.iter().map(|item| rsx! { <li> { item } </li> }) } </ul>
+///     </Memo>
+/// };
+/// ```
+///
+/// ## Portal
+/// Macro supports portal, which is used to render its children into another DOM element, e.g. to escape `overflow: hidden` of the ancestors.
+///
+/// Portal requires `target` attribute. `target` attribute can be assigned with a literal or an expression wrapped in curly braces `{}`.
+/// The value must be a CSS selector of the target element or the [Element](https://docs.rs/web-sys/latest/web_sys/struct.Element.html) itself.
+/// The children are still part of the component tree, so the messages work as usual and the events dispatched inside of the portal bubble to its ancestors.
+///
+/// Portal children could be anything that is supported by this macro except:
+/// - expression not wrapped in curly braces `{}`
+/// - for expression not wrapped in curly braces `{}`
+///
+/// The example usage of portal:
+///
+/// ```
+/// use wal_rsx::rsx;
+///
+/// rsx! {
+///     <div class="card">
+///         <Portal target="#modal-root">
+///             <div class="modal">{ "Modal" }</div>
+///         </Portal>
+///     </div>
+/// };
+/// ```
+///
+/// ## Head
+/// Macro supports head, which is used to declare the title and the meta tags of the document, e.g. per page.
+/// Its children are not rendered in place of the head, they set the elements of the document `<head>` instead.
+///
+/// Head does not support any attributes. Its children could be only:
+/// - `title` element with the text
+/// - `meta` element identified by `charset`, `name` or `property` attribute
+/// - `link` element identified by `rel` and `href` attributes, e.g. stylesheets and icons do not replace each other,
+///   except for `canonical` and `manifest` links, which are identified by `rel` alone
+///
+/// Other children, e.g. text or custom components, are rejected at compile time.
+///
+/// If the same element is declared by multiple heads, the deepest one in the component tree wins, so nested components
+/// can override the title of the page. When the head is removed, e.g. its component is unmounted or the route changes,
+/// the elements are restored to the values they had before.
+///
+/// The example usage of head:
+///
+/// ```
+/// use wal_rsx::rsx;
+///
+/// let user = "Alice";
+/// rsx! {
+///     <div>
+///         <Head>
+///             <title>{ format!("{user} - Users") }</title>
+///             <meta name="description" content={format!("Profile of {user}")} />
+///             <link rel="canonical" href="https://example.com/users/alice" />
+///         </Head>
+///         <h1>{ user }</h1>
+///     </div>
+/// };
+/// ```
+///
 /// ## Custom components
 /// Macro supports custom components, which are used to define reusable, custom views and behaviors.
 /// Custom components can not have children.
@@ -343,6 +494,8 @@ mod tree;
 /// The only restriction is that the value must be convertable to a
 /// [Properties](../wal/component/trait.Component.html#associatedtype.Properties) type of the
 /// [Component](../wal/component/trait.Component.html).
+/// If the properties implement [Clone], the component is rerendered only when they differ from the last ones,
+/// otherwise they are moved to the component and it is notified on every rerender of its parent.
 /// Custom components support `key` attribute. More about this attribute could be found [here](#key-attribute-1).
 ///
 /// The example usage of custom components:
@@ -357,7 +510,7 @@ mod tree;
 ///     x: i32,
 /// }
 ///
-/// #[derive(Hash)]
+/// #[derive(PartialEq, Clone)]
 /// struct MyComponentProps {
 ///     x: i32,
 /// }
@@ -387,6 +540,28 @@ mod tree;
 /// };
 /// ```
 ///
+/// Generic components are used with their generic arguments.
+/// For example [ErrorBoundary](../wal/component/struct.ErrorBoundary.html) renders the wrapped component
+/// and replaces it with a fallback view, when the component or any of its descendants fails.
+///
+/// ```
+/// # use wal_rsx::rsx;
+/// # use wal_core::component::{Component, DefaultFallback, ErrorBoundary};
+/// # use wal_core::component::behavior::Behavior;
+/// # use wal_core::virtual_dom::VNode;
+/// # struct MyComponent;
+/// # impl Component for MyComponent {
+/// #     type Properties = i32;
+/// #     type Message = ();
+/// #     fn new(_props: Self::Properties) -> Self { Self }
+/// #     fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode { rsx! {} }
+/// #     fn update(&mut self, _message: Self::Message) -> bool { false }
+/// # }
+/// rsx! {
+///     <ErrorBoundary<MyComponent, DefaultFallback> props=1 />
+/// };
+/// ```
+///
 /// ## Empty
 /// Macro supports empty input. It is used to return an empty view.
 ///
@@ -402,3 +577,51 @@ pub fn rsx(input: TokenStream) -> TokenStream {
     let root = parse_macro_input!(input as Root);
     TokenStream::from(root.into_token_stream())
 }
+
+/// The `Routable` derive macro implements [Routable](../wal_core/router/trait.Routable.html) for an enum of the routes of the application,
+/// so the paths are declared once instead of being duplicated between the router and the links.
+/// It also implements [Display](std::fmt::Display) formatting the route as the path, so the route can be passed to the `to` attribute of the `Link`.
+///
+/// Every variant requires the `#[at("/path")]` attribute with the path of the route. Segments of the path starting with `:` are parameters,
+/// which are parsed into the named fields of the variant with [FromStr](std::str::FromStr) and formatted with [Display](std::fmt::Display).
+/// Each parameter has to match a field of the variant and each field has to match a parameter. Routes are matched in the order of the variants.
+///
+/// A single unit variant can be marked with the `#[not_found]` attribute. It is returned by `Routable::not_found`
+/// and rendered for every path, which does not match any route, instead of the not found page of the router.
+///
+/// ```
+/// use wal_core::router::Routable;
+/// use wal_rsx::Routable;
+///
+/// #[derive(Routable, Clone, PartialEq, Debug)]
+/// enum Route {
+///     #[at("/")]
+///     Home,
+///     #[at("/users/:id")]
+///     User { id: u32 },
+///     #[at("/users/:id/posts/:slug")]
+///     Post { id: u32, slug: String },
+///     #[not_found]
+///     #[at("/404")]
+///     NotFound,
+/// }
+///
+/// assert_eq!(Route::from_path("/"), Some(Route::Home));
+/// assert_eq!(Route::from_path("/users/7"), Some(Route::User { id: 7 }));
+/// assert_eq!(Route::from_path("/users/seven"), None);
+/// assert_eq!(Route::not_found(), Some(Route::NotFound));
+/// assert_eq!(
+///     Route::Post { id: 7, slug: "hello world".to_string() }.to_string(),
+///     "/users/7/posts/hello%20world"
+/// );
+/// ```
+///
+/// Pages of the routes are registered with `RouterBuilder::add_routes::<Route, RoutePage>()`,
+/// where `RoutePage` is a component with `Route` as its properties.
+#[proc_macro_derive(Routable, attributes(at, not_found))]
+pub fn routable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    routable::derive_routable(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
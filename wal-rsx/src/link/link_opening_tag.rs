@@ -1,17 +1,20 @@
 use quote::{quote, quote_spanned, ToTokens};
 use syn::parse::Parse;
 
-use crate::attributes::{
-    normal_attribute::NormalAttribute, process_specialized_attribute, KEY_ATTR,
+use crate::{
+    attributes::normal_attribute::NormalAttribute, element::element_attributes::ElementAttributes,
 };
 
-use super::TO_ATTR;
+use super::{ACTIVE_CLASS_ATTR, EXACT_ATTR, GENERATED_ATTRS, REPLACE_ATTR, TO_ATTR};
 
 pub(crate) struct LinkOpeningTag {
     lt: syn::token::Lt,
     pub(crate) name: proc_macro2::Ident,
-    pub(crate) to: NormalAttribute,
-    pub(crate) key: Option<NormalAttribute>,
+    pub(crate) to: proc_macro2::TokenStream,
+    pub(crate) active_class: Option<proc_macro2::TokenStream>,
+    pub(crate) exact: proc_macro2::TokenStream,
+    pub(crate) replace: Option<proc_macro2::TokenStream>,
+    pub(crate) attributes: ElementAttributes,
     slash: Option<syn::token::Slash>,
     gt: syn::token::Gt,
 }
@@ -19,21 +22,38 @@ pub(crate) struct LinkOpeningTag {
 impl Parse for LinkOpeningTag {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let lt = input.parse()?;
-        let name = input.parse()?;
+        let name = input.parse::<proc_macro2::Ident>()?;
+        let mut attributes = input.parse::<ElementAttributes>()?;
 
-        let mut key = None;
-        let mut to = None;
-        while NormalAttribute::peek(input) {
-            let incoming_attribute = input.parse::<NormalAttribute>()?;
-            Self::process_attribute(&mut key, &mut to, incoming_attribute, &name)?;
+        for generated_attr in GENERATED_ATTRS {
+            if let Some(attribute) = attributes.take_normal_attribute(generated_attr) {
+                return Err(syn::Error::new(
+                    attribute.ident.span(),
+                    format!(
+                        "Unsupported attribute `{}`. `{}` sets it from `{}` attribute",
+                        attribute.ident, name, TO_ATTR
+                    ),
+                ));
+            }
         }
 
-        if to.is_none() {
+        let Some(to) = attributes.take_normal_attribute(TO_ATTR) else {
             return Err(syn::Error::new(
                 name.span(),
                 format!("`{name}` requires `{TO_ATTR}` attribute"),
             ));
-        }
+        };
+        let to = Self::get_to_string_token_stream(&to);
+        let active_class = attributes
+            .take_normal_attribute(ACTIVE_CLASS_ATTR)
+            .map(|active_class| Self::get_to_string_token_stream(&active_class));
+        let exact = attributes.take_normal_attribute(EXACT_ATTR).map_or_else(
+            || quote!(false),
+            |exact| Self::get_value_token_stream(&exact),
+        );
+        let replace = attributes
+            .take_normal_attribute(REPLACE_ATTR)
+            .map(|replace| Self::get_value_token_stream(&replace));
 
         let slash = input.parse().ok();
         let gt = input.parse()?;
@@ -41,37 +61,17 @@ impl Parse for LinkOpeningTag {
         Ok(LinkOpeningTag {
             lt,
             name,
-            to: to.unwrap(),
-            key,
+            to,
+            active_class,
+            exact,
+            replace,
+            attributes,
             slash,
             gt,
         })
     }
 }
 
-impl LinkOpeningTag {
-    fn process_attribute(
-        key: &mut Option<NormalAttribute>,
-        to: &mut Option<NormalAttribute>,
-        incoming_attribute: NormalAttribute,
-        tag_ident: &proc_macro2::Ident,
-    ) -> syn::Result<()> {
-        if incoming_attribute.ident == KEY_ATTR {
-            process_specialized_attribute(key, incoming_attribute)
-        } else if incoming_attribute.ident == TO_ATTR {
-            process_specialized_attribute(to, incoming_attribute)
-        } else {
-            Err(syn::Error::new(
-                incoming_attribute.ident.span(),
-                format!(
-                    "Unsupported attribute `{}`. `{}` supports only `{}` and `{}` attributes",
-                    incoming_attribute.ident, tag_ident, KEY_ATTR, TO_ATTR
-                ),
-            ))
-        }
-    }
-}
-
 impl LinkOpeningTag {
     pub(crate) fn is_self_closing(&self) -> bool {
         self.slash.is_some()
@@ -83,19 +83,13 @@ impl LinkOpeningTag {
         quote! { #lt #gt }
     }
 
-    pub(crate) fn get_to_attribute_token_stream(&self) -> Vec<proc_macro2::TokenStream> {
-        let mut attributes = Vec::new();
-
-        let to_value = &self.to.value;
-        attributes
-            .push(quote_spanned!(to_value.error_span() => (::std::string::String::from("href"), #to_value.to_string())));
-        attributes
-            .push(quote_spanned!(to_value.error_span() => (::std::string::String::from("data_link"), #to_value.to_string())));
-
-        attributes
+    fn get_value_token_stream(attribute: &NormalAttribute) -> proc_macro2::TokenStream {
+        let value = &attribute.value;
+        quote_spanned!(value.error_span() => #value)
     }
 
-    pub(crate) fn get_key_attribute_token_stream(&self) -> proc_macro2::TokenStream {
-        NormalAttribute::get_key_attribute_token_stream(self.key.as_ref())
+    fn get_to_string_token_stream(attribute: &NormalAttribute) -> proc_macro2::TokenStream {
+        let value = &attribute.value;
+        quote_spanned!(value.error_span() => #value.to_string())
     }
 }
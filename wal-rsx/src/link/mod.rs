@@ -13,6 +13,11 @@ mod link_opening_tag;
 
 pub(crate) const LINK_TAG: &str = "Link";
 const TO_ATTR: &str = "to";
+const ACTIVE_CLASS_ATTR: &str = "active_class";
+const EXACT_ATTR: &str = "exact";
+const REPLACE_ATTR: &str = "replace";
+/// Attributes set by the link itself, which can not be set directly.
+const GENERATED_ATTRS: [&str; 3] = ["href", "data_link", "data_link_replace"];
 
 pub(crate) struct Link {
     opening_tag: LinkOpeningTag,
@@ -81,18 +86,55 @@ impl Link {
 
 impl ToTokens for Link {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let attributes = self.opening_tag.get_to_attribute_token_stream();
-        let key = self.opening_tag.get_key_attribute_token_stream();
+        let to = &self.opening_tag.to;
+        let attributes = self.opening_tag.attributes.get_attributes_token_stream();
+        let key = self.opening_tag.attributes.get_key_attribute_token_stream();
+        let event_handlers = self
+            .opening_tag
+            .attributes
+            .get_event_handlers_token_stream();
         let children = &self.children;
 
+        let replace = self.opening_tag.replace.as_ref().map(|replace| {
+            quote! {
+                if #replace {
+                    __link_attributes.insert(
+                        ::std::string::String::from("data_link_replace"),
+                        ::std::string::String::new(),
+                    );
+                }
+            }
+        });
+        let active_class = self.opening_tag.active_class.as_ref().map(|active_class| {
+            let exact = &self.opening_tag.exact;
+            quote! {
+                if ::wal_core::router::is_link_active(&__link_to, #exact) {
+                    let __link_class = match __link_attributes.remove("class") {
+                        Some(class) => ::std::format!("{} {}", class, #active_class),
+                        None => #active_class,
+                    };
+                    __link_attributes.insert(::std::string::String::from("class"), __link_class);
+                }
+            }
+        });
+
         tokens.extend(quote_spanned! { self.error_span() =>
             ::wal_core::virtual_dom::VNode::Element(
                 ::wal_core::virtual_dom::VElement::new(
                     ::std::string::String::from("a"),
-                    ::std::collections::HashMap::from([
-                        #(#attributes,)*
-                    ]),
-                    ::std::vec::Vec::new(),
+                    {
+                        let __link_to = #to;
+                        #[allow(unused_mut)]
+                        let mut __link_attributes = ::std::collections::HashMap::from([
+                            #(#attributes,)*
+                            (::std::string::String::from("href"), __link_to.clone()),
+                            (::std::string::String::from("data_link"), __link_to.clone()),
+                        ]);
+                        #replace
+                        #active_class
+                        __link_attributes
+                    },
+                    ::std::vec![#(#event_handlers,)*],
                     #key,
                     ::std::vec![#(#children,)*],
                 ),
@@ -0,0 +1,77 @@
+use quote::{quote, ToTokens};
+use syn::parse::Parse;
+
+use crate::attributes::{normal_attribute::NormalAttribute, process_specialized_attribute};
+
+use super::DEPS_ATTR;
+
+pub(crate) struct MemoOpeningTag {
+    lt: syn::token::Lt,
+    pub(crate) name: proc_macro2::Ident,
+    pub(crate) deps: NormalAttribute,
+    slash: Option<syn::token::Slash>,
+    gt: syn::token::Gt,
+}
+
+impl Parse for MemoOpeningTag {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lt = input.parse()?;
+        let name = input.parse()?;
+
+        let mut deps = None;
+        while NormalAttribute::peek(input) {
+            let incoming_attribute = input.parse::<NormalAttribute>()?;
+            Self::process_attribute(&mut deps, incoming_attribute, &name)?;
+        }
+
+        if deps.is_none() {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("`{name}` requires `{DEPS_ATTR}` attribute"),
+            ));
+        }
+
+        let slash = input.parse().ok();
+        let gt = input.parse()?;
+
+        Ok(MemoOpeningTag {
+            lt,
+            name,
+            deps: deps.unwrap(),
+            slash,
+            gt,
+        })
+    }
+}
+
+impl MemoOpeningTag {
+    fn process_attribute(
+        deps: &mut Option<NormalAttribute>,
+        incoming_attribute: NormalAttribute,
+        tag_ident: &proc_macro2::Ident,
+    ) -> syn::Result<()> {
+        if incoming_attribute.ident == DEPS_ATTR {
+            process_specialized_attribute(deps, incoming_attribute)
+        } else {
+            Err(syn::Error::new(
+                incoming_attribute.ident.span(),
+                format!(
+                    "Unsupported attribute `{}`. `{}` supports only `{}` attribute",
+                    incoming_attribute.ident, tag_ident, DEPS_ATTR
+                ),
+            ))
+        }
+    }
+}
+
+impl MemoOpeningTag {
+    pub(crate) fn is_self_closing(&self) -> bool {
+        self.slash.is_some()
+    }
+
+    pub(crate) fn error_spanned(&self) -> impl ToTokens {
+        let lt = &self.lt;
+        let gt = &self.gt;
+        quote! { #lt #gt }
+    }
+}
@@ -0,0 +1,118 @@
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+use self::{memo_closing_tag::MemoClosingTag, memo_opening_tag::MemoOpeningTag};
+
+use super::tree::Tree;
+
+mod memo_closing_tag;
+mod memo_opening_tag;
+
+pub(crate) const MEMO_TAG: &str = "Memo";
+const DEPS_ATTR: &str = "deps";
+
+pub(crate) struct Memo {
+    opening_tag: MemoOpeningTag,
+    children: Vec<Tree>,
+    closing_tag: Option<MemoClosingTag>,
+}
+
+impl Parse for Memo {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek2(syn::token::Slash) {
+            let closing_tag = input.parse::<MemoClosingTag>()?;
+            return Err(syn::Error::new_spanned(
+                closing_tag.error_spanned(),
+                format!(
+                    "This closing tag does not have a corresponding opening tag. (hint: try adding `<{}>`)",
+                    closing_tag.name
+                )
+            ));
+        }
+
+        let opening_tag = input.parse::<MemoOpeningTag>()?;
+        if opening_tag.is_self_closing() {
+            return Ok(Memo {
+                opening_tag,
+                children: Vec::new(),
+                closing_tag: None,
+            });
+        }
+
+        let children = Self::parse_children(&opening_tag, input)?;
+        let closing_tag = input.parse()?;
+
+        Ok(Memo {
+            opening_tag,
+            children,
+            closing_tag: Some(closing_tag),
+        })
+    }
+}
+
+impl Memo {
+    fn parse_children(opening_tag: &MemoOpeningTag, input: ParseStream) -> syn::Result<Vec<Tree>> {
+        let mut children = Vec::new();
+
+        loop {
+            if input.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    opening_tag.error_spanned(),
+                    format!(
+                        "This opening tag does not have a corresponding closing tag. (hint: try adding `</{}>`)",
+                        opening_tag.name
+                    ),
+                ));
+            }
+
+            if MemoClosingTag::peek(input) {
+                break;
+            }
+
+            children.push(input.parse()?);
+        }
+
+        Ok(children)
+    }
+}
+
+impl ToTokens for Memo {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let deps = &self.opening_tag.deps.value;
+        let children = &self.children;
+
+        tokens.extend(quote_spanned! { self.error_span() =>
+            ::wal_core::virtual_dom::VNode::Memo(
+                ::wal_core::virtual_dom::VMemo::new(
+                    #[allow(unused_braces)]
+                    #deps,
+                    ::wal_core::virtual_dom::VNode::List(
+                        ::wal_core::virtual_dom::VList::new(
+                            ::std::vec![#(#children,)*],
+                            None,
+                        ),
+                    ),
+                ),
+            )
+        });
+    }
+}
+
+impl Memo {
+    fn error_span(&self) -> proc_macro2::Span {
+        self.error_spanned().span()
+    }
+
+    fn error_spanned(&self) -> impl ToTokens {
+        let start_error_spanned = self.opening_tag.error_spanned();
+        let end_error_spanned = self.closing_tag.as_ref().map(MemoClosingTag::error_spanned);
+        if end_error_spanned.is_some() {
+            quote!(#start_error_spanned #end_error_spanned)
+        } else {
+            quote!(#start_error_spanned)
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+use self::{portal_closing_tag::PortalClosingTag, portal_opening_tag::PortalOpeningTag};
+
+use super::tree::Tree;
+
+mod portal_closing_tag;
+mod portal_opening_tag;
+
+pub(crate) const PORTAL_TAG: &str = "Portal";
+const TARGET_ATTR: &str = "target";
+
+pub(crate) struct Portal {
+    opening_tag: PortalOpeningTag,
+    children: Vec<Tree>,
+    closing_tag: Option<PortalClosingTag>,
+}
+
+impl Parse for Portal {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek2(syn::token::Slash) {
+            let closing_tag = input.parse::<PortalClosingTag>()?;
+            return Err(syn::Error::new_spanned(
+                closing_tag.error_spanned(),
+                format!(
+                    "This closing tag does not have a corresponding opening tag. (hint: try adding `<{}>`)",
+                    closing_tag.name
+                )
+            ));
+        }
+
+        let opening_tag = input.parse::<PortalOpeningTag>()?;
+        if opening_tag.is_self_closing() {
+            return Ok(Portal {
+                opening_tag,
+                children: Vec::new(),
+                closing_tag: None,
+            });
+        }
+
+        let children = Self::parse_children(&opening_tag, input)?;
+        let closing_tag = input.parse()?;
+
+        Ok(Portal {
+            opening_tag,
+            children,
+            closing_tag: Some(closing_tag),
+        })
+    }
+}
+
+impl Portal {
+    fn parse_children(
+        opening_tag: &PortalOpeningTag,
+        input: ParseStream,
+    ) -> syn::Result<Vec<Tree>> {
+        let mut children = Vec::new();
+
+        loop {
+            if input.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    opening_tag.error_spanned(),
+                    format!(
+                        "This opening tag does not have a corresponding closing tag. (hint: try adding `</{}>`)",
+                        opening_tag.name
+                    ),
+                ));
+            }
+
+            if PortalClosingTag::peek(input) {
+                break;
+            }
+
+            children.push(input.parse()?);
+        }
+
+        Ok(children)
+    }
+}
+
+impl ToTokens for Portal {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let target = &self.opening_tag.target.value;
+        let children = &self.children;
+
+        tokens.extend(quote_spanned! { self.error_span() =>
+            ::wal_core::virtual_dom::VNode::Portal(
+                ::wal_core::virtual_dom::VPortal::new(
+                    #[allow(unused_braces)]
+                    #target,
+                    ::wal_core::virtual_dom::VNode::List(
+                        ::wal_core::virtual_dom::VList::new(
+                            ::std::vec![#(#children,)*],
+                            None,
+                        ),
+                    ),
+                ),
+            )
+        });
+    }
+}
+
+impl Portal {
+    fn error_span(&self) -> proc_macro2::Span {
+        self.error_spanned().span()
+    }
+
+    fn error_spanned(&self) -> impl ToTokens {
+        let start_error_spanned = self.opening_tag.error_spanned();
+        let end_error_spanned = self
+            .closing_tag
+            .as_ref()
+            .map(PortalClosingTag::error_spanned);
+        if end_error_spanned.is_some() {
+            quote!(#start_error_spanned #end_error_spanned)
+        } else {
+            quote!(#start_error_spanned)
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use quote::{quote, ToTokens};
+use syn::parse::Parse;
+
+use crate::attributes::{normal_attribute::NormalAttribute, process_specialized_attribute};
+
+use super::TARGET_ATTR;
+
+pub(crate) struct PortalOpeningTag {
+    lt: syn::token::Lt,
+    pub(crate) name: proc_macro2::Ident,
+    pub(crate) target: NormalAttribute,
+    slash: Option<syn::token::Slash>,
+    gt: syn::token::Gt,
+}
+
+impl Parse for PortalOpeningTag {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lt = input.parse()?;
+        let name = input.parse()?;
+
+        let mut target = None;
+        while NormalAttribute::peek(input) {
+            let incoming_attribute = input.parse::<NormalAttribute>()?;
+            Self::process_attribute(&mut target, incoming_attribute, &name)?;
+        }
+
+        if target.is_none() {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("`{name}` requires `{TARGET_ATTR}` attribute"),
+            ));
+        }
+
+        let slash = input.parse().ok();
+        let gt = input.parse()?;
+
+        Ok(PortalOpeningTag {
+            lt,
+            name,
+            target: target.unwrap(),
+            slash,
+            gt,
+        })
+    }
+}
+
+impl PortalOpeningTag {
+    fn process_attribute(
+        target: &mut Option<NormalAttribute>,
+        incoming_attribute: NormalAttribute,
+        tag_ident: &proc_macro2::Ident,
+    ) -> syn::Result<()> {
+        if incoming_attribute.ident == TARGET_ATTR {
+            process_specialized_attribute(target, incoming_attribute)
+        } else {
+            Err(syn::Error::new(
+                incoming_attribute.ident.span(),
+                format!(
+                    "Unsupported attribute `{}`. `{}` supports only `{}` attribute",
+                    incoming_attribute.ident, tag_ident, TARGET_ATTR
+                ),
+            ))
+        }
+    }
+}
+
+impl PortalOpeningTag {
+    pub(crate) fn is_self_closing(&self) -> bool {
+        self.slash.is_some()
+    }
+
+    pub(crate) fn error_spanned(&self) -> impl ToTokens {
+        let lt = &self.lt;
+        let gt = &self.gt;
+        quote! { #lt #gt }
+    }
+}
@@ -0,0 +1,272 @@
+use quote::quote;
+use syn::spanned::Spanned;
+
+const AT_ATTR: &str = "at";
+const NOT_FOUND_ATTR: &str = "not_found";
+
+enum Segment {
+    Literal(String),
+    Parameter(proc_macro2::Ident),
+}
+
+struct Route {
+    ident: proc_macro2::Ident,
+    segments: Vec<Segment>,
+    is_unit: bool,
+}
+
+impl Route {
+    fn parse(variant: &syn::Variant) -> syn::Result<Route> {
+        let mut at_attrs = variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident(AT_ATTR));
+        let Some(at_attr) = at_attrs.next() else {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                format!(
+                    "Variant `{}` requires `#[{AT_ATTR}(\"/path\")]` attribute",
+                    variant.ident
+                ),
+            ));
+        };
+        if let Some(at_attr) = at_attrs.next() {
+            return Err(syn::Error::new(
+                at_attr.span(),
+                format!("Duplicate `{AT_ATTR}` attribute"),
+            ));
+        }
+        let path = at_attr.parse_args::<syn::LitStr>()?;
+        let segments = Self::parse_segments(&path)?;
+
+        let fields = match &variant.fields {
+            syn::Fields::Named(fields) => fields.named.iter().collect(),
+            syn::Fields::Unit => Vec::new(),
+            syn::Fields::Unnamed(fields) => {
+                return Err(syn::Error::new(
+                    fields.span(),
+                    "Tuple variants are not supported, use named fields matching the parameters of the path",
+                ))
+            }
+        };
+        let parameters: Vec<&proc_macro2::Ident> = segments
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Parameter(parameter) => Some(parameter),
+                Segment::Literal(_) => None,
+            })
+            .collect();
+        for (i, parameter) in parameters.iter().enumerate() {
+            if parameters[..i].contains(parameter) {
+                return Err(syn::Error::new(
+                    path.span(),
+                    format!("Duplicate parameter `:{parameter}` in the path"),
+                ));
+            }
+            if !fields
+                .iter()
+                .any(|field| field.ident.as_ref() == Some(*parameter))
+            {
+                return Err(syn::Error::new(
+                    path.span(),
+                    format!(
+                        "Parameter `:{parameter}` does not match any field of variant `{}`",
+                        variant.ident
+                    ),
+                ));
+            }
+        }
+        for field in fields {
+            let ident = field.ident.as_ref().unwrap();
+            if !parameters.contains(&ident) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("Field `{ident}` does not match any parameter of the path"),
+                ));
+            }
+        }
+
+        Ok(Route {
+            ident: variant.ident.clone(),
+            segments,
+            is_unit: matches!(variant.fields, syn::Fields::Unit),
+        })
+    }
+
+    fn parse_segments(path: &syn::LitStr) -> syn::Result<Vec<Segment>> {
+        let value = path.value();
+        if !value.starts_with('/') {
+            return Err(syn::Error::new(path.span(), "Path has to start with `/`"));
+        }
+        value
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| match segment.strip_prefix(':') {
+                Some(parameter) => {
+                    syn::parse_str(parameter)
+                        .map(Segment::Parameter)
+                        .map_err(|_| {
+                            syn::Error::new(
+                                path.span(),
+                                format!("Parameter `{segment}` has to be a valid identifier"),
+                            )
+                        })
+                }
+                None => Ok(Segment::Literal(segment.to_string())),
+            })
+            .collect()
+    }
+
+    fn pattern(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        if self.is_unit {
+            return quote!(Self::#ident);
+        }
+        let parameters = self.parameters();
+        quote!(Self::#ident { #(#parameters),* })
+    }
+
+    fn parameters(&self) -> impl Iterator<Item = &proc_macro2::Ident> {
+        self.segments.iter().filter_map(|segment| match segment {
+            Segment::Parameter(parameter) => Some(parameter),
+            Segment::Literal(_) => None,
+        })
+    }
+
+    fn parsing_tokens(&self) -> proc_macro2::TokenStream {
+        let pattern = self.pattern();
+        let mut tokens = quote!(return ::std::option::Option::Some(#pattern););
+        for (i, segment) in self.segments.iter().enumerate().rev() {
+            if let Segment::Parameter(parameter) = segment {
+                tokens = quote! {
+                    if let ::std::result::Result::Ok(#parameter) = segments[#i].parse() {
+                        #tokens
+                    }
+                };
+            }
+        }
+
+        let length_condition = match self.segments.len() {
+            0 => quote!(segments.is_empty()),
+            length => quote!(segments.len() == #length),
+        };
+        let literal_conditions =
+            self.segments
+                .iter()
+                .enumerate()
+                .filter_map(|(i, segment)| match segment {
+                    Segment::Literal(literal) => Some(quote!(&& segments[#i] == #literal)),
+                    Segment::Parameter(_) => None,
+                });
+        quote! {
+            if #length_condition #(#literal_conditions)* {
+                #tokens
+            }
+        }
+    }
+
+    fn formatting_tokens(&self) -> proc_macro2::TokenStream {
+        let pattern = self.pattern();
+        if self.parameters().next().is_none() {
+            let path = self.join_segments(|literal| literal.to_string());
+            return quote!(#pattern => ::std::string::String::from(#path));
+        }
+        // Parameters are replaced by `{}`, so the path can be passed to the `format!` macro
+        let format_string =
+            self.join_segments(|literal| literal.replace('{', "{{").replace('}', "}}"));
+        let parameters = self.parameters();
+        quote! {
+            #pattern => ::std::format!(
+                #format_string,
+                #(::wal_core::router::routable::encode_path_segment(#parameters)),*
+            )
+        }
+    }
+
+    fn join_segments(&self, format_literal: impl Fn(&str) -> String) -> String {
+        if self.segments.is_empty() {
+            return "/".to_string();
+        }
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Literal(literal) => format!("/{}", format_literal(literal)),
+                Segment::Parameter(_) => "/{}".to_string(),
+            })
+            .collect()
+    }
+}
+
+pub(crate) fn derive_routable(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let syn::Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`Routable` can only be derived for enums",
+        ));
+    };
+
+    let routes = data
+        .variants
+        .iter()
+        .map(Route::parse)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let mut not_found_variants = data.variants.iter().filter(|variant| {
+        variant
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident(NOT_FOUND_ATTR))
+    });
+    let not_found = match not_found_variants.next() {
+        Some(variant) if !matches!(variant.fields, syn::Fields::Unit) => {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                format!("`{NOT_FOUND_ATTR}` variant has to be a unit variant"),
+            ))
+        }
+        Some(variant) => {
+            let ident = &variant.ident;
+            quote! {
+                fn not_found() -> ::std::option::Option<Self> {
+                    ::std::option::Option::Some(Self::#ident)
+                }
+            }
+        }
+        None => quote!(),
+    };
+    if let Some(variant) = not_found_variants.next() {
+        return Err(syn::Error::new(
+            variant.ident.span(),
+            format!("Only one variant can be marked as `{NOT_FOUND_ATTR}`"),
+        ));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let from_path = routes.iter().map(Route::parsing_tokens);
+    let to_path = routes.iter().map(Route::formatting_tokens);
+
+    Ok(quote! {
+        impl #impl_generics ::wal_core::router::Routable for #ident #ty_generics #where_clause {
+            fn from_path(path: &str) -> ::std::option::Option<Self> {
+                let segments = ::wal_core::router::routable::split_path(path);
+                #(#from_path)*
+                ::std::option::Option::None
+            }
+
+            fn to_path(&self) -> ::std::string::String {
+                match self {
+                    #(#to_path,)*
+                }
+            }
+
+            #not_found
+        }
+
+        impl #impl_generics ::std::fmt::Display for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(&::wal_core::router::Routable::to_path(self))
+            }
+        }
+    })
+}
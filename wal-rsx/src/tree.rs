@@ -6,8 +6,11 @@ use super::{
     element::Element,
     expression_block::ExpressionBlock,
     fragment::Fragment,
+    head::{Head, HEAD_TAG},
     link::{Link, LINK_TAG},
     literal::Literal,
+    memo::{Memo, MEMO_TAG},
+    portal::{Portal, PORTAL_TAG},
     r#for::For,
     r#if::IfExpression,
 };
@@ -21,6 +24,9 @@ pub(crate) enum Tree {
     Literal(Literal),
     ExpressionBlock(ExpressionBlock),
     Link(Link),
+    Memo(Memo),
+    Portal(Portal),
+    Head(Head),
 }
 
 impl Parse for Tree {
@@ -69,13 +75,15 @@ impl Tree {
 
         let tree = if forked_input.peek(syn::token::Eq) {
             Self::Fragment(input.parse()?)
-        } else if ident
-            .chars()
-            .next()
-            .map_or(false, |c| c.is_ascii_uppercase())
-        {
+        } else if ident.chars().next().is_some_and(|c| c.is_ascii_uppercase()) {
             if ident == LINK_TAG {
                 Self::Link(input.parse()?)
+            } else if ident == MEMO_TAG {
+                Self::Memo(input.parse()?)
+            } else if ident == PORTAL_TAG {
+                Self::Portal(input.parse()?)
+            } else if ident == HEAD_TAG {
+                Self::Head(input.parse()?)
             } else {
                 Self::Component(input.parse()?)
             }
@@ -100,6 +108,9 @@ impl ToTokens for Tree {
             Self::Literal(literal) => literal.to_tokens(tokens),
             Self::ExpressionBlock(expr_block) => expr_block.to_tokens(tokens),
             Self::Link(link) => link.to_tokens(tokens),
+            Self::Memo(memo) => memo.to_tokens(tokens),
+            Self::Portal(portal) => portal.to_tokens(tokens),
+            Self::Head(head) => head.to_tokens(tokens),
         }
     }
 }
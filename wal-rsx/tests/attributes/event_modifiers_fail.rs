@@ -0,0 +1,9 @@
+use wal_rsx::rsx;
+
+fn main() {
+    rsx! { <div onclick:unknown={Callback::new(|_event: wal_core::events::MouseEvent| {})}></div> };
+    rsx! { <div onclick:stop:stop={Callback::new(|_event: wal_core::events::MouseEvent| {})}></div> };
+    rsx! { <div onclick:prevent:passive={Callback::new(|_event: wal_core::events::MouseEvent| {})}></div> };
+    rsx! { <div onclick:passive:prevent={Callback::new(|_event: wal_core::events::MouseEvent| {})}></div> };
+    rsx! { <div onclick:={Callback::new(|_event: wal_core::events::MouseEvent| {})}></div> };
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use wal_core::{
+    component::callback::Callback,
+    events::{on_custom_event, onclick, onscroll, onsubmit, EventHandler, EventOptions},
+    virtual_dom::{VElement, VNode},
+};
+use wal_rsx::rsx;
+
+include!("../utils/new_velement_str.rs");
+
+fn main() {
+    single_event_modifier();
+    multiple_event_modifiers();
+    passive_and_capture_event_modifiers();
+    custom_event_modifiers();
+}
+
+fn single_event_modifier() {
+    let callback = Callback::new(|_event: wal_core::events::SubmitEvent| {});
+    let rsx = rsx! {
+        <form onsubmit:prevent={callback.clone()}></form>
+    };
+    assert_eq!(
+        rsx,
+        VNode::Element(new_velement_str(
+            "form",
+            HashMap::new(),
+            vec![EventHandler::new_with_options(
+                onsubmit(callback),
+                EventOptions {
+                    prevent_default: true,
+                    ..Default::default()
+                },
+            )],
+            None,
+            Vec::new(),
+        ))
+    );
+}
+
+fn multiple_event_modifiers() {
+    let callback = Callback::new(|_event: wal_core::events::MouseEvent| {});
+    let rsx = rsx! {
+        <button onclick:prevent:stop:once={callback.clone()}></button>
+    };
+    assert_eq!(
+        rsx,
+        VNode::Element(new_velement_str(
+            "button",
+            HashMap::new(),
+            vec![EventHandler::new_with_options(
+                onclick(callback),
+                EventOptions {
+                    prevent_default: true,
+                    stop_propagation: true,
+                    once: true,
+                    ..Default::default()
+                },
+            )],
+            None,
+            Vec::new(),
+        ))
+    );
+}
+
+fn passive_and_capture_event_modifiers() {
+    let callback = Callback::new(|_event: wal_core::events::Event| {});
+    let rsx = rsx! {
+        <div onscroll:passive:capture={callback.clone()}></div>
+    };
+    assert_eq!(
+        rsx,
+        VNode::Element(new_velement_str(
+            "div",
+            HashMap::new(),
+            vec![EventHandler::new_with_options(
+                onscroll(callback),
+                EventOptions {
+                    passive: true,
+                    capture: true,
+                    ..Default::default()
+                },
+            )],
+            None,
+            Vec::new(),
+        ))
+    );
+}
+
+fn custom_event_modifiers() {
+    let callback = Callback::new(|_event: wal_core::events::CustomEvent| {});
+    let rsx = rsx! {
+        <div on:item-selected:stop={callback.clone()}></div>
+    };
+    assert_eq!(
+        rsx,
+        VNode::Element(new_velement_str(
+            "div",
+            HashMap::new(),
+            vec![EventHandler::new_with_options(
+                on_custom_event("item-selected", callback),
+                EventOptions {
+                    stop_propagation: true,
+                    ..Default::default()
+                },
+            )],
+            None,
+            Vec::new(),
+        ))
+    );
+}
@@ -0,0 +1,69 @@
+use wal_core::{
+    component::{behavior::Behavior, Component},
+    virtual_dom::{VComponent, VNode},
+};
+use wal_rsx::rsx;
+
+include!("../utils/custom_components/custom_component_props_struct.rs");
+
+struct CustomComponentNotCloneableProps;
+
+#[derive(PartialEq, Debug)]
+struct NotCloneableProps {
+    x: i32,
+}
+
+impl Component for CustomComponentNotCloneableProps {
+    type Properties = NotCloneableProps;
+    type Message = ();
+
+    fn new(_props: Self::Properties) -> Self {
+        CustomComponentNotCloneableProps
+    }
+
+    fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+        rsx! { <div /> }
+    }
+
+    fn update(&mut self, _message: Self::Message) -> bool {
+        false
+    }
+}
+
+fn main() {
+    custom_component_not_cloneable_props();
+    custom_component_not_cloneable_props_with_key();
+    custom_component_cloneable_props();
+}
+
+fn custom_component_not_cloneable_props() {
+    let rsx = rsx! { <CustomComponentNotCloneableProps props = NotCloneableProps { x: 1 } /> };
+    assert_eq!(
+        rsx,
+        VNode::Component(VComponent::new_with_owned_props::<
+            CustomComponentNotCloneableProps,
+        >(NotCloneableProps { x: 1 }, None))
+    );
+}
+
+fn custom_component_not_cloneable_props_with_key() {
+    let props = NotCloneableProps { x: 1 };
+    let rsx = rsx! { <CustomComponentNotCloneableProps props = {props} key = "key" /> };
+    assert_eq!(
+        rsx,
+        VNode::Component(VComponent::new_with_owned_props::<
+            CustomComponentNotCloneableProps,
+        >(NotCloneableProps { x: 1 }, Some("key".to_string())))
+    );
+}
+
+fn custom_component_cloneable_props() {
+    let rsx = rsx! { <CustomComponentPropsStruct props = PropsStruct { x: 1 } /> };
+    assert_eq!(
+        rsx,
+        VNode::Component(VComponent::new::<CustomComponentPropsStruct>(
+            PropsStruct { x: 1 },
+            None
+        ))
+    );
+}
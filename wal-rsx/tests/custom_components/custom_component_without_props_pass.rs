@@ -5,7 +5,7 @@ use wal_core::{
 use wal_rsx::rsx;
 
 include!("../utils/custom_components/custom_component_props_i32.rs");
-include!("../utils/custom_components/custom_component_props_struct_with_default_and_hash.rs");
+include!("../utils/custom_components/custom_component_props_struct_with_default_and_partial_eq.rs");
 
 fn main() {
     custom_component_props_i32();
@@ -27,13 +27,13 @@ fn custom_component_props_i32() {
 }
 
 fn custom_component_props_struct_with_default() {
-    let rsx = rsx! { <CustomComponentPropsStructWithDefaultAndHash /> };
+    let rsx = rsx! { <CustomComponentPropsStructWithDefaultAndPartialEq /> };
     assert_eq!(
         rsx,
         VNode::Component(VComponent::new::<
-            CustomComponentPropsStructWithDefaultAndHash,
+            CustomComponentPropsStructWithDefaultAndPartialEq,
         >(
-            <CustomComponentPropsStructWithDefaultAndHash as Component>::Properties::default(),
+            <CustomComponentPropsStructWithDefaultAndPartialEq as Component>::Properties::default(),
             None
         ))
     );
@@ -51,13 +51,13 @@ fn custom_component_props_i32_with_key() {
 }
 
 fn custom_component_props_struct_with_default_with_key() {
-    let rsx = rsx! { <CustomComponentPropsStructWithDefaultAndHash key="key" /> };
+    let rsx = rsx! { <CustomComponentPropsStructWithDefaultAndPartialEq key="key" /> };
     assert_eq!(
         rsx,
         VNode::Component(VComponent::new::<
-            CustomComponentPropsStructWithDefaultAndHash,
+            CustomComponentPropsStructWithDefaultAndPartialEq,
         >(
-            <CustomComponentPropsStructWithDefaultAndHash as Component>::Properties::default(),
+            <CustomComponentPropsStructWithDefaultAndPartialEq as Component>::Properties::default(),
             Some("key".to_string())
         ))
     );
@@ -4,8 +4,8 @@ use wal_core::{
 };
 use wal_rsx::rsx;
 
-include!("../utils/custom_components/custom_component_props_struct_without_hash.rs");
+include!("../utils/custom_components/custom_component_props_struct_without_partial_eq.rs");
 
 fn main() {
-    rsx! { <CustomComponentPropsStructWithoutHash /> };
+    rsx! { <CustomComponentPropsStructWithoutPartialEq /> };
 }
@@ -0,0 +1,33 @@
+use wal_core::{
+    component::{behavior::Behavior, Component},
+    virtual_dom::VNode,
+};
+use wal_rsx::rsx;
+
+#[derive(Default)]
+struct Comp;
+impl Component for Comp {
+    type Message = ();
+    type Properties = ();
+
+    fn new(_props: Self::Properties) -> Self {
+        Comp
+    }
+    fn view(&self, _behavior: &mut impl Behavior<Self>) -> VNode {
+        rsx! { <div /> }
+    }
+    fn update(&mut self, _message: Self::Message) -> bool {
+        false
+    }
+}
+
+fn main() {
+    rsx! { <Head> "text" </Head> };
+    rsx! { <Head> { "expression" } </Head> };
+    rsx! { <Head> <Comp /> </Head> };
+    rsx! { <Head> <div></div> </Head> };
+    rsx! { <Head> <><script></script></> </Head> };
+    rsx! { <Head> <title><span></span></title> </Head> };
+    rsx! { <Head> <meta content="Users" /> </Head> };
+    rsx! { <Head> <link rel="icon" /> </Head> };
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+use wal_core::{
+    events::EventHandler,
+    virtual_dom::{VElement, VHead, VList, VNode, VText},
+};
+use wal_rsx::rsx;
+
+include!("../utils/new_velement_str.rs");
+
+fn main() {
+    head_with_title_meta_and_links();
+    head_with_fragment_and_if();
+}
+
+fn head_with_title_meta_and_links() {
+    let user = "Alice";
+    let rsx = rsx! {
+        <Head>
+            <title>{ format!("{user} - Users") }</title>
+            <meta charset="utf-8" />
+            <meta name="description" content="Users" />
+            <link rel="stylesheet" href="/users.css" />
+        </Head>
+    };
+    assert_eq!(
+        rsx,
+        VNode::Head(VHead::new(VNode::List(VList::new(
+            vec![
+                VNode::Element(new_velement_str(
+                    "title",
+                    HashMap::new(),
+                    Vec::new(),
+                    None,
+                    vec![VNode::Text(VText::new("Alice - Users"))],
+                )),
+                VNode::Element(new_velement_str(
+                    "meta",
+                    HashMap::from([("charset", "utf-8")]),
+                    Vec::new(),
+                    None,
+                    Vec::new(),
+                )),
+                VNode::Element(new_velement_str(
+                    "meta",
+                    HashMap::from([("name", "description"), ("content", "Users")]),
+                    Vec::new(),
+                    None,
+                    Vec::new(),
+                )),
+                VNode::Element(new_velement_str(
+                    "link",
+                    HashMap::from([("rel", "stylesheet"), ("href", "/users.css")]),
+                    Vec::new(),
+                    None,
+                    Vec::new(),
+                )),
+            ],
+            None,
+        ))))
+    );
+}
+
+fn head_with_fragment_and_if() {
+    let is_draft = true;
+    let rsx = rsx! {
+        <Head>
+            <>
+                <title>"Draft"</title>
+            </>
+            if is_draft {
+                <meta name="robots" content="noindex" />
+            }
+        </Head>
+    };
+    assert!(matches!(rsx, VNode::Head(_)));
+}
@@ -7,12 +7,14 @@ fn main() {
     rsx! { <Link></Link> };
     rsx! { <Link to /> };
     rsx! { <Link to= /> };
-    rsx! { <Link unsupported_attr="value" /> };
-    rsx! { <Link to="value" unsupported_attr="value" /> };
+    rsx! { <Link href="value" to="value" /> };
+    rsx! { <Link to="value" data_link="value" /> };
     rsx! { <Link key="value" /> };
     rsx! { </Link> };
     rsx! { <Link> };
     rsx! { <Link to="value"> };
     rsx! { <Link to={NonDisplayStruct}></Link> };
     rsx! { <Link key={NonDisplayStruct} to="value"></Link> };
+    rsx! { <Link to="value" active_class={NonDisplayStruct}></Link> };
+    rsx! { <Link to="value" active_class="active" exact="value"></Link> };
 }
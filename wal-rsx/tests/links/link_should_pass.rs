@@ -14,6 +14,8 @@ fn main() {
     link_with_key_attr();
     link_with_single_child();
     link_with_multiple_children();
+    link_with_element_attributes();
+    link_with_replace_attr();
 }
 
 fn link_with_to_attr_as_string() {
@@ -114,3 +116,41 @@ fn link_with_multiple_children() {
         ))
     );
 }
+
+fn link_with_element_attributes() {
+    let rsx = rsx! { <Link to="/home" class="nav-link" id="home" target="_blank"></Link> };
+    assert_eq!(
+        rsx,
+        VNode::Element(new_velement_str(
+            "a",
+            HashMap::from([
+                ("href", "/home"),
+                ("data_link", "/home"),
+                ("class", "nav-link"),
+                ("id", "home"),
+                ("target", "_blank"),
+            ]),
+            Vec::new(),
+            None,
+            Vec::new(),
+        ))
+    );
+}
+
+fn link_with_replace_attr() {
+    let rsx = rsx! { <Link to="/home" replace=true></Link> };
+    assert_eq!(
+        rsx,
+        VNode::Element(new_velement_str(
+            "a",
+            HashMap::from([
+                ("href", "/home"),
+                ("data_link", "/home"),
+                ("data_link_replace", ""),
+            ]),
+            Vec::new(),
+            None,
+            Vec::new(),
+        ))
+    );
+}
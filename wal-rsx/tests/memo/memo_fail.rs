@@ -0,0 +1,9 @@
+use wal_rsx::rsx;
+
+fn main() {
+    rsx! { <Memo> "Missing deps" </Memo> };
+    rsx! { <Memo deps=1 key="key"> "Unsupported attribute" </Memo> };
+    rsx! { <Memo deps=1 deps=2> "Duplicate deps" </Memo> };
+    rsx! { <Memo deps=1> "Missing closing tag" };
+    rsx! { </Memo> };
+}
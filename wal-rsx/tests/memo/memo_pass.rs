@@ -0,0 +1,43 @@
+use wal_core::virtual_dom::{VList, VMemo, VNode, VText};
+use wal_rsx::rsx;
+
+fn main() {
+    memo_with_literal_deps();
+    memo_with_expression_deps();
+    self_closing_memo();
+}
+
+fn memo_with_literal_deps() {
+    let rsx = rsx! { <Memo deps=1> "Memoized" </Memo> };
+    assert_eq!(
+        rsx,
+        VNode::Memo(VMemo::new(
+            1,
+            VNode::List(VList::new(vec![VNode::Text(VText::new("Memoized"))], None))
+        ))
+    );
+}
+
+fn memo_with_expression_deps() {
+    let items = vec![1, 2, 3];
+    let rsx = rsx! {
+        <Memo deps={items.clone()}>
+            { items.len() }
+        </Memo>
+    };
+    assert_eq!(
+        rsx,
+        VNode::Memo(VMemo::new(
+            vec![1, 2, 3],
+            VNode::List(VList::new(vec![VNode::Text(VText::new(3))], None))
+        ))
+    );
+}
+
+fn self_closing_memo() {
+    let rsx = rsx! { <Memo deps={"deps"} /> };
+    assert_eq!(
+        rsx,
+        VNode::Memo(VMemo::new("deps", VNode::List(VList::new(vec![], None))))
+    );
+}
@@ -0,0 +1,16 @@
+use wal_rsx::rsx;
+
+include!("../utils/non_display_struct.rs");
+
+fn main() {
+    rsx! { <Portal /> };
+    rsx! { <Portal></Portal> };
+    rsx! { <Portal target /> };
+    rsx! { <Portal target= /> };
+    rsx! { <Portal target="#modal-root" target="#other-root" /> };
+    rsx! { <Portal target="#modal-root" class="modal" /> };
+    rsx! { <Portal key="value" target="#modal-root" /> };
+    rsx! { </Portal> };
+    rsx! { <Portal target="#modal-root"> };
+    rsx! { <Portal target={NonDisplayStruct}></Portal> };
+}
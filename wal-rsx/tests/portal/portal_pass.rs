@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+use wal_core::{
+    events::EventHandler,
+    virtual_dom::{PortalTarget, VElement, VList, VNode, VPortal, VText},
+};
+use wal_rsx::rsx;
+
+include!("../utils/new_velement_str.rs");
+
+fn main() {
+    self_closing_portal_with_target_as_string();
+    portal_with_target_as_string();
+    portal_with_target_as_expr();
+    portal_with_multiple_children();
+}
+
+fn self_closing_portal_with_target_as_string() {
+    let rsx = rsx! { <Portal target="#modal-root" /> };
+    assert_eq!(
+        rsx,
+        VNode::Portal(VPortal::new(
+            "#modal-root",
+            VNode::List(VList::new(Vec::new(), None)),
+        ))
+    );
+}
+
+fn portal_with_target_as_string() {
+    let rsx = rsx! { <Portal target="#modal-root">"Modal"</Portal> };
+    assert_eq!(
+        rsx,
+        VNode::Portal(VPortal::new(
+            "#modal-root",
+            VNode::List(VList::new(vec![VNode::Text(VText::new("Modal"))], None)),
+        ))
+    );
+}
+
+fn portal_with_target_as_expr() {
+    let target = PortalTarget::Selector(String::from("#modal-root"));
+    let rsx = rsx! { <Portal target={target.clone()}>"Modal"</Portal> };
+    assert_eq!(
+        rsx,
+        VNode::Portal(VPortal::new(
+            target,
+            VNode::List(VList::new(vec![VNode::Text(VText::new("Modal"))], None)),
+        ))
+    );
+}
+
+fn portal_with_multiple_children() {
+    let rsx = rsx! {
+        <Portal target={String::from("#modal-root")}>
+            "Modal"
+            <span>"Span"</span>
+        </Portal>
+    };
+    assert_eq!(
+        rsx,
+        VNode::Portal(VPortal::new(
+            "#modal-root",
+            VNode::List(VList::new(
+                vec![
+                    VNode::Text(VText::new("Modal")),
+                    VNode::Element(new_velement_str(
+                        "span",
+                        HashMap::new(),
+                        Vec::new(),
+                        None,
+                        vec![VNode::Text(VText::new("Span"))],
+                    )),
+                ],
+                None,
+            )),
+        ))
+    );
+}
@@ -0,0 +1,79 @@
+use wal_rsx::Routable;
+
+#[derive(Routable)]
+struct Struct;
+
+#[derive(Routable)]
+enum MissingAt {
+    Home,
+}
+
+#[derive(Routable)]
+enum DuplicateAt {
+    #[at("/")]
+    #[at("/home")]
+    Home,
+}
+
+#[derive(Routable)]
+enum RelativePath {
+    #[at("home")]
+    Home,
+}
+
+#[derive(Routable)]
+enum TupleVariant {
+    #[at("/users/:id")]
+    User(u32),
+}
+
+#[derive(Routable)]
+enum ParameterWithoutField {
+    #[at("/users/:id")]
+    User,
+}
+
+#[derive(Routable)]
+enum FieldWithoutParameter {
+    #[at("/users")]
+    User { id: u32 },
+}
+
+#[derive(Routable)]
+enum DuplicateParameter {
+    #[at("/users/:id/:id")]
+    User { id: u32 },
+}
+
+#[derive(Routable)]
+enum InvalidParameter {
+    #[at("/users/:1")]
+    User,
+}
+
+#[derive(Routable)]
+enum NonUnitNotFound {
+    #[not_found]
+    #[at("/users/:id")]
+    User { id: u32 },
+}
+
+#[derive(Routable)]
+enum MultipleNotFound {
+    #[not_found]
+    #[at("/")]
+    Home,
+    #[not_found]
+    #[at("/404")]
+    NotFound,
+}
+
+struct NonParsable;
+
+#[derive(Routable)]
+enum NonParsableParameter {
+    #[at("/users/:id")]
+    User { id: NonParsable },
+}
+
+fn main() {}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use wal_core::{
+    events::EventHandler,
+    router::Routable,
+    virtual_dom::{VElement, VNode},
+};
+use wal_rsx::{rsx, Routable};
+
+include!("../utils/new_velement_str.rs");
+
+#[derive(Routable, Clone, PartialEq, Debug)]
+enum Route {
+    #[at("/")]
+    Home,
+    #[at("/users")]
+    Users,
+    #[at("/users/:id")]
+    User { id: u32 },
+    #[at("/users/:id/posts/:slug")]
+    Post { slug: String, id: u32 },
+}
+
+#[derive(Routable, Clone, PartialEq, Debug)]
+enum RouteWithNotFound {
+    #[at("/")]
+    Home,
+    #[not_found]
+    #[at("/404")]
+    NotFound,
+}
+
+fn main() {
+    routes_should_be_parsed_from_paths();
+    routes_should_be_formatted_as_paths();
+    not_matching_path_should_not_be_parsed();
+    not_found_route_should_not_be_parsed_from_paths();
+    link_with_route_as_to_attr();
+}
+
+fn routes_should_be_parsed_from_paths() {
+    assert_eq!(Route::from_path("/"), Some(Route::Home));
+    assert_eq!(Route::from_path("/users/"), Some(Route::Users));
+    assert_eq!(Route::from_path("/users/7?tab=posts"), Some(Route::User { id: 7 }));
+    assert_eq!(
+        Route::from_path("/users/7/posts/hello-world"),
+        Some(Route::Post {
+            slug: "hello-world".to_string(),
+            id: 7
+        })
+    );
+}
+
+fn routes_should_be_formatted_as_paths() {
+    assert_eq!(Route::Home.to_path(), "/");
+    assert_eq!(Route::Users.to_string(), "/users");
+    assert_eq!(Route::User { id: 7 }.to_string(), "/users/7");
+    assert_eq!(
+        Route::Post {
+            slug: "hello world".to_string(),
+            id: 7
+        }
+        .to_string(),
+        "/users/7/posts/hello%20world"
+    );
+}
+
+fn not_matching_path_should_not_be_parsed() {
+    assert_eq!(Route::from_path("/users/seven"), None);
+    assert_eq!(Route::from_path("/posts"), None);
+    assert_eq!(Route::from_path("/users/7/posts"), None);
+}
+
+fn not_found_route_should_not_be_parsed_from_paths() {
+    assert_eq!(
+        RouteWithNotFound::from_path("/"),
+        Some(RouteWithNotFound::Home)
+    );
+    assert_eq!(RouteWithNotFound::from_path("/users"), None);
+    assert_eq!(
+        RouteWithNotFound::not_found(),
+        Some(RouteWithNotFound::NotFound)
+    );
+    assert_eq!(Route::not_found(), None);
+    assert_eq!(RouteWithNotFound::NotFound.to_string(), "/404");
+}
+
+fn link_with_route_as_to_attr() {
+    let id = 7;
+    let rsx = rsx! { <Link to={Route::User { id }} /> };
+    assert_eq!(
+        rsx,
+        VNode::Element(new_velement_str(
+            "a",
+            HashMap::from([("href", "/users/7"), ("data_link", "/users/7")]),
+            Vec::new(),
+            None,
+            Vec::new(),
+        ))
+    );
+}
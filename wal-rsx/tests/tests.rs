@@ -20,6 +20,8 @@ fn rsx_macro_tests() {
     // t.compile_fail("tests/attributes/attributes_fail.rs");
     // t.pass("tests/attributes/event_attributes_pass.rs");
     // t.compile_fail("tests/attributes/event_attributes_fail.rs");
+    t.pass("tests/attributes/event_modifiers_pass.rs");
+    t.compile_fail("tests/attributes/event_modifiers_fail.rs");
     // t.pass("tests/attributes/class_attributes_pass.rs");
     // t.compile_fail("tests/attributes/class_attributes_fail.rs");
     t.pass("tests/attributes/attributes_with_names_same_as_rusts_keywords_pass.rs");
@@ -37,9 +39,18 @@ fn rsx_macro_tests() {
     // t.compile_fail(
     //     "tests/custom_components/custom_component_without_props_without_default_fail.rs",
     // );
-    // t.compile_fail("tests/custom_components/custom_component_without_props_without_hash_fail.rs");
+    t.compile_fail("tests/custom_components/custom_component_without_props_without_partial_eq_fail.rs");
     // t.pass("tests/custom_components/custom_component_with_props_pass.rs");
+    t.pass("tests/custom_components/custom_component_with_not_cloneable_props_pass.rs");
     // t.compile_fail("tests/custom_components/custom_component_wrong_attributes_fail.rs");
-    // t.pass("tests/links/link_should_pass.rs");
-    // t.compile_fail("tests/links/link_should_fail.rs");
+    t.pass("tests/memo/memo_pass.rs");
+    t.compile_fail("tests/memo/memo_fail.rs");
+    t.pass("tests/links/link_should_pass.rs");
+    t.compile_fail("tests/links/link_should_fail.rs");
+    t.pass("tests/portal/portal_pass.rs");
+    t.compile_fail("tests/portal/portal_fail.rs");
+    t.pass("tests/head/head_pass.rs");
+    t.compile_fail("tests/head/head_fail.rs");
+    t.pass("tests/routable/routable_pass.rs");
+    t.compile_fail("tests/routable/routable_fail.rs");
 }
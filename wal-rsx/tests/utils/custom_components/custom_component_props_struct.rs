@@ -1,6 +1,6 @@
 struct CustomComponentPropsStruct;
 
-#[derive(Hash)]
+#[derive(PartialEq, Clone)]
 struct PropsStruct {
     x: i32,
 }
@@ -1,10 +1,10 @@
-struct CustomComponentPropsStructWithDefaultAndHash;
+struct CustomComponentPropsStructWithDefaultAndPartialEq;
 
-#[derive(Default, Hash)]
-struct PropsWithDefaultAndHash;
+#[derive(Default, PartialEq, Clone)]
+struct PropsWithDefaultAndPartialEq;
 
-impl Component for CustomComponentPropsStructWithDefaultAndHash {
-    type Properties = PropsWithDefaultAndHash;
+impl Component for CustomComponentPropsStructWithDefaultAndPartialEq {
+    type Properties = PropsWithDefaultAndPartialEq;
     type Message = ();
 
     fn new(_props: Self::Properties) -> Self {
@@ -1,6 +1,6 @@
 struct CustomComponentPropsStructWithoutDefault;
 
-#[derive(Hash)]
+#[derive(PartialEq, Clone)]
 struct PropsWithoutDefault;
 
 impl Component for CustomComponentPropsStructWithoutDefault {
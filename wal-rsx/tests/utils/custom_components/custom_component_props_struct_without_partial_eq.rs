@@ -1,10 +1,10 @@
-struct CustomComponentPropsStructWithoutHash;
+struct CustomComponentPropsStructWithoutPartialEq;
 
-#[derive(Default)]
-struct PropsWithoutHash;
+#[derive(Default, Clone)]
+struct PropsWithoutPartialEq;
 
-impl Component for CustomComponentPropsStructWithoutHash {
-    type Properties = PropsWithoutHash;
+impl Component for CustomComponentPropsStructWithoutPartialEq {
+    type Properties = PropsWithoutPartialEq;
     type Message = ();
 
     fn new(_props: Self::Properties) -> Self {
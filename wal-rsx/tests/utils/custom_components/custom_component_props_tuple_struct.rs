@@ -1,6 +1,6 @@
 struct CustomComponentPropsTupleStruct;
 
-#[derive(Hash)]
+#[derive(PartialEq, Clone)]
 struct PropsTupleStruct(i32);
 
 impl PropsTupleStruct {